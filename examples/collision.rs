@@ -2,17 +2,14 @@ use std::io::stdout;
 
 use color_eyre::Result;
 use crossterm::{
-    cursor::MoveTo,
     event::KeyCode,
-    execute,
-    style::Color,
-    terminal::{Clear, ClearType, disable_raw_mode, enable_raw_mode},
+    terminal::{disable_raw_mode, enable_raw_mode},
 };
 use rastor::{
     key::KeyInput,
     shapes::{Shape, rectangle::Rectangle},
-    types::vec2::Vec2,
-    utils::get_terminal_size,
+    types::{color::Color, vec2::Vec2},
+    utils::{clear_screen, get_terminal_size},
 };
 
 fn main() -> Result<()> {
@@ -22,8 +19,8 @@ fn main() -> Result<()> {
 
     let term_size = get_terminal_size()?;
     let initial_pos = term_size / Vec2::splat(2);
-    let mut rect = Rectangle::new(initial_pos.to_f32().into(), Vec2::splat(5.0), Color::Green);
-    let mut other_rect = Rectangle::new((initial_pos.to_f32() + 2.0).into(), Vec2::splat(5.0), Color::Green);
+    let mut rect = Rectangle::new(initial_pos.to_f32().into(), Vec2::splat(5.0), Color::GREEN);
+    let mut other_rect = Rectangle::new((initial_pos.to_f32() + 2.0).into(), Vec2::splat(5.0), Color::GREEN);
 
     enable_raw_mode().unwrap();
 
@@ -32,7 +29,8 @@ fn main() -> Result<()> {
     let mut key_input = KeyInput::new();
 
     while is_running {
-        execute!(stdout, Clear(ClearType::All), MoveTo(0, 0)).unwrap();
+        clear_screen(&mut stdout)?;
+        key_input.begin_frame();
 
         rect.draw();
         rect.update();
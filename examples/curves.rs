@@ -0,0 +1,83 @@
+use std::{io::stdout, thread::sleep, time::{Duration, Instant}};
+
+use crossterm::{
+    event::KeyCode,
+    terminal::{disable_raw_mode, enable_raw_mode},
+};
+use rastor::{
+    key::KeyInput,
+    shapes::{
+        Shape,
+        bezier::Bezier,
+        circle::Circle,
+        fill::Fill,
+        path::Path,
+        polyline::Polyline,
+        spline::CatmullRom,
+        stroke::Join,
+    },
+    types::{color::Color, vec2::Vec2},
+    utils::{clear_screen, get_terminal_size},
+};
+
+fn main() -> color_eyre::Result<()> {
+    let term_size = get_terminal_size()?.to_f32();
+    let at = |x: f32, y: f32| Vec2::new(term_size.x * x, term_size.y * y);
+
+    // A thick zigzag: 'j' switches between the ways of joining its corners.
+    let mut zigzag = Polyline::new(
+        vec![at(0.05, 0.35), at(0.15, 0.1), at(0.25, 0.35), at(0.35, 0.1), at(0.45, 0.35)],
+        Color::rgb(240, 180, 60),
+    );
+    zigzag.stroke.width = 2.5;
+    let joins = [Join::Bevel, Join::Miter, Join::Round];
+    let mut join = 0;
+
+    let mut quadratic = Bezier::quadratic(at(0.55, 0.35), at(0.75, -0.2), at(0.95, 0.35), Color::rgb(120, 200, 255));
+    quadratic.stroke.width = 1.5;
+    let mut cubic = Bezier::cubic(at(0.55, 0.45), at(0.65, 0.1), at(0.85, 0.8), at(0.95, 0.45), Fill::LinearGradient {
+        from: Color::rgb(255, 80, 160),
+        to: Color::rgb(80, 160, 255),
+        angle: 0.0,
+    });
+
+    // A closed loop through a few points, with a ball going round it at a
+    // steady speed.
+    let mut track = CatmullRom::new(
+        vec![at(0.1, 0.6), at(0.3, 0.55), at(0.45, 0.75), at(0.3, 0.95), at(0.15, 0.85)],
+        Color::rgb(80, 80, 80),
+    );
+    track.closed = true;
+    track.stroke.width = 2.0;
+    let mut ball = Circle::new(at(0.1, 0.6).into(), 1.0, 12, Color::rgb(80, 255, 120));
+    ball.style.z_index = 1;
+
+    let mut stdout = stdout().lock();
+    let mut is_running = true;
+    let mut key_input = KeyInput::new();
+    let start = Instant::now();
+
+    enable_raw_mode().unwrap();
+    while is_running {
+        clear_screen(&mut stdout)?;
+        key_input.begin_frame();
+
+        let t = start.elapsed().as_secs_f32();
+        ball.center = track.point_at_distance((t * 12.0) % track.length()).into();
+
+        if key_input.is_pressed(&KeyCode::Char('j')) { join = (join + 1) % joins.len() }
+        zigzag.stroke.join = joins[join];
+
+        for shape in [&mut zigzag as &mut dyn Shape, &mut quadratic, &mut cubic, &mut track, &mut ball] {
+            shape.update();
+            shape.draw();
+        }
+
+        if key_input.is_pressed(&KeyCode::Char('q')) { is_running = false }
+
+        sleep(Duration::from_millis(16));
+    }
+    disable_raw_mode().unwrap();
+
+    Ok(())
+}
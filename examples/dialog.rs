@@ -0,0 +1,91 @@
+use std::{io::stdout, thread::sleep, time::Duration};
+
+use color_eyre::Result;
+use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode},
+};
+use rastor::{
+    key::{KeyCode, KeyInput},
+    types::{color::Color, vec2::Vec2},
+    ui::{
+        UIElement,
+        container::style::ContainerStyle,
+        dialog::{Backdrop, Dialog, DialogResult},
+        layout::{Align, Direction, Justify, Layout},
+        root::{DialogResponse, UIRoot},
+        style::border::Border,
+        text::{Text, style::{TextAlign, TextStyle}},
+        theme::{self, Theme},
+    },
+    utils::clear_screen,
+};
+
+fn main() -> Result<()> {
+    let mut is_running = true;
+    color_eyre::install().unwrap();
+    let mut stdout = stdout();
+
+    let mut root = UIRoot::new();
+    root.container.layout = Some(Layout {
+        direction: Direction::Column,
+        align: Align::Stretch,
+        justify: Justify::Center,
+        ..Default::default()
+    });
+    let mut border = Border::new(Vec2::zero().into(), Vec2::zero());
+    border.title = Some(String::from("the game"));
+    root.container.style = Some(ContainerStyle::new(Some(border)));
+
+    let mut help = Text::new(
+        Vec2::zero().into(),
+        Vec2::new(0.0, 3.0),
+        String::from("t: toast   b: blocking dialog   q: quit\n1/2/3: dark, light and high contrast themes"),
+    );
+    help.text_style = Some(TextStyle { align: TextAlign::Center, ..Default::default() });
+    root.container.add_child(Box::new(help));
+
+    let mut key_input = KeyInput::new();
+    let mut toasts = 0;
+
+    enable_raw_mode().unwrap();
+    execute!(stdout, EnableMouseCapture).unwrap();
+    while is_running {
+        clear_screen(&mut stdout)?;
+        key_input.begin_frame();
+
+        match root.handle_input(&mut key_input) {
+            Some(DialogResponse { id, result: DialogResult::Ok }) if id == "quit" => is_running = false,
+            Some(DialogResponse { id, result }) => root.toast(format!("{id}: {result:?}"), Duration::from_secs(2)),
+            None => {}
+        }
+        if !root.has_dialog() {
+            if key_input.is_pressed(&KeyCode::Char('t')) {
+                toasts += 1;
+                root.toast(format!("Achievement #{toasts} unlocked"), Duration::from_secs(3));
+            }
+            if key_input.is_pressed(&KeyCode::Char('b')) {
+                let mut dialog = Dialog::new("notice", "Notice", "The scene is hidden until you press OK.");
+                dialog.backdrop = Backdrop::Block(Color::DARK_BLUE);
+                root.push_dialog(dialog);
+            }
+            if key_input.is_pressed(&KeyCode::Char('1')) { theme::set(Theme::dark()) }
+            if key_input.is_pressed(&KeyCode::Char('2')) { theme::set(Theme::light()) }
+            if key_input.is_pressed(&KeyCode::Char('3')) { theme::set(Theme::high_contrast()) }
+            if key_input.is_pressed(&KeyCode::Char('q')) {
+                root.push_dialog(Dialog::confirm("quit", "Quit", "Do you really want to quit?"));
+            }
+        }
+
+        root.update();
+        root.draw();
+
+        sleep(Duration::from_millis(16));
+    }
+    execute!(stdout, DisableMouseCapture).unwrap();
+    disable_raw_mode().unwrap();
+
+    println!();
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use std::{cell::Cell, io::stdout, rc::Rc, thread::sleep, time::Duration};
+
+use color_eyre::Result;
+use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode},
+};
+use rastor::{
+    key::{KeyCode, KeyInput},
+    types::vec2::Vec2,
+    ui::{
+        ElementState, UIElement,
+        button::Button,
+        container::{UIContainer, style::ContainerStyle},
+        event::EventResult,
+        layout::{Align, Direction, Justify, Layout, LayoutItem, Sizing},
+        root::UIRoot,
+        style::border::Border,
+        text::{Text, style::{TextAlign, TextStyle}},
+    },
+    utils::clear_screen,
+};
+
+fn main() -> Result<()> {
+    color_eyre::install().unwrap();
+    let mut stdout = stdout();
+
+    let count = Rc::new(Cell::new(0));
+    let quit = Rc::new(Cell::new(false));
+
+    let mut root = UIRoot::new();
+    root.container.layout = Some(Layout {
+        direction: Direction::Column,
+        gap: 1.0,
+        align: Align::Center,
+        justify: Justify::Center,
+        ..Default::default()
+    });
+    let mut border = Border::new(Vec2::zero().into(), Vec2::zero());
+    border.title = Some(String::from("events"));
+    root.container.style = Some(ContainerStyle::new(Some(border)));
+    // Keys nobody else wanted bubble up to here.
+    let on_quit = Rc::clone(&quit);
+    root.container.on_key = Some(Box::new(move |key| match key {
+        KeyCode::Char('q') | KeyCode::Esc => {
+            on_quit.set(true);
+            EventResult::Handled
+        }
+        _ => EventResult::Ignored,
+    }));
+
+    let mut hint = Text::new(
+        Vec2::zero().into(),
+        Vec2::new(44.0, 2.0),
+        String::from("click the buttons or use tab and enter\nq to quit"),
+    );
+    hint.text_style = Some(TextStyle { align: TextAlign::Center, ..Default::default() });
+    root.container.add_child(Box::new(hint));
+
+    let mut row = UIContainer::new(Vec2::zero().into(), Vec2::zero());
+    row.layout_item = LayoutItem::new(Sizing::Fixed(40.0), Sizing::Fixed(3.0));
+    row.layout = Some(Layout { direction: Direction::Row, gap: 2.0, justify: Justify::Center, ..Default::default() });
+    for (label, step) in [("-1", -1), ("+1", 1), ("+10", 10)] {
+        let mut button = Button::new(Vec2::zero().into(), String::from(label));
+        let count = Rc::clone(&count);
+        button.on_click = Some(Box::new(move || count.set(count.get() + step)));
+        row.add_child(Box::new(button));
+    }
+    let mut disabled = Button::new(Vec2::zero().into(), String::from("nope"));
+    disabled.state = ElementState::Disabled;
+    row.add_child(Box::new(disabled));
+    root.container.add_child(Box::new(row));
+
+    let mut key_input = KeyInput::new();
+
+    enable_raw_mode().unwrap();
+    execute!(stdout, EnableMouseCapture).unwrap();
+    while !quit.get() {
+        clear_screen(&mut stdout)?;
+        key_input.begin_frame();
+
+        root.handle_input(&mut key_input);
+        root.update();
+        root.draw();
+
+        let mut counter = Text::new(Vec2::new(2.0, 1.0).into(), Vec2::new(20.0, 1.0), format!("count: {}", count.get()));
+        counter.text_style = Some(TextStyle::default());
+        counter.draw();
+
+        sleep(Duration::from_millis(16));
+    }
+    execute!(stdout, DisableMouseCapture).unwrap();
+    disable_raw_mode().unwrap();
+
+    println!();
+    Ok(())
+}
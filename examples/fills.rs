@@ -0,0 +1,94 @@
+use std::{f32::consts::PI, io::stdout, thread::sleep, time::{Duration, Instant}};
+
+use crossterm::{
+    event::KeyCode,
+    terminal::{disable_raw_mode, enable_raw_mode},
+};
+use rastor::{
+    key::KeyInput,
+    shapes::{Shape, circle::Circle, fill::Fill, rectangle::Rectangle, shading::{Ramp, Shading}},
+    types::{color::Color, vec2::Vec2},
+    utils::{clear_screen, get_terminal_size},
+};
+
+fn main() -> color_eyre::Result<()> {
+    let term_size = get_terminal_size()?.to_f32();
+    let (column, top, bottom) = (term_size.x / 6.0, term_size.y * 0.3, term_size.y * 0.75);
+    let at = |x: f32, y: f32| Vec2::new(x, y).into();
+
+    let sunset = Fill::LinearGradient { from: Color::rgb(255, 200, 60), to: Color::rgb(120, 20, 90), angle: PI / 2.0 };
+    let mut sky = Rectangle::new(at(column, top), Vec2::new(3.0, 4.0), sunset);
+
+    let mut sun = Circle::new(at(column * 3.0, top), 4.0, 32, Fill::RadialGradient {
+        inner: Color::rgb(255, 255, 200),
+        outer: Color::rgb(255, 90, 0),
+    });
+
+    let mut board = Rectangle::new(at(column * 5.0, top), Vec2::new(4.0, 4.0), Fill::Checker {
+        a: Color::WHITE,
+        b: Color::BLACK,
+        columns: 4,
+        rows: 4,
+    });
+
+    let mut candy = Rectangle::new(at(column, bottom), Vec2::new(3.0, 3.0), Fill::Stripes {
+        a: Color::rgb(230, 30, 50),
+        b: Color::WHITE,
+        count: 3,
+        angle: PI / 4.0,
+    });
+
+    // Hue going around the circle, fading to white in the middle.
+    let mut wheel = Circle::new(at(column * 3.0, bottom), 4.0, 32, Fill::custom(|uv| {
+        let (dx, dy) = (uv.x - 0.5, uv.y - 0.5);
+        let hue = dy.atan2(dx).to_degrees().rem_euclid(360.0);
+        Color::from_hsv(hue, ((dx * dx + dy * dy).sqrt() * 2.0).min(1.0), 1.0)
+    }));
+
+    let mut spinner = Rectangle::new(at(column * 5.0, bottom), Vec2::new(3.0, 3.0), Fill::LinearGradient {
+        from: Color::rgb(0, 200, 255),
+        to: Color::rgb(0, 40, 120),
+        angle: 0.0,
+    });
+
+    // Press 's' to go through ASCII art ramps and back to solid blocks.
+    let ramps = [None, Some(Ramp::STANDARD), Some(Ramp::DETAILED), Some(Ramp::BLOCKS)];
+    let mut ramp = 0;
+
+    let mut stdout = stdout().lock();
+    let mut is_running = true;
+    let mut key_input = KeyInput::new();
+    let start = Instant::now();
+
+    enable_raw_mode().unwrap();
+    while is_running {
+        clear_screen(&mut stdout)?;
+        key_input.begin_frame();
+
+        // Fills turn along with their shape.
+        spinner.rotate_to(start.elapsed().as_secs_f32());
+        wheel.rotate_to(-start.elapsed().as_secs_f32());
+
+        for shape in [&mut sky as &mut dyn Shape, &mut sun, &mut board, &mut candy, &mut wheel, &mut spinner] {
+            shape.update();
+            shape.draw();
+        }
+
+        if key_input.is_pressed(&KeyCode::Char('q')) { is_running = false }
+        if key_input.is_pressed(&KeyCode::Char('s')) {
+            ramp = (ramp + 1) % ramps.len();
+            let shading = ramps[ramp].map_or(Shading::Solid, |glyphs| Shading::Ramp(Ramp::new(glyphs)));
+            for rect in [&mut sky, &mut board, &mut candy, &mut spinner] {
+                rect.style.shading = shading.clone();
+            }
+            for circle in [&mut sun, &mut wheel] {
+                circle.style.shading = shading.clone();
+            }
+        }
+
+        sleep(Duration::from_millis(16));
+    }
+    disable_raw_mode().unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use std::{f32::consts::{PI, TAU}, io::stdout, thread::sleep, time::{Duration, Instant}};
+
+use crossterm::{
+    event::KeyCode,
+    terminal::{disable_raw_mode, enable_raw_mode},
+};
+use rastor::{
+    key::KeyInput,
+    shapes::{
+        Shape,
+        antialias::AntiAliasing,
+        arc::Arc,
+        ellipse::Ellipse,
+        fill::Fill,
+        pie::Pie,
+        ring::Ring,
+        stroke::{DrawMode, Stroke},
+    },
+    types::{color::Color, vec2::Vec2},
+    utils::{clear_screen, get_terminal_size},
+};
+
+fn main() -> color_eyre::Result<()> {
+    let term_size = get_terminal_size()?.to_f32();
+    let at = |x: f32, y: f32| Vec2::new(x, y).into();
+
+    // A gauge: a grey track with the value drawn over it, from 7 to 5 o'clock.
+    let gauge_center = at(term_size.x * 0.25, term_size.y * 0.5);
+    let (from, to) = (-0.75 * PI, 0.75 * PI);
+    let mut track = Arc::new(gauge_center, 8.0, from, to, Color::rgb(60, 60, 60));
+    track.width = 2.0;
+    track.style.antialiasing = AntiAliasing::Blend;
+    let mut value = Arc::new(gauge_center, 8.0, from, from, Fill::LinearGradient {
+        from: Color::rgb(80, 220, 80),
+        to: Color::rgb(255, 60, 40),
+        angle: 0.0,
+    });
+    value.width = 2.0;
+    value.style.antialiasing = AntiAliasing::Blend;
+    value.style.z_index = 1;
+    let mut hub = Ring::new(gauge_center, 1.0, 2.0, Color::rgb(200, 200, 200));
+    hub.style.antialiasing = AntiAliasing::Quadrants;
+
+    // A radial menu: left and right pick a slice.
+    let menu_center = at(term_size.x * 0.7, term_size.y * 0.5);
+    let n_slices = 6;
+    let mut selected = 0;
+    let mut slices: Vec<Pie> = (0..n_slices)
+        .map(|i| {
+            let start = i as f32 * TAU / n_slices as f32;
+            let mut slice = Pie::new(menu_center, 9.0, start, start + TAU / n_slices as f32, Color::rgb(50, 50, 90));
+            slice.style.draw_mode = DrawMode::FillAndStroke(Stroke::new(Color::rgb(20, 20, 30)));
+            slice.style.antialiasing = AntiAliasing::Shade;
+            slice
+        })
+        .collect();
+    let mut middle = Ellipse::new(menu_center, Vec2::new(2.5, 2.5), Color::rgb(20, 20, 30));
+    middle.style.z_index = 1;
+
+    let mut stdout = stdout().lock();
+    let mut is_running = true;
+    let mut key_input = KeyInput::new();
+    let start = Instant::now();
+
+    enable_raw_mode().unwrap();
+    while is_running {
+        clear_screen(&mut stdout)?;
+        key_input.begin_frame();
+
+        let t = start.elapsed().as_secs_f32();
+        value.end = from + (to - from) * (0.5 + 0.5 * (t * 0.8).sin());
+
+        if key_input.is_pressed(&KeyCode::Right) { selected = (selected + 1) % n_slices }
+        if key_input.is_pressed(&KeyCode::Left) { selected = (selected + n_slices - 1) % n_slices }
+        for (i, slice) in slices.iter_mut().enumerate() {
+            slice.style.fill = if i == selected { Color::rgb(120, 120, 255) } else { Color::rgb(50, 50, 90) }.into();
+        }
+
+        for shape in [&mut track as &mut dyn Shape, &mut value, &mut hub] {
+            shape.update();
+            shape.draw();
+        }
+        for slice in &mut slices {
+            slice.update();
+            slice.draw();
+        }
+        middle.update();
+        middle.draw();
+
+        if key_input.is_pressed(&KeyCode::Char('q')) { is_running = false }
+
+        sleep(Duration::from_millis(16));
+    }
+    disable_raw_mode().unwrap();
+
+    Ok(())
+}
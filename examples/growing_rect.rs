@@ -1,17 +1,12 @@
 use std::{io::stdout, thread::sleep, time::Duration};
 
 use color_eyre::Result;
-use crossterm::{
-    cursor::MoveTo,
-    execute,
-    style::Color,
-    terminal::{Clear, ClearType, disable_raw_mode, enable_raw_mode},
-};
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
 use rastor::{
     key::{KeyInput, KeyCode},
     shapes::{Shape, rectangle::Rectangle},
-    types::vec2::Vec2,
-    utils::get_terminal_size,
+    types::{color::Color, vec2::Vec2},
+    utils::{clear_screen, get_terminal_size},
 };
 
 fn main() -> Result<()> {
@@ -21,13 +16,14 @@ fn main() -> Result<()> {
 
     let term_size = get_terminal_size()?;
     let initial_pos = term_size / Vec2::splat(2);
-    let mut rect = Rectangle::new(initial_pos.to_f32().into(), Vec2::new(0.0, 0.0), Color::Green);
+    let mut rect = Rectangle::new(initial_pos.to_f32().into(), Vec2::new(0.0, 0.0), Color::GREEN);
 
     let mut key_input = KeyInput::new();
 
     enable_raw_mode().unwrap();
     while is_running {
-        execute!(stdout, Clear(ClearType::All), MoveTo(0, 0)).unwrap();
+        clear_screen(&mut stdout)?;
+        key_input.begin_frame();
 
         rect.draw();
         rect.update();
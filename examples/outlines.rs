@@ -0,0 +1,75 @@
+use std::{io::stdout, thread::sleep, time::{Duration, Instant}};
+
+use crossterm::{
+    event::KeyCode,
+    terminal::{disable_raw_mode, enable_raw_mode},
+};
+use rastor::{
+    key::KeyInput,
+    shapes::{
+        Shape,
+        circle::Circle,
+        fill::Fill,
+        line::Line,
+        rectangle::Rectangle,
+        stroke::{DrawMode, Stroke},
+    },
+    types::{color::Color, vec2::Vec2},
+    utils::{clear_screen, get_terminal_size},
+};
+
+fn main() -> color_eyre::Result<()> {
+    let term_size = get_terminal_size()?.to_f32();
+    let (column, top, bottom) = (term_size.x / 6.0, term_size.y * 0.3, term_size.y * 0.75);
+    let at = |x: f32, y: f32| Vec2::new(x, y).into();
+
+    // A hollow frame.
+    let mut frame = Rectangle::new(at(column, top), Vec2::new(3.0, 4.0), Color::WHITE);
+    frame.style.draw_mode = DrawMode::Stroke(Stroke::new(Color::rgb(200, 200, 200)));
+
+    // A ring, thick enough to need more than one cell.
+    let mut ring = Circle::new(at(column * 3.0, top), 4.0, 32, Color::WHITE);
+    let mut thick = Stroke::new(Fill::LinearGradient { from: Color::rgb(255, 80, 80), to: Color::rgb(80, 80, 255), angle: 0.0 });
+    thick.width = 2.0;
+    ring.style.draw_mode = DrawMode::Stroke(thick);
+
+    // A filled button with a dotted edge, which keeps the fill behind its dots.
+    let mut button = Rectangle::new(at(column * 5.0, top), Vec2::new(3.0, 4.0), Color::rgb(40, 90, 40));
+    let mut dots = Stroke::new(Color::rgb(180, 255, 180));
+    dots.glyph = '•';
+    button.style.draw_mode = DrawMode::FillAndStroke(dots);
+
+    // A spinning sign, filled and outlined in different colours.
+    let mut sign = Rectangle::new(at(column * 2.0, bottom), Vec2::new(3.0, 3.0), Color::rgb(250, 200, 0));
+    let mut edge = Stroke::new(Color::rgb(120, 60, 0));
+    edge.width = 1.5;
+    sign.style.draw_mode = DrawMode::FillAndStroke(edge);
+
+    let mut beam = Line::new(Vec2::new(column * 3.5, bottom - 3.0), Vec2::new(column * 5.5, bottom + 3.0), Color::rgb(0, 220, 255));
+    beam.width = 2.0;
+
+    let mut stdout = stdout().lock();
+    let mut is_running = true;
+    let mut key_input = KeyInput::new();
+    let start = Instant::now();
+
+    enable_raw_mode().unwrap();
+    while is_running {
+        clear_screen(&mut stdout)?;
+        key_input.begin_frame();
+
+        sign.rotate_to(start.elapsed().as_secs_f32());
+
+        for shape in [&mut frame as &mut dyn Shape, &mut ring, &mut button, &mut sign, &mut beam] {
+            shape.update();
+            shape.draw();
+        }
+
+        if key_input.is_pressed(&KeyCode::Char('q')) { is_running = false }
+
+        sleep(Duration::from_millis(16));
+    }
+    disable_raw_mode().unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use std::{io::stdout, thread::sleep, time::{Duration, Instant}};
+
+use crossterm::{
+    event::KeyCode,
+    terminal::{disable_raw_mode, enable_raw_mode},
+};
+use rastor::{
+    key::KeyInput,
+    shapes::{
+        Shape,
+        antialias::AntiAliasing,
+        fill::Fill,
+        regular_polygon::RegularPolygon,
+        rounded_rect::RoundedRect,
+        star::Star,
+        stroke::{DrawMode, Stroke},
+    },
+    types::{color::Color, pos2::Pos2, vec2::Vec2},
+    utils::{clear_screen, get_terminal_size},
+};
+
+fn main() -> color_eyre::Result<()> {
+    let term_size = get_terminal_size()?.to_f32();
+    let at = |x: f32, y: f32| Vec2::new(term_size.x * x, term_size.y * y).into();
+
+    // A panel with a big rounded corner on the top left and a square one on
+    // the bottom right, and a badge on it as a child.
+    let mut panel = RoundedRect::new(at(0.3, 0.5), Vec2::new(9.0, 7.0), 2.0, Fill::LinearGradient {
+        from: Color::rgb(40, 50, 90),
+        to: Color::rgb(20, 25, 45),
+        angle: std::f32::consts::FRAC_PI_2,
+    });
+    panel.radii = [5.0, 2.0, 0.0, 2.0];
+    panel.style.draw_mode = DrawMode::FillAndStroke(Stroke::new(Color::rgb(120, 140, 220)));
+    panel.style.antialiasing = AntiAliasing::Blend;
+    let mut badge = Star::new(Pos2::Absolute(Vec2::new(10.0, -3.0)), 5, 1.2, 3.0, Color::rgb(255, 210, 60));
+    badge.style.z_index = 1;
+    panel.push(Box::new(badge));
+
+    // A spinning hexagon and star.
+    let mut hexagon = RegularPolygon::new(at(0.65, 0.3), 6, 5.0, Color::rgb(60, 200, 140));
+    hexagon.style.antialiasing = AntiAliasing::Shade;
+    let mut star = Star::new(at(0.75, 0.7), 7, 2.5, 6.0, Fill::RadialGradient {
+        inner: Color::rgb(255, 255, 200),
+        outer: Color::rgb(255, 100, 40),
+    });
+    star.style.antialiasing = AntiAliasing::Blend;
+
+    let mut stdout = stdout().lock();
+    let mut is_running = true;
+    let mut key_input = KeyInput::new();
+    let start = Instant::now();
+
+    enable_raw_mode().unwrap();
+    while is_running {
+        clear_screen(&mut stdout)?;
+        key_input.begin_frame();
+
+        let t = start.elapsed().as_secs_f32();
+        hexagon.rotate_to(t * 0.5);
+        star.rotate_to(-t * 0.8);
+
+        for shape in [&mut panel as &mut dyn Shape, &mut hexagon, &mut star] {
+            shape.update();
+            shape.draw();
+        }
+
+        if key_input.is_pressed(&KeyCode::Char('q')) { is_running = false }
+
+        sleep(Duration::from_millis(16));
+    }
+    disable_raw_mode().unwrap();
+
+    Ok(())
+}
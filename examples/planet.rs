@@ -1,17 +1,14 @@
 use std::{io::stdout, thread::sleep, time::Duration};
 
 use crossterm::{
-    cursor::MoveTo,
     event::KeyCode,
-    execute,
-    style::Color,
-    terminal::{Clear, ClearType, disable_raw_mode, enable_raw_mode},
+    terminal::{disable_raw_mode, enable_raw_mode},
 };
 use rastor::{
     key::KeyInput,
     shapes::{Shape, circle::Circle, rectangle::Rectangle},
-    types::vec2::Vec2,
-    utils::get_terminal_size,
+    types::{color::Color, vec2::Vec2},
+    utils::{clear_screen, get_terminal_size},
 };
 use rand::random_range;
 
@@ -27,17 +24,17 @@ fn main() -> color_eyre::Result<()> {
     let term_size = get_terminal_size()?;
     let initial_pos = term_size / Vec2::splat(2);
 
-    let mut circle = Circle::new(initial_pos.to_f32().into(), 10.0, 64, Color::Blue);
-    circle.z_index = 0;
+    let mut circle = Circle::new(initial_pos.to_f32().into(), 10.0, 64, Color::BLUE);
+    circle.style.z_index = 0;
     let mut patches = Vec::new();
 
     for (rect_x, rect_y, rect_w, rect_h) in patch_rects {
         let mut patch = Rectangle::new(
             (initial_pos.to_f32() + Vec2::new(rect_x as f32, rect_y as f32) - Vec2::new(12.0, 8.0)).into(),
             Vec2::new(rect_w as f32, rect_h as f32),
-            Color::Green,
+            Color::GREEN,
         );
-        patch.z_index = 10;
+        patch.style.z_index = 10;
         patches.push(patch);
     }
 
@@ -49,7 +46,8 @@ fn main() -> color_eyre::Result<()> {
 
     enable_raw_mode().unwrap();
     while is_running {
-        execute!(stdout, Clear(ClearType::All), MoveTo(0, 0)).unwrap();
+        clear_screen(&mut stdout)?;
+        key_input.begin_frame();
 
         circle.draw();
         circle.update();
@@ -0,0 +1,77 @@
+use std::{io::stdout, thread::sleep, time::{Duration, Instant}};
+
+use crossterm::{
+    event::KeyCode,
+    terminal::{disable_raw_mode, enable_raw_mode},
+};
+use rastor::{
+    key::KeyInput,
+    shapes::{
+        Shape,
+        antialias::AntiAliasing,
+        circle::Circle,
+        fill::Fill,
+        polygon::Polygon,
+        stroke::{DrawMode, Stroke},
+    },
+    types::{color::Color, vec2::Vec2},
+    utils::{clear_screen, get_terminal_size},
+};
+
+fn main() -> color_eyre::Result<()> {
+    let term_size = get_terminal_size()?.to_f32();
+    let at = |x: f32, y: f32| Vec2::new(x, y).into();
+    let points = |points: &[(f32, f32)]| points.iter().map(|&(x, y)| Vec2::new(x, y)).collect();
+
+    // An arrow pointing right: concave where the head meets the shaft.
+    let arrow = points(&[(-5.0, -1.5), (1.0, -1.5), (1.0, -4.0), (6.0, 0.0), (1.0, 4.0), (1.0, 1.5), (-5.0, 1.5)]);
+    let mut arrow = Polygon::new(at(term_size.x * 0.25, term_size.y * 0.3), arrow, Fill::LinearGradient {
+        from: Color::rgb(40, 120, 255),
+        to: Color::rgb(120, 255, 200),
+        angle: 0.0,
+    });
+    arrow.style.antialiasing = AntiAliasing::Blend;
+
+    // An L, filled and outlined.
+    let l = points(&[(-4.0, 4.0), (-1.0, 4.0), (-1.0, -1.0), (4.0, -1.0), (4.0, -4.0), (-4.0, -4.0)]);
+    let mut l = Polygon::new(at(term_size.x * 0.7, term_size.y * 0.3), l, Color::rgb(200, 60, 60));
+    l.style.draw_mode = DrawMode::FillAndStroke(Stroke::new(Color::rgb(255, 200, 200)));
+
+    // A spinning cross that lights up while the ball is inside it.
+    let cross = points(&[
+        (-1.0, -4.0), (1.0, -4.0), (1.0, -1.0), (4.0, -1.0), (4.0, 1.0), (1.0, 1.0),
+        (1.0, 4.0), (-1.0, 4.0), (-1.0, 1.0), (-4.0, 1.0), (-4.0, -1.0), (-1.0, -1.0),
+    ]);
+    let mut cross = Polygon::new(at(term_size.x * 0.5, term_size.y * 0.72), cross, Color::rgb(90, 90, 90));
+    cross.style.antialiasing = AntiAliasing::Shade;
+    let mut ball = Circle::new(at(0.0, term_size.y * 0.72), 1.0, 12, Color::rgb(255, 220, 0));
+    ball.style.z_index = 1;
+
+    let mut stdout = stdout().lock();
+    let mut is_running = true;
+    let mut key_input = KeyInput::new();
+    let start = Instant::now();
+
+    enable_raw_mode().unwrap();
+    while is_running {
+        clear_screen(&mut stdout)?;
+        key_input.begin_frame();
+
+        let t = start.elapsed().as_secs_f32();
+        cross.rotate_to(t * 0.8);
+        ball.center = at(term_size.x * (0.5 + 0.3 * (t * 0.7).sin()), term_size.y * 0.72);
+        cross.style.fill = if cross.collides_with(&ball) { Color::rgb(80, 220, 80) } else { Color::rgb(90, 90, 90) }.into();
+
+        for shape in [&mut arrow as &mut dyn Shape, &mut l, &mut cross, &mut ball] {
+            shape.update();
+            shape.draw();
+        }
+
+        if key_input.is_pressed(&KeyCode::Char('q')) { is_running = false }
+
+        sleep(Duration::from_millis(16));
+    }
+    disable_raw_mode().unwrap();
+
+    Ok(())
+}
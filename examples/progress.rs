@@ -0,0 +1,112 @@
+use std::{io::stdout, thread::sleep, time::{Duration, Instant}};
+
+use color_eyre::Result;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use rastor::{
+    color_depth::{self, ColorDepth, Dither},
+    key::{KeyCode, KeyInput},
+    types::{color::Color, vec2::Vec2},
+    ui::{
+        UIElement,
+        container::style::ContainerStyle,
+        progress::{BarFill, BarStyle, Orientation, ProgressBar, gauge::{Gauge, GaugeLabel}},
+        style::border::{Border, BorderSet},
+        text::{Text, style::TextStyle},
+    },
+    utils::clear_screen,
+};
+
+fn bordered(title: &str, color: Color) -> Option<ContainerStyle> {
+    let mut border = Border::new(Vec2::zero().into(), Vec2::zero());
+    border.color = Some(color);
+    border.set = Some(BorderSet::ROUNDED);
+    border.title = Some(title.to_string());
+    Some(ContainerStyle::new(Some(border)))
+}
+
+fn main() -> Result<()> {
+    let mut is_running = true;
+    color_eyre::install().unwrap();
+    let mut stdout = stdout();
+
+    let mut hp = Gauge::new(Vec2::new(2.0, 1.0).into(), Vec2::new(40.0, 3.0), 40.0, 40.0);
+    hp.label = GaugeLabel::Value;
+    hp.style = bordered("HP", Color::RED);
+    hp.bar.fill = Some(BarFill::Gradient { from: Color::RED, to: Color::GREEN });
+    hp.bar.empty_char = ' ';
+
+    let mut mana = Gauge::new(Vec2::new(2.0, 4.0).into(), Vec2::new(40.0, 3.0), 10.0, 10.0);
+    mana.label = GaugeLabel::None;
+    mana.style = bordered("Mana", Color::BLUE);
+    mana.bar.fill = Some(BarFill::Solid(Color::BLUE));
+    mana.bar.style = BarStyle::Segmented { length: 3, gap: 1 };
+
+    let mut loading = ProgressBar::new(Vec2::new(2.0, 8.0).into(), Vec2::new(40.0, 1.0), 0.0);
+
+    let mut levels: Vec<ProgressBar> = (0..8)
+        .map(|i| {
+            let mut bar = ProgressBar::new(Vec2::new(46.0 + i as f32 * 3.0, 1.0).into(), Vec2::new(2.0, 8.0), 0.0);
+            bar.orientation = Orientation::Vertical;
+            bar.fill = Some(BarFill::Gradient { from: Color::GREEN, to: Color::RED });
+            bar.empty_char = ' ';
+            bar
+        })
+        .collect();
+
+    let mut key_input = KeyInput::new();
+    let start = Instant::now();
+
+    enable_raw_mode().unwrap();
+    while is_running {
+        clear_screen(&mut stdout)?;
+        key_input.begin_frame();
+
+        let t = start.elapsed().as_secs_f32();
+        hp.value = 20.0 + 20.0 * (t * 0.7).cos();
+        mana.value = (10.0 - t * 2.0).rem_euclid(11.0).floor();
+        loading.ratio = (t / 8.0).fract();
+        loading.label = Some(format!("loading... {:.0}%", loading.ratio * 100.0));
+        for (i, bar) in levels.iter_mut().enumerate() {
+            bar.ratio = 0.5 + 0.5 * (t * 2.0 + i as f32).sin();
+        }
+
+        hp.update();
+        mana.update();
+        hp.draw();
+        mana.draw();
+        loading.draw();
+        for bar in &levels {
+            bar.draw();
+        }
+
+        if key_input.is_pressed(&KeyCode::Char('c')) {
+            color_depth::set(match color_depth::current() {
+                ColorDepth::TrueColor => ColorDepth::Ansi256,
+                ColorDepth::Ansi256 => ColorDepth::Ansi16,
+                ColorDepth::Ansi16 => ColorDepth::TrueColor,
+            });
+        }
+        if key_input.is_pressed(&KeyCode::Char('d')) {
+            color_depth::set_dither(match color_depth::dither() {
+                Dither::None => Dither::Ordered,
+                Dither::Ordered => Dither::FloydSteinberg,
+                Dither::FloydSteinberg => Dither::None,
+            });
+        }
+        let mut help = Text::new(
+            Vec2::new(2.0, 10.0).into(),
+            Vec2::new(60.0, 1.0),
+            format!("{:?}, {:?} dithering (c/d to change, q to quit)", color_depth::current(), color_depth::dither()),
+        );
+        help.text_style = Some(TextStyle::default());
+        help.draw();
+
+        if key_input.is_down(&KeyCode::Char('q')) { is_running = false }
+
+        sleep(Duration::from_millis(16));
+    }
+    disable_raw_mode().unwrap();
+
+    println!();
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use std::{io::stdout, thread::sleep, time::{Duration, Instant}};
+
+use color_eyre::Result;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
+use ratatui::{
+    layout::Constraint,
+    style::{Style, Stylize},
+    widgets::{Block, Paragraph, Row, Sparkline, Table},
+};
+use rastor::{
+    key::{KeyCode, KeyInput},
+    shapes::{Shape, circle::Circle},
+    types::{color::Color, vec2::Vec2},
+    ui::{UIElement, interop::RatatuiWidget},
+    utils::clear_screen,
+};
+
+fn main() -> Result<()> {
+    let mut is_running = true;
+    color_eyre::install().unwrap();
+    let mut stdout = stdout();
+
+    let mut planet = Circle::new(Vec2::new(60.0, 10.0).into(), 6.0, 64, Color::BLUE);
+
+    let mut stats = RatatuiWidget::new(Vec2::new(1.0, 1.0).into(), Vec2::new(34.0, 8.0), Table::default());
+    let mut fps = RatatuiWidget::new(Vec2::new(1.0, 9.0).into(), Vec2::new(34.0, 5.0), Sparkline::default());
+    let mut help = RatatuiWidget::new(
+        Vec2::new(1.0, 14.0).into(),
+        Vec2::new(34.0, 3.0),
+        Paragraph::new("press q to quit".dark_gray()).block(Block::bordered()),
+    );
+    help.transparent = true;
+
+    let mut key_input = KeyInput::new();
+    let mut frame_times: Vec<u64> = Vec::new();
+    let mut last_frame = Instant::now();
+
+    enable_raw_mode().unwrap();
+    while is_running {
+        clear_screen(&mut stdout)?;
+        key_input.begin_frame();
+
+        let frame_time = last_frame.elapsed();
+        last_frame = Instant::now();
+        frame_times.push(frame_time.as_millis() as u64);
+        if frame_times.len() > 32 {
+            frame_times.remove(0);
+        }
+
+        let rows = [
+            ("frame time", format!("{frame_time:.1?}")),
+            ("planet x", format!("{:.1}", Vec2::<f32>::from(planet.pos()).x)),
+            ("frames kept", frame_times.len().to_string()),
+        ];
+        stats.widget = Table::new(
+            rows.map(|(name, value)| Row::new([name.to_string(), value])),
+            [Constraint::Length(12), Constraint::Fill(1)],
+        )
+        .header(Row::new(["stat", "value"]).style(Style::new().bold().yellow()))
+        .block(Block::bordered().title("debug"));
+        fps.widget = Sparkline::default()
+            .data(&frame_times)
+            .style(Style::new().green())
+            .block(Block::bordered().title("frame times"));
+
+        planet.update();
+        planet.draw();
+        stats.draw();
+        fps.draw();
+        help.draw();
+
+        if key_input.is_down(&KeyCode::Char('q')) { is_running = false }
+
+        sleep(Duration::from_millis(16));
+    }
+    disable_raw_mode().unwrap();
+
+    println!();
+    Ok(())
+}
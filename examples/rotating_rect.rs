@@ -1,16 +1,13 @@
 use color_eyre::Result;
 use crossterm::{
-    cursor::MoveTo,
     event::KeyCode,
-    execute,
-    style::Color,
-    terminal::{Clear, ClearType, disable_raw_mode, enable_raw_mode},
+    terminal::{disable_raw_mode, enable_raw_mode},
 };
 use rastor::{
     key::KeyInput,
-    shapes::{Shape, rectangle::Rectangle},
-    types::vec2::Vec2,
-    utils::get_terminal_size,
+    shapes::{Shape, antialias::AntiAliasing, rectangle::Rectangle},
+    types::{color::Color, vec2::Vec2},
+    utils::{clear_screen, get_terminal_size},
 };
 use std::{f32::consts::PI, io::stdout};
 
@@ -21,16 +18,21 @@ fn main() -> Result<()> {
 
     let term_size = get_terminal_size()?;
     let initial_pos = term_size / Vec2::splat(2);
-    let mut rect = Rectangle::new(initial_pos.to_f32().into(), Vec2::splat(10.0), Color::Green);
+    let mut rect = Rectangle::new(initial_pos.to_f32().into(), Vec2::splat(10.0), Color::GREEN);
 
     let rad = PI / 16.0;
 
+    // Press 'a' to smooth the edges in different ways.
+    let modes = [AntiAliasing::Off, AntiAliasing::Blend, AntiAliasing::Shade, AntiAliasing::Quadrants];
+    let mut mode = 0;
+
     // create KeyInput to handle key events
     let mut keys = KeyInput::new();
 
     enable_raw_mode().unwrap();
     while is_running {
-        execute!(stdout, Clear(ClearType::All), MoveTo(0, 0)).unwrap();
+        clear_screen(&mut stdout)?;
+        keys.begin_frame();
 
         rect.draw();
         rect.update();
@@ -41,6 +43,10 @@ fn main() -> Result<()> {
 
         // check for 'q' press using KeyInput
         if keys.is_pressed(&KeyCode::Char('q')) { is_running = false }
+        if keys.is_pressed(&KeyCode::Char('a')) {
+            mode = (mode + 1) % modes.len();
+            rect.style.antialiasing = modes[mode];
+        }
     }
     disable_raw_mode().unwrap();
 
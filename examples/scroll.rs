@@ -0,0 +1,78 @@
+use std::{io::stdout, thread::sleep, time::Duration};
+
+use color_eyre::Result;
+use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode},
+};
+use rastor::{
+    key::{KeyCode, KeyInput},
+    types::{color::Color, vec2::Vec2},
+    ui::{
+        UIElement,
+        container::style::ContainerStyle,
+        list::ListView,
+        scroll::ScrollView,
+        style::border::{Border, BorderSet},
+        text::{Text, style::TextStyle},
+    },
+    utils::clear_screen,
+};
+
+fn panel(title: &str) -> ContainerStyle {
+    let mut border = Border::new(Vec2::zero().into(), Vec2::zero());
+    border.color = Some(Color::WHITE);
+    border.set = Some(BorderSet::ROUNDED);
+    border.title = Some(title.to_string());
+    ContainerStyle::new(Some(border))
+}
+
+fn main() -> Result<()> {
+    let mut is_running = true;
+    color_eyre::install().unwrap();
+    let mut stdout = stdout();
+
+    let players = (1..=200).map(|i| format!("{i:>3}. player_{i:<8} {:>6}", 100_000 / i)).collect();
+    let mut leaderboard = ListView::new(Vec2::new(2.0, 1.0).into(), Vec2::new(32.0, 16.0), players);
+    leaderboard.style = Some(panel("Leaderboard"));
+    leaderboard.focused = true;
+    leaderboard.select(0);
+
+    let mut log = ScrollView::new(Vec2::new(36.0, 1.0).into(), Vec2::new(40.0, 16.0));
+    log.style = Some(panel("Changelog"));
+    for i in 1..=30 {
+        let mut entry = Text::new(
+            Vec2::zero().into(),
+            Vec2::new(0.0, 2.0),
+            format!("v0.{i}: fixed a bug that was introduced in v0.{}, probably", i - 1),
+        );
+        entry.text_style = Some(TextStyle::new(1, if i % 2 == 0 { Color::CYAN } else { Color::WHITE }, Color::RESET));
+        log.add_child(Box::new(entry));
+    }
+
+    let mut key_input = KeyInput::new();
+
+    enable_raw_mode().unwrap();
+    execute!(stdout, EnableMouseCapture).unwrap();
+    while is_running {
+        clear_screen(&mut stdout)?;
+        key_input.begin_frame();
+
+        leaderboard.handle_input(&mut key_input);
+        log.handle_input(&mut key_input);
+        leaderboard.update();
+        log.update();
+        leaderboard.draw();
+        log.draw();
+
+        if key_input.is_down(&KeyCode::Char('q')) { is_running = false }
+
+        sleep(Duration::from_millis(16));
+    }
+    execute!(stdout, DisableMouseCapture).unwrap();
+    disable_raw_mode().unwrap();
+
+    println!();
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use std::{io::stdout, thread::sleep, time::{Duration, Instant}};
+
+use crossterm::{event::KeyCode, terminal::{disable_raw_mode, enable_raw_mode}};
+use rastor::{
+    key::KeyInput,
+    shapes::{Shape, circle::Circle, rectangle::Rectangle},
+    types::{color::{BlendMode, Color}, vec2::Vec2},
+    ui::{UIElement, text::{Text, style::TextStyle}},
+    utils::{clear_screen, get_terminal_size},
+};
+
+fn main() -> color_eyre::Result<()> {
+    let term_size = get_terminal_size()?.to_f32();
+
+    // The scene: a sandy beach with a red hut on it.
+    let mut sand = Rectangle::new((term_size / 2.0).into(), term_size, Color::rgb(194, 178, 128));
+    let mut hut = Rectangle::new(Vec2::new(term_size.x * 0.3, term_size.y * 0.6).into(), Vec2::new(4.0, 6.0), Color::rgb(160, 40, 30));
+
+    // Water washing over the beach, half see-through.
+    let mut water = Rectangle::new(Vec2::zero().into(), Vec2::new(term_size.y, term_size.x / 6.0), Color::rgb(30, 90, 200));
+    water.style.opacity = 0.5;
+
+    // A cloud's shadow darkening whatever it passes over.
+    let mut shadow = Circle::new(Vec2::zero().into(), 5.0, 24, Color::rgb(120, 120, 140));
+    shadow.style.blend = BlendMode::Multiply;
+
+    // A lantern lighting things up around the hut.
+    let mut glow = Circle::new(hut.pos, 4.0, 24, Color::rgb(90, 60, 0));
+    glow.style.blend = BlendMode::Add;
+
+    // A sign on the hut. It has no background of its own, so it takes the
+    // colours of whatever is drawn below it.
+    let mut sign = Text::new((Vec2::from(hut.pos) - Vec2::new(1.0, 1.0)).into(), Vec2::new(4.0, 1.0), "INN".to_string());
+    sign.text_style = Some(TextStyle { fg_color: Some(Color::rgb(255, 220, 120)), ..Default::default() });
+
+    let mut stdout = stdout().lock();
+    let mut is_running = true;
+    let mut key_input = KeyInput::new();
+    let start = Instant::now();
+
+    enable_raw_mode().unwrap();
+    while is_running {
+        clear_screen(&mut stdout)?;
+        key_input.begin_frame();
+
+        let t = start.elapsed().as_secs_f32();
+        water.pos = Vec2::new(term_size.x * (0.75 + 0.05 * t.sin()), term_size.y / 2.0).into();
+        shadow.center = Vec2::new((t * 8.0) % term_size.x, term_size.y * 0.4).into();
+
+        for shape in [&mut sand as &mut dyn Shape, &mut hut, &mut glow, &mut water, &mut shadow] {
+            shape.update();
+            shape.draw();
+        }
+        sign.draw();
+
+        if key_input.is_pressed(&KeyCode::Char('q')) { is_running = false }
+
+        sleep(Duration::from_millis(16));
+    }
+    disable_raw_mode().unwrap();
+
+    Ok(())
+}
@@ -1,14 +1,9 @@
 use std::{io::stdout, thread::sleep, time::Duration};
 
 use color_eyre::Result;
-use crossterm::{
-    cursor::MoveTo,
-    execute,
-    style::Color,
-    terminal::{Clear, ClearType, disable_raw_mode, enable_raw_mode},
-};
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode};
 use rastor::{
-    key::{KeyCode, KeyInput}, shapes::{Shape, rectangle::Rectangle}, types::vec2::Vec2, ui::{UIElement, container::{UIContainer, style::ContainerStyle}, style::border::{Border, BorderStyle}, text::{Text, style::TextStyle}}, utils::get_terminal_size
+    key::{KeyCode, KeyInput}, types::{color::Color, vec2::Vec2}, ui::{UIElement, container::{UIContainer, style::ContainerStyle}, layout::{Align, Direction, Justify, Layout, LayoutItem, Sizing, Spacing}, style::border::{Border, BorderSet, TitleAlign}, text::{Text, style::{TextAlign, TextStyle}}}, utils::clear_screen
 };
 
 fn main() -> Result<()> {
@@ -16,21 +11,61 @@ fn main() -> Result<()> {
     color_eyre::install().unwrap();
     let mut stdout = stdout();
 
-    let term_size = get_terminal_size()?;
-    let initial_pos = term_size / Vec2::splat(2);
-    let mut container = UIContainer::new(initial_pos.to_f32().into(), Vec2::new(20.0, 10.0));
-    let mut text = Text::new(initial_pos.to_f32().into(), Vec2::new(2.0, 2.0), String::from("Hello, World!"));
-    text.text_style = Some(TextStyle::new(text.size.x as u16, Color::Green, Color::Black));
-    container.add_child(Box::new(text.clone()));
+    let mut container = UIContainer::new(Vec2::zero().into(), Vec2::zero());
+    container.layout_item = LayoutItem::new(Sizing::Percent(50.0), Sizing::Percent(50.0));
+    container.layout_item.margin = Spacing::symmetric(2.0, 4.0);
+    container.layout_item.max_size = Vec2::new(60.0, 20.0);
+    container.layout = Some(Layout {
+        direction: Direction::Column,
+        gap: 1.0,
+        align: Align::Stretch,
+        justify: Justify::Center,
+        ..Default::default()
+    });
+
+    let mut title = Text::new(Vec2::zero().into(), Vec2::new(0.0, 1.0), String::from("Hello, World!"));
+    let mut title_style = TextStyle::new(1, Color::GREEN, Color::RESET);
+    title_style.align = TextAlign::Center;
+    title_style.attributes.bold = true;
+    title.text_style = Some(title_style);
+    container.add_child(Box::new(title));
+
+    let mut score = Text::new(Vec2::zero().into(), Vec2::new(0.0, 5.0), String::from("1337"));
+    let mut score_style = TextStyle::new(2, Color::YELLOW, Color::RESET);
+    score_style.align = TextAlign::Center;
+    score.text_style = Some(score_style);
+    container.add_child(Box::new(score));
+
+    let mut status = Text::from_markup(
+        Vec2::zero().into(),
+        Vec2::new(0.0, 2.0),
+        "HP [red]12/40[/]  [b yellow]Warning:[/b] [i]the terminal is not a real screen[/i]",
+    )?;
+    status.text_style = Some(TextStyle::new(1, Color::WHITE, Color::RESET));
+    container.add_child(Box::new(status));
+
+    let mut hint = Text::new(Vec2::zero().into(), Vec2::new(0.0, 1.0), String::from("press q to quit"));
+    hint.text_style = Some(TextStyle::new(1, Color::DARK_GREY, Color::RESET));
+    hint.layout_item.align_self = Some(Align::End);
+    hint.layout_item.width = Sizing::Fixed(16.0);
+    container.add_child(Box::new(hint));
 
-    container.style = Some(ContainerStyle::new(Some(Border::new(container.pos, container.size.x, Color::White, BorderStyle::Solid))));
+    let mut border = Border::new(container.pos, container.size);
+    border.color = Some(Color::WHITE);
+    border.set = Some(BorderSet::ROUNDED);
+    border.title = Some(String::from("rastor"));
+    border.title_align = TitleAlign::Center;
+    container.style = Some(ContainerStyle::new(Some(border)));
 
     let mut key_input = KeyInput::new();
 
     enable_raw_mode().unwrap();
     while is_running {
-        execute!(stdout, Clear(ClearType::All), MoveTo(0, 0)).unwrap();
+        clear_screen(&mut stdout)?;
+        key_input.begin_frame();
 
+        container.fit_to_terminal()?;
+        container.update();
         container.draw();
 
         if key_input.is_down(&KeyCode::Char('q')) { is_running = false }
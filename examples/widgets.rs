@@ -0,0 +1,142 @@
+use std::{cell::{Cell, RefCell}, io::stdout, rc::Rc, thread::sleep, time::Duration};
+
+use color_eyre::Result;
+use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode},
+};
+use rastor::{
+    key::{KeyCode, KeyInput},
+    types::vec2::Vec2,
+    ui::{
+        UIElement,
+        button::Button,
+        container::UIContainer,
+        help::HelpOverlay,
+        layout::{Align, Direction, Layout, LayoutItem, Sizing},
+        menu::{Menu, MenuBar, MenuItem},
+        root::UIRoot,
+        select::Select,
+        tabs::TabView,
+        text::{Text, style::TextStyle},
+        theme::{self, Theme},
+    },
+    utils::clear_screen,
+};
+
+fn page() -> UIContainer {
+    let mut page = UIContainer::new(Vec2::zero().into(), Vec2::zero());
+    page.layout = Some(Layout {
+        direction: Direction::Column,
+        gap: 1.0,
+        padding: theme::current().padding,
+        align: Align::Start,
+        ..Default::default()
+    });
+    page
+}
+
+fn label(content: &str) -> Box<Text> {
+    let mut text = Text::new(Vec2::zero().into(), Vec2::new(50.0, 1.0), String::from(content));
+    text.text_style = Some(TextStyle::default());
+    Box::new(text)
+}
+
+fn main() -> Result<()> {
+    color_eyre::install().unwrap();
+    let mut stdout = stdout();
+
+    let quit = Rc::new(Cell::new(false));
+    let status = Rc::new(RefCell::new(String::from("F10 opens the menu, tab moves focus, ? lists the keys")));
+
+    let mut root = UIRoot::new();
+    root.container.layout = Some(Layout { direction: Direction::Column, align: Align::Stretch, ..Default::default() });
+    let mut help = HelpOverlay::default();
+    help.bind(KeyCode::Tab, "Focus the next element");
+    help.bind(KeyCode::BackTab, "Focus the previous element");
+    root.help = Some(help);
+
+    let say = |message: &'static str| {
+        let status = Rc::clone(&status);
+        move || *status.borrow_mut() = String::from(message)
+    };
+    let on_quit = Rc::clone(&quit);
+    let mut quit_item = MenuItem::new("Quit", move || on_quit.set(true));
+    quit_item.accelerator = Some(KeyCode::Char('q'));
+    let mut save = MenuItem::new("Save", say("saved"));
+    save.accelerator = Some(KeyCode::Char('s'));
+    let mut redo = MenuItem::new("Redo", say("nothing to redo"));
+    redo.enabled = false;
+    let themes = vec![
+        MenuItem::new("Dark", || theme::set(Theme::dark())),
+        MenuItem::new("Light", || theme::set(Theme::light())),
+        MenuItem::new("High contrast", || theme::set(Theme::high_contrast())),
+    ];
+    let mut menu = MenuBar::new(Vec2::zero().into(), 0.0, vec![
+        Menu::new("File", vec![MenuItem::new("New", say("new file")), save, quit_item]),
+        Menu::new("Edit", vec![MenuItem::new("Undo", say("undone")), redo]),
+        Menu::new("View", vec![MenuItem::submenu("Theme", themes), MenuItem::new("Zoom", say("zoomed"))]),
+    ]);
+    menu.layout_item.width = Sizing::Fill(1.0);
+    root.container.add_child(Box::new(menu));
+
+    let mut tabs = TabView::new(Vec2::zero().into(), Vec2::zero());
+    tabs.layout_item = LayoutItem::new(Sizing::Fill(1.0), Sizing::Fill(1.0));
+
+    let mut general = page();
+    general.add_child(label("Difficulty"));
+    let mut difficulty = Select::new(
+        Vec2::zero().into(),
+        24.0,
+        ["Easy", "Normal", "Hard", "Nightmare"].map(String::from).to_vec(),
+    );
+    difficulty.placeholder = String::from("choose one");
+    difficulty.tooltip = Some(String::from("How hard the enemies hit. Can be changed at any time."));
+    let on_change = Rc::clone(&status);
+    difficulty.on_change = Some(Box::new(move |i| *on_change.borrow_mut() = format!("difficulty {i}")));
+    general.add_child(Box::new(difficulty));
+    general.add_child(label("Level"));
+    let mut level = Select::new(Vec2::zero().into(), 24.0, (1..=30).map(|i| format!("Level {i}")).collect());
+    level.selected = Some(0);
+    general.add_child(Box::new(level));
+    tabs.add_tab("General", general);
+
+    let mut audio = page();
+    audio.add_child(label("Nothing to see here yet"));
+    let mut mute = Button::new(Vec2::zero().into(), String::from("Mute"));
+    mute.on_click = Some(Box::new(say("muted")));
+    mute.tooltip = Some(String::from("Silence all sounds"));
+    audio.add_child(Box::new(mute));
+    tabs.add_tab("Audio", audio);
+
+    let mut about = page();
+    about.add_child(label("rastor widgets example"));
+    tabs.add_tab("About", about);
+    root.container.add_child(Box::new(tabs));
+
+    let mut key_input = KeyInput::new();
+
+    enable_raw_mode().unwrap();
+    execute!(stdout, EnableMouseCapture).unwrap();
+    while !quit.get() {
+        clear_screen(&mut stdout)?;
+        key_input.begin_frame();
+
+        root.handle_input(&mut key_input);
+        root.update();
+        root.draw();
+
+        let size = root.container.size;
+        let mut footer = Text::new(Vec2::new(2.0, size.y - 1.0).into(), Vec2::new(size.x - 4.0, 1.0), status.borrow().clone());
+        footer.text_style = Some(TextStyle::default());
+        footer.draw();
+
+        sleep(Duration::from_millis(16));
+    }
+    execute!(stdout, DisableMouseCapture).unwrap();
+    disable_raw_mode().unwrap();
+
+    println!();
+    Ok(())
+}
@@ -1,17 +1,14 @@
 use std::{io::stdout, thread::sleep, time::Duration};
 
 use crossterm::{
-    cursor::MoveTo,
     event::KeyCode,
-    execute,
-    style::Color,
-    terminal::{Clear, ClearType, disable_raw_mode, enable_raw_mode},
+    terminal::{disable_raw_mode, enable_raw_mode},
 };
 use rastor::{
     key::KeyInput,
     shapes::{Shape, rectangle::Rectangle},
-    types::vec2::Vec2,
-    utils::get_terminal_size,
+    types::{color::Color, vec2::Vec2},
+    utils::{clear_screen, get_terminal_size},
 };
 
 fn main() -> color_eyre::Result<()> {
@@ -21,35 +18,35 @@ fn main() -> color_eyre::Result<()> {
     let mut parent_rect = Rectangle::new(
         initial_pos.to_f32().into(),
         Vec2::splat(10.0),
-        Color::White
+        Color::WHITE
     );
     let mut rectangles = Vec::new();
     rectangles.push(Box::new(Rectangle::new(
         // initial_pos.to_f32(),
         Vec2::zero().into(),
         Vec2::splat(5.0),
-        Color::Green,
+        Color::GREEN,
     )));
     rectangles.push(Box::new(Rectangle::new(
         // initial_pos.to_f32(),
         (Vec2::zero() + 3.0).into(),
         Vec2::splat(5.0),
-        Color::Blue,
+        Color::BLUE,
     )));
-    rectangles[1].z_index = 10; // second rectangle = more important
+    rectangles[1].style.z_index = 10; // second rectangle = more important
     rectangles.push(Box::new(Rectangle::new(
         (Vec2::zero() + 6.0).into(),
         Vec2::splat(5.0),
-        Color::Yellow,
+        Color::YELLOW,
     )));
-    rectangles[2].z_index = 20; // third rectangle = more important
+    rectangles[2].style.z_index = 20; // third rectangle = more important
     rectangles.push(Box::new(Rectangle::new(
         (Vec2::zero() + 9.0).into(),
         Vec2::splat(5.0),
-        Color::Magenta,
+        Color::MAGENTA,
     )));
-    rectangles[3].z_index = -10; // fourth rectangle = least important
-    rectangles.sort_by_key(|rect| rect.z_index);
+    rectangles[3].style.z_index = -10; // fourth rectangle = least important
+    rectangles.sort_by_key(|rect| rect.style.z_index);
 
     for rect in &rectangles {
         parent_rect.push(rect.clone());
@@ -63,7 +60,8 @@ fn main() -> color_eyre::Result<()> {
 
     enable_raw_mode().unwrap();
     while is_running {
-        execute!(stdout, Clear(ClearType::All), MoveTo(0, 0)).unwrap();
+        clear_screen(&mut stdout)?;
+        key_input.begin_frame();
 
         parent_rect.draw();
         parent_rect.update();
@@ -1,10 +1,13 @@
+// The whole module is kept around for reference only, see the deprecation note below.
+#![allow(deprecated)]
+
 use std::sync::atomic::{AtomicU8, Ordering};
 
 use crate::utils::get_terminal_size;
 use crossterm::{
     cursor::MoveTo,
     queue,
-    style::{Color, Print, SetForegroundColor},
+    style::{Color, SetForegroundColor},
 };
 use lazy_static::lazy_static;
 
@@ -30,7 +33,7 @@ pub struct FrameBuffer {
 impl FrameBuffer {
     pub fn new(width: usize, height: usize) -> Self {
         let data = (0..width * height)
-            .map(|_| AtomicU8::new(' ' as u8))
+            .map(|_| AtomicU8::new(b' '))
             .collect();
         FrameBuffer {
             width,
@@ -53,7 +56,7 @@ impl FrameBuffer {
         queue!(out, MoveTo(x as u16, y as u16)).unwrap();
     }
 
-    pub fn render(&self, out: &mut impl std::io::Write) {
+    pub fn render(&self, _out: &mut impl std::io::Write) {
         // let mut output = String::new();
         // for y in 0..self.height {
         //     let start = y * self.width;
@@ -0,0 +1,39 @@
+//! A stack of clipping rectangles. While a rect is pushed, drawing code
+//! (`flush_pixels`, `Text`, ...) drops anything that falls outside of it.
+
+use std::cell::RefCell;
+
+use crate::types::{rect::Rect, vec2::Vec2};
+
+thread_local! {
+    static CLIP_STACK: RefCell<Vec<Rect>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Restrict drawing to `rect`, intersected with the current clip rect.
+/// Every call must be matched by a call to `pop`.
+pub fn push(rect: Rect) {
+    CLIP_STACK.with_borrow_mut(|stack| {
+        let clipped = match stack.last() {
+            // Nothing overlaps, so nothing may be drawn at all.
+            Some(current) => current.intersect(&rect).unwrap_or(Rect::new(rect.pos, Vec2::zero())),
+            None => rect,
+        };
+        stack.push(clipped);
+    });
+}
+
+pub fn pop() {
+    CLIP_STACK.with_borrow_mut(|stack| {
+        stack.pop();
+    });
+}
+
+/// The area drawing is currently restricted to, if any.
+pub fn current() -> Option<Rect> {
+    CLIP_STACK.with_borrow(|stack| stack.last().copied())
+}
+
+/// Whether the terminal cell at (`x`, `y`) may be drawn to.
+pub fn is_visible(x: u16, y: u16) -> bool {
+    current().is_none_or(|clip| clip.contains(Vec2::new(x as f32, y as f32)))
+}
@@ -0,0 +1,398 @@
+//! How many colours the terminal can show, and turning `Color`s into
+//! colours it understands. The depth is detected from the environment the
+//! first time it is needed, unless it was `set` before.
+
+use std::{collections::HashMap, env, fs, path::PathBuf, str::FromStr};
+
+use color_eyre::{Report, Result, eyre::eyre};
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+
+use crate::types::{color::Color, palette::Palette};
+
+lazy_static! {
+    static ref SETTINGS: RwLock<Option<(ColorDepth, Dither)>> = RwLock::new(None);
+}
+
+/// The colours a terminal supports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorDepth {
+    /// Any 24-bit RGB colour.
+    TrueColor,
+    /// The xterm 256 colour palette: the 16 ANSI colours, a 6x6x6 colour cube
+    /// and a greyscale ramp.
+    Ansi256,
+    /// Only the 16 ANSI colours.
+    Ansi16,
+}
+
+/// How colours that fall between the ones the terminal has are approximated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Dither {
+    /// Use the closest colour.
+    #[default]
+    None,
+    /// Nudge colours by a repeating 4x4 Bayer pattern before picking the
+    /// closest one, which gives gradients a regular cross-hatched look.
+    Ordered,
+    /// Spread each pixel's rounding error over its neighbours to the right
+    /// and below. Smoother than `Ordered`, but the pattern can shift from
+    /// frame to frame as things move.
+    FloydSteinberg,
+}
+
+/// The colour depth in use: the one `set`, or else the detected one.
+pub fn current() -> ColorDepth {
+    settings().0
+}
+
+/// Override the detected colour depth.
+pub fn set(depth: ColorDepth) {
+    let dither = dither();
+    *SETTINGS.write() = Some((depth, dither));
+}
+
+/// The dithering used for pixels. Text is never dithered.
+pub fn dither() -> Dither {
+    settings().1
+}
+
+pub fn set_dither(dither: Dither) {
+    let depth = current();
+    *SETTINGS.write() = Some((depth, dither));
+}
+
+fn settings() -> (ColorDepth, Dither) {
+    if let Some(settings) = *SETTINGS.read() {
+        return settings;
+    }
+    let settings = (ColorDepth::detect(), Dither::None);
+    *SETTINGS.write() = Some(settings);
+    settings
+}
+
+impl ColorDepth {
+    /// Guess the depth from the environment, in this order:
+    /// - `RASTOR_COLOR_DEPTH` (`truecolor`, `256` or `16`), to override it,
+    /// - `COLORTERM` set to `truecolor` or `24bit`, or running in Windows
+    ///   Terminal,
+    /// - `TERM` mentioning `direct`, `truecolor` or `256color`,
+    /// - the `colors` capability of `TERM`'s terminfo entry,
+    /// - and finally 16 colours, which every colour terminal has.
+    pub fn detect() -> Self {
+        let var = |name: &str| env::var(name).unwrap_or_default().to_ascii_lowercase();
+
+        if let Ok(depth) = var("RASTOR_COLOR_DEPTH").parse() {
+            return depth;
+        }
+        let colorterm = var("COLORTERM");
+        if colorterm == "truecolor" || colorterm == "24bit" || env::var_os("WT_SESSION").is_some() {
+            return Self::TrueColor;
+        }
+
+        let term = var("TERM");
+        if term.contains("direct") || term.contains("truecolor") {
+            return Self::TrueColor;
+        }
+        if term.contains("256color") {
+            return Self::Ansi256;
+        }
+        match terminfo_colors(&term) {
+            Some(colors) if colors >= 1 << 24 => Self::TrueColor,
+            Some(colors) if colors >= 256 => Self::Ansi256,
+            _ => Self::Ansi16,
+        }
+    }
+
+    /// The closest colour this depth can show. Transparent colours are kept
+    /// as they are, since they stand for the terminal's default colour.
+    pub fn nearest(self, color: Color) -> Color {
+        self.quantize(color).0
+    }
+
+    /// The closest colour this depth can show along with what to send to
+    /// the terminal for it.
+    fn quantize(self, color: Color) -> (Color, crossterm::style::Color) {
+        use crossterm::style::Color as C;
+        if color.is_transparent() {
+            return (color, C::Reset);
+        }
+        match self {
+            Self::TrueColor => (color, C::Rgb { r: color.r, g: color.g, b: color.b }),
+            Self::Ansi256 => {
+                let code = nearest_ansi256(color);
+                (Color::ansi(code).with_alpha(color.a), C::AnsiValue(code))
+            }
+            Self::Ansi16 => {
+                const NAMED: [C; 16] = [
+                    C::Black, C::DarkRed, C::DarkGreen, C::DarkYellow,
+                    C::DarkBlue, C::DarkMagenta, C::DarkCyan, C::Grey,
+                    C::DarkGrey, C::Red, C::Green, C::Yellow,
+                    C::Blue, C::Magenta, C::Cyan, C::White,
+                ];
+                let index = Palette::ANSI.nearest_index(color);
+                (Color::ANSI[index].with_alpha(color.a), NAMED[index])
+            }
+        }
+    }
+
+    /// About how far apart neighbouring colours are, per channel. Ordered
+    /// dithering nudges colours by up to half of this.
+    fn step(self) -> f32 {
+        match self {
+            Self::TrueColor => 0.0,
+            Self::Ansi256 => 40.0,
+            Self::Ansi16 => 128.0,
+        }
+    }
+
+    /// What to send to the terminal for `color`.
+    pub fn to_crossterm(self, color: Color) -> crossterm::style::Color {
+        self.quantize(color).1
+    }
+}
+
+/// Accepts `truecolor` (or `24bit`), `256` and `16`.
+impl FromStr for ColorDepth {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "truecolor" | "24bit" => Ok(Self::TrueColor),
+            "256" => Ok(Self::Ansi256),
+            "16" => Ok(Self::Ansi16),
+            _ => Err(eyre!("unknown colour depth `{s}`")),
+        }
+    }
+}
+
+/// The closest colour in the xterm colour cube or greyscale ramp. The first
+/// 16 colours are left out because terminals let users change them.
+fn nearest_ansi256(color: Color) -> u8 {
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    let distance = |other: Color| {
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        d(color.r, other.r) + d(color.g, other.g) + d(color.b, other.b)
+    };
+    let level = |c: u8| (0..6).min_by_key(|&i| (LEVELS[i] as i32 - c as i32).abs()).unwrap() as u8;
+    let cube = 16 + 36 * level(color.r) + 6 * level(color.g) + level(color.b);
+
+    let average = (color.r as u32 + color.g as u32 + color.b as u32) / 3;
+    let grey = 232 + ((average.saturating_sub(3)) / 10).min(23) as u8;
+
+    if distance(Color::ansi(grey)) < distance(Color::ansi(cube)) { grey } else { cube }
+}
+
+/// Turns the colours of a batch of pixels into terminal colours, dithering
+/// them with the current settings. Pixels have to be passed in row-major
+/// order for Floyd–Steinberg to work.
+pub struct Quantizer {
+    depth: ColorDepth,
+    dither: Dither,
+    /// Error carried over to not yet visited cells, per channel.
+    errors: HashMap<(u16, u16), [f32; 3]>,
+}
+
+impl Default for Quantizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Quantizer {
+    pub fn new() -> Self {
+        let (depth, dither) = settings();
+        Self { depth, dither, errors: HashMap::new() }
+    }
+
+    /// Forget the carried error, e.g. before starting on another layer.
+    pub fn reset(&mut self) {
+        self.errors.clear();
+    }
+
+    /// The terminal colour for `color` at the cell (`x`, `y`).
+    pub fn quantize(&mut self, color: Color, x: u16, y: u16) -> crossterm::style::Color {
+        if self.depth == ColorDepth::TrueColor || color.is_transparent() {
+            return self.depth.to_crossterm(color);
+        }
+
+        let channels = [color.r, color.g, color.b].map(f32::from);
+        let wanted = match self.dither {
+            Dither::None => channels,
+            Dither::Ordered => {
+                const BAYER: [[f32; 4]; 4] = [[0.0, 8.0, 2.0, 10.0], [12.0, 4.0, 14.0, 6.0], [3.0, 11.0, 1.0, 9.0], [15.0, 7.0, 13.0, 5.0]];
+                let offset = ((BAYER[y as usize % 4][x as usize % 4] + 0.5) / 16.0 - 0.5) * self.depth.step();
+                channels.map(|c| c + offset)
+            }
+            Dither::FloydSteinberg => {
+                let error = self.errors.remove(&(x, y)).unwrap_or_default();
+                [channels[0] + error[0], channels[1] + error[1], channels[2] + error[2]]
+            }
+        };
+
+        let [r, g, b] = wanted.map(|c| c.round().clamp(0.0, 255.0) as u8);
+        let (nearest, output) = self.depth.quantize(Color::rgba(r, g, b, color.a));
+
+        if self.dither == Dither::FloydSteinberg {
+            let error = [wanted[0] - nearest.r as f32, wanted[1] - nearest.g as f32, wanted[2] - nearest.b as f32];
+            for (dx, dy, weight) in [(1, 0, 7.0), (-1, 1, 3.0), (0, 1, 5.0), (1, 1, 1.0)] {
+                let (Some(nx), Some(ny)) = (x.checked_add_signed(dx), y.checked_add_signed(dy)) else { continue };
+                let carried = self.errors.entry((nx, ny)).or_default();
+                for (carried, error) in carried.iter_mut().zip(error) {
+                    *carried += error * weight / 16.0;
+                }
+            }
+        }
+        output
+    }
+}
+
+/// The `colors` capability of `term`'s compiled terminfo entry, if it can be
+/// found.
+fn terminfo_colors(term: &str) -> Option<u32> {
+    if term.is_empty() {
+        return None;
+    }
+    let first = term.chars().next()?;
+
+    let mut dirs: Vec<PathBuf> = Vec::new();
+    if let Some(dir) = env::var_os("TERMINFO") {
+        dirs.push(dir.into());
+    }
+    if let Some(home) = env::var_os("HOME") {
+        dirs.push(PathBuf::from(home).join(".terminfo"));
+    }
+    if let Ok(list) = env::var("TERMINFO_DIRS") {
+        dirs.extend(list.split(':').filter(|dir| !dir.is_empty()).map(PathBuf::from));
+    }
+    dirs.extend(["/etc/terminfo", "/lib/terminfo", "/usr/share/terminfo", "/usr/lib/terminfo"].map(PathBuf::from));
+
+    dirs.iter()
+        .flat_map(|dir| {
+            // Entries are filed by their first letter, or its hex code on macOS.
+            [dir.join(first.to_string()).join(term), dir.join(format!("{:x}", first as u32)).join(term)]
+        })
+        .find_map(|path| fs::read(path).ok())
+        .and_then(|entry| parse_terminfo_colors(&entry))
+}
+
+/// Read `colors` (numeric capability 13) out of a compiled terminfo entry,
+/// in either the legacy or the extended number format.
+fn parse_terminfo_colors(entry: &[u8]) -> Option<u32> {
+    const COLORS: usize = 13;
+    let short = |i: usize| -> Option<u16> { Some(u16::from_le_bytes([*entry.get(i)?, *entry.get(i + 1)?])) };
+
+    let number_size = match short(0)? {
+        0o432 => 2,
+        0o1036 => 4,
+        _ => return None,
+    };
+    let names_size = short(2)? as usize;
+    let bools = short(4)? as usize;
+    let numbers = short(6)? as usize;
+    if COLORS >= numbers {
+        return None;
+    }
+
+    // The numbers start on an even offset after the header, names and booleans.
+    let mut start = 12 + names_size + bools;
+    start += start % 2;
+    let at = start + COLORS * number_size;
+    let value = match number_size {
+        2 => short(at)? as i16 as i32,
+        _ => i32::from_le_bytes(entry.get(at..at + 4)?.try_into().ok()?),
+    };
+    u32::try_from(value).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ansi256_cube() {
+        assert_eq!(nearest_ansi256(Color::BLACK), 16);
+        assert_eq!(nearest_ansi256(Color::RED), 196);
+        assert_eq!(nearest_ansi256(Color::BLUE), 21);
+        assert_eq!(nearest_ansi256(Color::WHITE), 231);
+        assert_eq!(nearest_ansi256(Color::rgb(255, 135, 0)), 208);
+        assert_eq!(nearest_ansi256(Color::rgb(100, 140, 210)), 68);
+    }
+
+    #[test]
+    fn ansi256_grey_ramp() {
+        assert_eq!(nearest_ansi256(Color::rgb(128, 128, 128)), 244);
+        assert_eq!(nearest_ansi256(Color::rgb(8, 8, 8)), 232);
+        assert_eq!(nearest_ansi256(Color::rgb(238, 238, 238)), 255);
+        // Cut-offs halfway between two greys.
+        assert_eq!(nearest_ansi256(Color::rgb(12, 12, 12)), 232);
+        assert_eq!(nearest_ansi256(Color::rgb(14, 14, 14)), 233);
+        // Closer to the cube's black and white than to the ends of the ramp.
+        assert_eq!(nearest_ansi256(Color::rgb(3, 3, 3)), 16);
+        assert_eq!(nearest_ansi256(Color::rgb(250, 250, 250)), 231);
+        // Slightly off grey still goes to the ramp.
+        assert_eq!(nearest_ansi256(Color::rgb(60, 58, 62)), 237);
+    }
+
+    /// A compiled terminfo entry for `xterm` with one boolean and `numbers`,
+    /// stored with 2 bytes each for the legacy magic number and 4 for the
+    /// extended one.
+    fn entry(magic: u16, numbers: &[i32]) -> Vec<u8> {
+        let names = b"xterm\0";
+        let mut entry = Vec::new();
+        for short in [magic, names.len() as u16, 1, numbers.len() as u16, 0, 0] {
+            entry.extend(short.to_le_bytes());
+        }
+        entry.extend(names);
+        entry.push(1);
+        // Numbers start on an even offset: 12 + 6 + 1 is odd.
+        entry.push(0);
+        for &number in numbers {
+            match magic {
+                0o432 => entry.extend((number as i16).to_le_bytes()),
+                _ => entry.extend(number.to_le_bytes()),
+            }
+        }
+        entry
+    }
+
+    fn numbers(colors: i32) -> Vec<i32> {
+        let mut numbers = vec![-1; 15];
+        numbers[0] = 80;
+        numbers[13] = colors;
+        numbers
+    }
+
+    #[test]
+    fn terminfo_legacy_format() {
+        assert_eq!(parse_terminfo_colors(&entry(0o432, &numbers(256))), Some(256));
+        assert_eq!(parse_terminfo_colors(&entry(0o432, &numbers(8))), Some(8));
+        // -1 means the capability is missing.
+        assert_eq!(parse_terminfo_colors(&entry(0o432, &numbers(-1))), None);
+    }
+
+    #[test]
+    fn terminfo_extended_format() {
+        assert_eq!(parse_terminfo_colors(&entry(0o1036, &numbers(1 << 24))), Some(1 << 24));
+        assert_eq!(parse_terminfo_colors(&entry(0o1036, &numbers(256))), Some(256));
+    }
+
+    #[test]
+    fn terminfo_invalid_entries() {
+        assert_eq!(parse_terminfo_colors(&[]), None);
+        assert_eq!(parse_terminfo_colors(&entry(0o433, &numbers(256))), None);
+        // Too few numbers to have `colors`.
+        assert_eq!(parse_terminfo_colors(&entry(0o432, &[80; 13])), None);
+        let mut truncated = entry(0o1036, &numbers(256));
+        truncated.truncate(truncated.len() - 6);
+        assert_eq!(parse_terminfo_colors(&truncated), None);
+    }
+
+    #[test]
+    fn depth_names() {
+        assert_eq!("24bit".parse::<ColorDepth>().unwrap(), ColorDepth::TrueColor);
+        assert_eq!("256".parse::<ColorDepth>().unwrap(), ColorDepth::Ansi256);
+        assert_eq!("16".parse::<ColorDepth>().unwrap(), ColorDepth::Ansi16);
+        assert!("88".parse::<ColorDepth>().is_err());
+    }
+}
@@ -1,54 +1,188 @@
 use crossterm::event;
 use parking_lot::Mutex;
-use std::{collections::HashSet, sync::Arc, time::Duration};
+use std::{collections::{HashSet, VecDeque}, sync::Arc, time::Duration};
+
+use crate::types::vec2::Vec2;
 
 // for export
 pub type KeyCode = event::KeyCode;
+pub type MouseButton = event::MouseButton;
+
+/// Something that happened before the last `KeyInput::begin_frame`. Unlike
+/// the key and button sets, these keep presses that were released again
+/// before anyone looked.
+#[derive(Debug, Clone, Copy)]
+pub enum InputEvent {
+    Key(KeyCode),
+    MouseDown(MouseButton, Vec2<u16>),
+    MouseUp(MouseButton, Vec2<u16>),
+}
+
+/// How many events `KeyInput` keeps around for the next frame.
+const MAX_EVENTS: usize = 256;
+
+/// Mouse events are only reported once mouse capture is enabled, e.g. with
+/// `crossterm::event::EnableMouseCapture`.
+#[derive(Debug, Clone)]
+pub struct MouseState {
+    /// Last known pointer position in terminal cells.
+    pub pos: Vec2<u16>,
+    pub buttons: HashSet<MouseButton>,
+    /// Wheel movement since the last `KeyInput::take_scroll`, positive is down.
+    pub scroll: i32,
+}
+
+impl Default for MouseState {
+    fn default() -> Self {
+        Self {
+            pos: Vec2::new(0, 0),
+            buttons: HashSet::new(),
+            scroll: 0,
+        }
+    }
+}
+
+/// Key presses and releases since the last frame, plus the events they came in.
+#[derive(Default)]
+struct FrameInput {
+    pressed: HashSet<KeyCode>,
+    released: HashSet<KeyCode>,
+    events: VecDeque<InputEvent>,
+}
 
+/// Keyboard and mouse input, read on a background thread. Call `begin_frame`
+/// once at the start of every frame: `is_pressed`, `is_released` and
+/// `take_events` report what happened before it, so every check in a frame
+/// sees the same presses and each press is seen in exactly one frame.
 pub struct KeyInput {
     current: Arc<Mutex<HashSet<KeyCode>>>,
-    previous: Arc<Mutex<HashSet<KeyCode>>>,
+    /// Filled by the poll thread until the next `begin_frame` takes it.
+    pending: Arc<Mutex<FrameInput>>,
+    frame: FrameInput,
+    mouse: Arc<Mutex<MouseState>>,
+}
+
+impl Default for KeyInput {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl KeyInput {
     pub fn new() -> Self {
         let current = Arc::new(Mutex::new(HashSet::new()));
-        let previous = Arc::new(Mutex::new(HashSet::new()));
+        let pending = Arc::new(Mutex::new(FrameInput::default()));
+        let mouse = Arc::new(Mutex::new(MouseState::default()));
 
         let current_clone = Arc::clone(&current);
-        let previous_clone = Arc::clone(&previous);
+        let pending_clone = Arc::clone(&pending);
+        let mouse_clone = Arc::clone(&mouse);
 
         std::thread::spawn(move || {
             loop {
-                Self::poll_key_event(&current_clone, &previous_clone);
+                Self::poll_key_event(&current_clone, &pending_clone, &mouse_clone);
             }
         });
-        Self { current, previous }
+        Self { current, pending, frame: FrameInput::default(), mouse }
     }
 
-    fn poll_key_event(curr: &Arc<Mutex<HashSet<KeyCode>>>, previous: &Arc<Mutex<HashSet<KeyCode>>>) {
-        let mut prev = previous.lock();
+    fn poll_key_event(
+        curr: &Arc<Mutex<HashSet<KeyCode>>>,
+        pending: &Arc<Mutex<FrameInput>>,
+        mouse: &Arc<Mutex<MouseState>>,
+    ) {
+        if !event::poll(Duration::from_millis(10)).unwrap() {
+            return;
+        }
         let mut curr = curr.lock();
-        *prev = curr.clone();
+        let mut pending = pending.lock();
         while event::poll(Duration::from_millis(0)).unwrap() {
-            if let event::Event::Key(event) = event::read().unwrap() {
-                match event.kind {
-                    event::KeyEventKind::Press | event::KeyEventKind::Repeat => { curr.insert(event.code); }
-                    event::KeyEventKind::Release => { curr.remove(&event.code); }
-                }
+            match event::read().unwrap() {
+                event::Event::Key(event) => match event.kind {
+                    event::KeyEventKind::Press | event::KeyEventKind::Repeat => {
+                        curr.insert(event.code);
+                        pending.pressed.insert(event.code);
+                        Self::push_event(&mut pending.events, InputEvent::Key(event.code));
+                    }
+                    event::KeyEventKind::Release => {
+                        curr.remove(&event.code);
+                        pending.released.insert(event.code);
+                    }
+                },
+                event::Event::Mouse(event) => Self::handle_mouse_event(&mut mouse.lock(), &mut pending.events, event),
+                _ => {}
+            }
+        }
+    }
+
+    fn push_event(events: &mut VecDeque<InputEvent>, event: InputEvent) {
+        if events.len() == MAX_EVENTS {
+            events.pop_front();
+        }
+        events.push_back(event);
+    }
+
+    fn handle_mouse_event(mouse: &mut MouseState, events: &mut VecDeque<InputEvent>, event: event::MouseEvent) {
+        mouse.pos = Vec2::new(event.column, event.row);
+        match event.kind {
+            event::MouseEventKind::Down(button) => {
+                mouse.buttons.insert(button);
+                Self::push_event(events, InputEvent::MouseDown(button, mouse.pos));
             }
+            event::MouseEventKind::Up(button) => {
+                mouse.buttons.remove(&button);
+                Self::push_event(events, InputEvent::MouseUp(button, mouse.pos));
+            }
+            event::MouseEventKind::ScrollDown => mouse.scroll += 1,
+            event::MouseEventKind::ScrollUp => mouse.scroll -= 1,
+            _ => {}
         }
     }
 
+    /// Start a new frame: what arrived since the last call becomes what
+    /// `is_pressed`, `is_released` and `take_events` report until the next one.
+    /// Whatever the last frame didn't take is dropped.
+    pub fn begin_frame(&mut self) {
+        self.frame = std::mem::take(&mut *self.pending.lock());
+    }
+
     pub fn is_down(&mut self, key: &KeyCode) -> bool {
         self.current.lock().contains(key)
     }
 
+    /// Whether `key` was pressed (or repeated) before this frame began.
     pub fn is_pressed(&mut self, key: &KeyCode) -> bool {
-        self.current.lock().contains(key) && !self.previous.lock().contains(key)
+        self.frame.pressed.contains(key)
+    }
+
+    /// Mark a press of `key` as handled, so `is_pressed` won't report it.
+    pub fn consume(&mut self, key: &KeyCode) {
+        self.frame.pressed.remove(key);
     }
 
+    /// Whether `key` was released before this frame began. Only terminals
+    /// that report key releases ever do.
     pub fn is_released(&mut self, key: &KeyCode) -> bool {
-        !self.current.lock().contains(key) && self.previous.lock().contains(key)
+        self.frame.released.contains(key)
+    }
+
+    pub fn mouse_pos(&mut self) -> Vec2<u16> {
+        self.mouse.lock().pos
+    }
+
+    pub fn is_mouse_down(&mut self, button: &MouseButton) -> bool {
+        self.mouse.lock().buttons.contains(button)
+    }
+
+    /// Return the wheel movement accumulated so far (positive is down) and
+    /// reset it, so that only one consumer gets to scroll with it.
+    pub fn take_scroll(&mut self) -> i32 {
+        std::mem::take(&mut self.mouse.lock().scroll)
+    }
+
+    /// Return this frame's events that haven't been taken yet, oldest first.
+    /// Only the most recent 256 of a frame are kept.
+    pub fn take_events(&mut self) -> Vec<InputEvent> {
+        self.frame.events.drain(..).collect()
     }
 }
@@ -4,5 +4,7 @@ pub mod key;
 pub mod utils;
 pub mod buffer;
 pub mod ui;
+pub mod clip;
+pub mod color_depth;
 
 const X_SCALE: f32 = 2.2;
\ No newline at end of file
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+
+use crate::{
+    shapes::{coverage, pixel::{FULL_BLOCK, Pixel}, triangle_coverage},
+    types::{color::Color, vec2::Vec2},
+};
+
+/// How the cells along the edges of a shape, which it only partly covers,
+/// are drawn.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AntiAliasing {
+    /// Cells whose center is inside are drawn in full, giving stair-stepped
+    /// edges on slanted and rotated shapes.
+    #[default]
+    Off,
+    /// Full blocks faded by how much of the cell is covered.
+    Blend,
+    /// Shade blocks (`░▒▓█`) picked by how much of the cell is covered.
+    Shade,
+    /// Quadrant blocks (`▘▀▙`...) showing which quarters of the cell are
+    /// covered.
+    Quadrants,
+}
+
+/// The sample points (see `triangle_coverage`) covered by a shape in each
+/// cell. Shapes made of several triangles gather all of them in one, so the
+/// edges the triangles share aren't anti-aliased.
+#[derive(Debug, Clone, Default)]
+pub struct Coverage {
+    cells: HashMap<(u16, u16), u16>,
+}
+
+impl Coverage {
+    /// Add the samples covered by the triangle `[a, b, c]`. `term_size` is
+    /// (width, height) in terminal cells.
+    pub fn add_triangle(&mut self, [a, b, c]: [Vec2<f32>; 3], term_size: (u16, u16)) {
+        let min = Vec2::new(a.x.min(b.x).min(c.x), a.y.min(b.y).min(c.y));
+        let max = Vec2::new(a.x.max(b.x).max(c.x), a.y.max(b.y).max(c.y));
+        self.add(min, max, term_size, |p| triangle_coverage(a, b, c, p));
+    }
+
+    /// Add the samples `inside` a shape whose cells are all between `min` and
+    /// `max`.
+    pub fn add_shape(&mut self, min: Vec2<f32>, max: Vec2<f32>, term_size: (u16, u16), inside: impl Fn(Vec2<f32>) -> bool) {
+        self.add(min, max, term_size, |p| coverage(p, &inside));
+    }
+
+    fn add(&mut self, min: Vec2<f32>, max: Vec2<f32>, term_size: (u16, u16), mask: impl Fn(Vec2<f32>) -> u16) {
+        let (term_width, term_height) = (term_size.0 as i32, term_size.1 as i32);
+        for py in (min.y.floor() as i32).max(0)..=(max.y.ceil() as i32).min(term_height - 1) {
+            for px in (min.x.floor() as i32).max(0)..=(max.x.ceil() as i32).min(term_width - 1) {
+                let mask = mask(Vec2::new(px as f32, py as f32));
+                if mask != 0 {
+                    *self.cells.entry((px as u16, py as u16)).or_default() |= mask;
+                }
+            }
+        }
+    }
+}
+
+impl AntiAliasing {
+    /// Push a pixel for each cell of `coverage` that shows in this mode, with
+    /// its glyph picked but no colour yet, and return how much of each of
+    /// those cells is covered (from 0 to 1), for `fade`.
+    pub fn rasterize(self, coverage: &Coverage, z: i32, out: &mut Vec<Pixel>) -> Vec<f32> {
+        let mut covered = Vec::with_capacity(coverage.cells.len());
+        for (&(x, y), &mask) in &coverage.cells {
+            let fraction = mask.count_ones() as f32 / 16.0;
+            let glyph = match self {
+                AntiAliasing::Off => (fraction >= 0.5).then_some(FULL_BLOCK),
+                AntiAliasing::Blend => Some(FULL_BLOCK),
+                AntiAliasing::Shade => [None, Some('░'), Some('▒'), Some('▓'), Some(FULL_BLOCK)][(fraction * 4.0).round() as usize],
+                AntiAliasing::Quadrants => quadrant_glyph(mask),
+            };
+            if let Some(glyph) = glyph {
+                out.push(Pixel::new(x, y, glyph, Color::RESET, z));
+                covered.push(fraction);
+            }
+        }
+        covered
+    }
+
+    /// Fade already coloured `pixels` by how much of their cell is covered,
+    /// when blending.
+    pub fn fade(self, pixels: &mut [Pixel], covered: &[f32]) {
+        if self != AntiAliasing::Blend {
+            return;
+        }
+        for (pixel, &fraction) in pixels.iter_mut().zip(covered) {
+            pixel.fg = pixel.fg.faded(fraction);
+        }
+    }
+}
+
+/// The quadrant block with the quarters of the cell that have at least half
+/// of their samples covered.
+fn quadrant_glyph(mask: u16) -> Option<char> {
+    const GLYPHS: [char; 16] = [' ', '▘', '▝', '▀', '▖', '▌', '▞', '▛', '▗', '▚', '▐', '▜', '▄', '▙', '▟', '█'];
+    // Sample bits of each quarter: top left, top right, bottom left, bottom right.
+    const QUARTERS: [u16; 4] = [0x0033, 0x00cc, 0x3300, 0xcc00];
+    let quadrants = QUARTERS
+        .iter()
+        .enumerate()
+        .filter(|&(_, &quarter)| (mask & quarter).count_ones() >= 2)
+        .fold(0, |quadrants, (i, _)| quadrants | 1 << i);
+    (quadrants != 0).then(|| GLYPHS[quadrants])
+}
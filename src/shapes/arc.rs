@@ -0,0 +1,126 @@
+use crate::{
+    shapes::{
+        self, Orientation, Shape,
+        fill::{Fill, FillFrame},
+        pixel::Pixel,
+        sector::Sector,
+        style::ShapeStyle,
+        triangle::Triangle,
+    },
+    types::{pos2::Pos2, vec2::Vec2},
+};
+
+/// A thick curve along part of a circle, with square ends, like the track or
+/// needle of a gauge.
+#[derive(Clone)]
+pub struct Arc {
+    pub center: Pos2,
+    /// Radius of the middle of the curve, in rows.
+    pub radius: f32,
+    /// Angle the curve starts at, in radians clockwise from straight up.
+    pub start: f32,
+    /// Angle the curve ends at, in radians clockwise from straight up.
+    pub end: f32,
+    /// How thick the curve is, in rows. It's centered on `radius`.
+    pub width: f32,
+    pub orientation: Orientation,
+    pub style: ShapeStyle,
+    pub children: Vec<Box<dyn Shape>>,
+}
+
+impl Arc {
+    pub fn new(center: Pos2, radius: f32, start: f32, end: f32, fill: impl Into<Fill>) -> Self {
+        Self {
+            center,
+            radius,
+            start,
+            end,
+            width: 1.0,
+            orientation: Orientation::Up,
+            style: ShapeStyle::new(fill),
+            children: vec![],
+        }
+    }
+
+    pub fn push(&mut self, child: Box<dyn Shape>) {
+        self.children.push(child);
+    }
+
+    /// The region covered by the curve.
+    pub fn sector(&self) -> Sector {
+        let outer = self.radius + self.width / 2.0;
+        Sector {
+            center: self.center.into(),
+            rad: Triangle::orientation_rad(self.orientation),
+            radii: Vec2::splat(outer),
+            inner: (self.radius - self.width / 2.0).max(0.0) / outer.max(f32::EPSILON),
+            angles: Some((self.start, self.end)),
+        }
+    }
+
+    /// Points along the outer edge from `start` to `end`, then back along
+    /// the inner edge, on screen.
+    pub fn outline(&self) -> Vec<Vec2<f32>> {
+        let sector = self.sector();
+        let (start, sweep) = sector.sweep();
+        let mut points = sector.curve(start, start + sweep, 1.0);
+        points.extend(sector.curve(start + sweep, start, sector.inner));
+        points
+    }
+
+    /// The area the fill is spread over: the bounding square of the whole
+    /// circle, so a gradient follows the angle rather than the curve's
+    /// length.
+    pub fn fill_frame(&self) -> FillFrame {
+        self.sector().fill_frame()
+    }
+}
+
+impl Shape for Arc {
+    fn draw(&self) {
+        shapes::draw(self, &self.children);
+    }
+
+    fn update(&mut self) {
+        shapes::update_children(&mut self.children, self.center);
+    }
+
+    /// The inside, then the outline on top as `draw_mode` says.
+    fn rasterize(&self, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
+        let frame = self.fill_frame();
+        if self.style.draw_mode.fills() {
+            self.sector().rasterize(&self.style.paint(frame), out, term_size);
+        }
+        self.style.rasterize_stroke(&[self.outline()], frame, out, term_size);
+    }
+
+
+    fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+    }
+
+    fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    fn pos(&self) -> Pos2 {
+        self.center
+    }
+
+    fn set_pos(&mut self, pos: Pos2) {
+        self.center = pos;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.style.z_index
+    }
+
+    fn box_clone(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    /// Whether the other shape's position is on the curve.
+    fn collides_with(&self, other: &dyn Shape) -> bool {
+        self.sector().contains(other.pos().into())
+    }
+}
@@ -0,0 +1,142 @@
+use std::io::stdout;
+
+use crossterm::terminal;
+
+use crate::{
+    shapes::{
+        Orientation, Shape,
+        fill::Fill,
+        path::{self, Path},
+        pixel::{self, Pixel, flush_pixels},
+        stroke::Stroke,
+    },
+    types::{color::BlendMode, pos2::Pos2, vec2::Vec2},
+};
+
+/// A Bézier curve from the first of its points to the last, pulled towards
+/// the ones in between: one for a quadratic curve, two for a cubic one.
+#[derive(Clone)]
+pub struct Bezier {
+    /// Terminal positions of the start, the control points and the end.
+    pub points: Vec<Vec2<f32>>,
+    pub stroke: Stroke,
+    /// Multiplies the alpha of the stroke, from 0 (invisible) to 1.
+    pub opacity: f32,
+    /// How the curve is combined with what is below it.
+    pub blend: BlendMode,
+    /// Turns the points around the middle of their box.
+    pub orientation: Orientation,
+    pub z_index: i32,
+    /// `points` turned to `orientation`, as of the last `update`.
+    oriented: Vec<Vec2<f32>>,
+    pub children: Vec<Box<dyn Shape>>,
+}
+
+impl Bezier {
+    pub fn new(points: Vec<Vec2<f32>>, fill: impl Into<Fill>) -> Self {
+        Self {
+            oriented: points.clone(),
+            points,
+            stroke: Stroke::new(fill),
+            opacity: 1.0,
+            blend: BlendMode::Normal,
+            orientation: Orientation::Up,
+            z_index: 0,
+            children: vec![],
+        }
+    }
+
+    pub fn quadratic(start: Vec2<f32>, control: Vec2<f32>, end: Vec2<f32>, fill: impl Into<Fill>) -> Self {
+        Self::new(vec![start, control, end], fill)
+    }
+
+    pub fn cubic(start: Vec2<f32>, control1: Vec2<f32>, control2: Vec2<f32>, end: Vec2<f32>, fill: impl Into<Fill>) -> Self {
+        Self::new(vec![start, control1, control2, end], fill)
+    }
+
+    pub fn push(&mut self, child: Box<dyn Shape>) {
+        self.children.push(child);
+    }
+}
+
+impl Path for Bezier {
+    /// `t` is the curve's own parameter, so the point moves faster where the
+    /// control points are further apart. Use `point_at_distance` for a
+    /// steady speed.
+    fn point_at(&self, t: f32) -> Vec2<f32> {
+        // De Casteljau: keep blending neighbouring points until one is left.
+        let mut points = self.oriented.clone();
+        for n in (1..points.len()).rev() {
+            for i in 0..n {
+                points[i] = points[i] + (points[i + 1] - points[i]) * t;
+            }
+        }
+        points.first().copied().unwrap_or(Vec2::splat(0.0))
+    }
+}
+
+impl Shape for Bezier {
+    fn draw(&self) {
+        let mut pixels: Vec<Pixel> = Vec::with_capacity(1024);
+        self.rasterize(&mut pixels, terminal::size().unwrap());
+        flush_pixels(&mut stdout().lock(), &mut pixels);
+
+        for child in &self.children {
+            child.draw();
+        }
+    }
+
+    fn update(&mut self) {
+        self.oriented = path::orient(&self.points, self.orientation);
+
+        self.children.sort_by_key(|child| child.z_index());
+
+        let parent_pos: Vec2<f32> = self.pos().into();
+        for child in &mut self.children {
+            let relative_pos = child.pos().to_relative(parent_pos);
+
+            if let Pos2::Relative(_) = relative_pos {
+                child.set_pos(relative_pos);
+            }
+
+            child.update();
+        }
+    }
+
+    fn rasterize(&self, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
+        let start = out.len();
+        path::rasterize(self, &self.stroke, out, term_size);
+        pixel::apply_style(&mut out[start..], self.opacity, self.blend, self.z_index);
+    }
+
+    fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+    }
+
+    fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// The middle of the box around the points.
+    fn pos(&self) -> Pos2 {
+        path::center(&self.points).into()
+    }
+
+    /// Move all the points so their middle ends up at `pos`.
+    fn set_pos(&mut self, pos: Pos2) {
+        path::move_to(&mut self.points, pos.into());
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn box_clone(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    /// Whether the other shape's position is on the curve.
+    fn collides_with(&self, other: &dyn Shape) -> bool {
+        path::near(&self.flattened(), false, other.pos().into(), self.stroke.width.max(1.0) / 2.0)
+    }
+}
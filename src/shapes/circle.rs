@@ -1,9 +1,9 @@
-use std::f32::consts::PI;
+use std::{f32::consts::PI, io::stdout};
 
-use crossterm::style::Color;
+use crossterm::terminal;
 
 use crate::{
-    shapes::{Orientation, Shape, triangle::Triangle},
+    shapes::{Orientation, Shape, fill::{Fill, FillFrame}, pixel::{Pixel, flush_pixels}, style::ShapeStyle, triangle::Triangle},
     types::{pos2::Pos2, vec2::Vec2},
 };
 
@@ -11,14 +11,14 @@ pub struct Circle {
     pub center: Pos2,
     pub radius: f32,
     pub orientation: Orientation,
-    pub color: Color,
-    pub z_index: i32,
+    pub style: ShapeStyle,
     triangles: Vec<Triangle>,
     pub children: Vec<Box<dyn Shape>>,
 }
 
 impl Circle {
-    pub fn new(center: Pos2, radius: f32, n_sectors: usize, color: Color) -> Self {
+    pub fn new(center: Pos2, radius: f32, n_sectors: usize, fill: impl Into<Fill>) -> Self {
+        let style = ShapeStyle::new(fill);
         let mut triangles = Vec::new();
 
         let angle_per_triangle = (PI * 2.0) / n_sectors as f32;
@@ -31,20 +31,16 @@ impl Circle {
                 center,
                 Orientation::Custom(theta),
                 Vec2::new(radius, base_length),
-                color,
+                style.fill.clone(),
             );
             triangles.push(triangle);
         }
 
-        // Ensure triangles are ordered by their z_index so rendering order is stable.
-        triangles.sort_by_key(|t| t.z_index);
-
         Self {
             center,
             radius,
             orientation: Orientation::Custom(0.0),
-            color,
-            z_index: 0,
+            style,
             triangles,
             children: vec![],
         }
@@ -54,9 +50,31 @@ impl Circle {
         self.children.push(child);
     }
 
+    /// The area the fill is spread over: the circle's bounding square,
+    /// turning with `orientation`.
+    pub fn fill_frame(&self) -> FillFrame {
+        FillFrame {
+            center: self.center.into(),
+            rad: Triangle::orientation_rad(self.orientation),
+            half_size: Vec2::splat(self.radius),
+        }
+    }
+
+    /// Points around the edge, one per sector.
+    pub fn outline(&self) -> Vec<Vec2<f32>> {
+        let frame = self.fill_frame();
+        let n = self.triangles.len().max(3);
+        (0..n)
+            .map(|i| {
+                let angle = i as f32 * 2.0 * PI / n as f32;
+                frame.point(Vec2::new(0.5 + 0.5 * angle.cos(), 0.5 + 0.5 * angle.sin()))
+            })
+            .collect()
+    }
+
     /// Getter for z_index as an inherent method.
     pub fn z_index(&self) -> i32 {
-        self.z_index
+        self.style.z_index
     }
 }
 
@@ -65,19 +83,21 @@ impl Clone for Circle {
         // Triangles (and their stdout locks) are not directly cloneable.
         // Recreate the triangles array using the same radius and sector count.
         let n_sectors = self.triangles.len();
-        let mut c = Circle::new(self.center, self.radius, n_sectors, self.color);
+        let mut c = Circle::new(self.center, self.radius, n_sectors, self.style.fill.clone());
         c.orientation = self.orientation;
-        c.z_index = self.z_index;
-        c.children = self.children.iter().map(|c| c.clone()).collect();
+        c.style = self.style.clone();
+        c.children = self.children.to_vec();
         c
     }
 }
 
 impl Shape for Circle {
     fn draw(&self) {
-        for triangle in &self.triangles {
-            triangle.draw();
-        }
+        // All sectors go in one batch, so the edges they share aren't blended
+        // twice when the circle is translucent.
+        let mut pixels: Vec<Pixel> = Vec::with_capacity(1024);
+        self.rasterize(&mut pixels, terminal::size().unwrap());
+        flush_pixels(&mut stdout().lock(), &mut pixels);
 
         for child in &self.children {
             child.draw();
@@ -86,12 +106,14 @@ impl Shape for Circle {
 
     fn update(&mut self) {
         // Update geometry/state of each triangle first.
+        let frame = self.fill_frame();
         for triangle in &mut self.triangles {
+            triangle.set_pos(self.center);
+            triangle.style = self.style.clone();
+            triangle.fill_frame = Some(frame);
             triangle.update();
         }
 
-        // After updates, re-sort by z_index so rendering order respects z values.
-        self.triangles.sort_by_key(|t| t.z_index);
         self.children.sort_by_key(|child| child.z_index());
 
         let parent_pos: Vec2<f32> = self.pos().into();
@@ -106,6 +128,15 @@ impl Shape for Circle {
         }
     }
 
+    /// The triangles making up the inside, then the outline on top as
+    /// `draw_mode` says.
+    fn rasterize(&self, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
+        if self.style.draw_mode.fills() {
+            Triangle::rasterize_inside(&self.triangles, out, term_size);
+        }
+        self.style.rasterize_stroke(&[self.outline()], self.fill_frame(), out, term_size);
+    }
+
     fn set_orientation(&mut self, orientation: Orientation) {
         self.orientation = orientation;
     }
@@ -115,7 +146,7 @@ impl Shape for Circle {
     }
 
     fn z_index(&self) -> i32 {
-        self.z_index
+        self.style.z_index
     }
 
     fn pos(&self) -> Pos2 {
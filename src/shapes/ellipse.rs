@@ -0,0 +1,113 @@
+use std::f32::consts::TAU;
+
+use crate::{
+    shapes::{
+        self, Orientation, Shape,
+        fill::{Fill, FillFrame},
+        pixel::Pixel,
+        sector::Sector,
+        style::ShapeStyle,
+        triangle::Triangle,
+    },
+    types::{pos2::Pos2, vec2::Vec2},
+};
+
+/// A filled ellipse, drawn from its equation rather than a fan of triangles
+/// like `Circle`.
+#[derive(Clone)]
+pub struct Ellipse {
+    pub center: Pos2,
+    /// Horizontal and vertical radius, in rows. As with the other shapes, x is
+    /// stretched by `X_SCALE` on screen, so equal radii give a circle.
+    pub radii: Vec2<f32>,
+    pub orientation: Orientation,
+    pub style: ShapeStyle,
+    pub children: Vec<Box<dyn Shape>>,
+}
+
+impl Ellipse {
+    pub fn new(center: Pos2, radii: Vec2<f32>, fill: impl Into<Fill>) -> Self {
+        Self {
+            center,
+            radii,
+            orientation: Orientation::Up,
+            style: ShapeStyle::new(fill),
+            children: vec![],
+        }
+    }
+
+    pub fn push(&mut self, child: Box<dyn Shape>) {
+        self.children.push(child);
+    }
+
+    /// The region covered by the ellipse.
+    pub fn sector(&self) -> Sector {
+        Sector {
+            center: self.center.into(),
+            rad: Triangle::orientation_rad(self.orientation),
+            radii: self.radii,
+            inner: 0.0,
+            angles: None,
+        }
+    }
+
+    /// Points around the edge, on screen.
+    pub fn outline(&self) -> Vec<Vec2<f32>> {
+        self.sector().curve(0.0, TAU, 1.0)
+    }
+
+    /// The area the fill is spread over: the ellipse's bounding box, turning
+    /// with `orientation`.
+    pub fn fill_frame(&self) -> FillFrame {
+        self.sector().fill_frame()
+    }
+}
+
+impl Shape for Ellipse {
+    fn draw(&self) {
+        shapes::draw(self, &self.children);
+    }
+
+    fn update(&mut self) {
+        shapes::update_children(&mut self.children, self.center);
+    }
+
+    /// The inside, then the outline on top as `draw_mode` says.
+    fn rasterize(&self, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
+        let frame = self.fill_frame();
+        if self.style.draw_mode.fills() {
+            self.sector().rasterize(&self.style.paint(frame), out, term_size);
+        }
+        self.style.rasterize_stroke(&[self.outline()], frame, out, term_size);
+    }
+
+
+    fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+    }
+
+    fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    fn pos(&self) -> Pos2 {
+        self.center
+    }
+
+    fn set_pos(&mut self, pos: Pos2) {
+        self.center = pos;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.style.z_index
+    }
+
+    fn box_clone(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    /// Whether the other shape's position is inside the ellipse.
+    fn collides_with(&self, other: &dyn Shape) -> bool {
+        self.sector().contains(other.pos().into())
+    }
+}
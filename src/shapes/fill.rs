@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use crate::{
+    X_SCALE,
+    shapes::{antialias::AntiAliasing, pixel::{self, Pixel}, shading::Shading},
+    types::{color::{BlendMode, Color}, vec2::Vec2},
+};
+
+/// What the inside of a shape is painted with. Gradients and patterns are laid
+/// out in the shape's local `uv` space, where (0, 0) is its top left corner
+/// and (1, 1) its bottom right one before rotating it, so they turn along
+/// with the shape.
+#[derive(Clone)]
+pub enum Fill {
+    Solid(Color),
+    /// Blends `from` into `to` across the shape. `angle` is in radians: 0
+    /// goes left to right and `PI / 2` top to bottom.
+    LinearGradient { from: Color, to: Color, angle: f32 },
+    /// Blends `inner` at the center into `outer` at the edge of the largest
+    /// ellipse that fits the shape. Corners outside of it are `outer`.
+    RadialGradient { inner: Color, outer: Color },
+    /// `columns` by `rows` squares alternating between `a` and `b`, starting
+    /// with `a` in the top left.
+    Checker { a: Color, b: Color, columns: u16, rows: u16 },
+    /// `count` pairs of `a` and `b` stripes, running across the shape at
+    /// `angle` radians like a `LinearGradient`.
+    Stripes { a: Color, b: Color, count: u16, angle: f32 },
+    /// Any colour you like for the given `uv`.
+    Custom(Arc<dyn Fn(Vec2<f32>) -> Color + Send + Sync>),
+}
+
+impl Fill {
+    pub fn custom(f: impl Fn(Vec2<f32>) -> Color + Send + Sync + 'static) -> Self {
+        Fill::Custom(Arc::new(f))
+    }
+
+    /// Colour at `uv`, from (0, 0) at the top left to (1, 1) at the bottom
+    /// right of the shape.
+    pub fn color_at(&self, uv: Vec2<f32>) -> Color {
+        match self {
+            Fill::Solid(color) => *color,
+            Fill::LinearGradient { from, to, angle } => from.lerp(*to, along(uv, *angle)),
+            Fill::RadialGradient { inner, outer } => {
+                let (dx, dy) = (uv.x - 0.5, uv.y - 0.5);
+                inner.lerp(*outer, ((dx * dx + dy * dy).sqrt() * 2.0).min(1.0))
+            }
+            Fill::Checker { a, b, columns, rows } => {
+                let cell = |t: f32, n: u16| (t.clamp(0.0, 0.999) * n.max(1) as f32) as u32;
+                if (cell(uv.x, *columns) + cell(uv.y, *rows)).is_multiple_of(2) { *a } else { *b }
+            }
+            Fill::Stripes { a, b, count, angle } => {
+                let stripe = (along(uv, *angle).min(0.999) * 2.0 * (*count).max(1) as f32) as u32;
+                if stripe.is_multiple_of(2) { *a } else { *b }
+            }
+            Fill::Custom(f) => f(uv),
+        }
+    }
+}
+
+impl From<Color> for Fill {
+    fn from(color: Color) -> Self {
+        Fill::Solid(color)
+    }
+}
+
+/// How far `uv` is across the unit square in the direction of `angle`, from 0
+/// at the corner it starts from to 1 at the opposite one.
+fn along(uv: Vec2<f32>, angle: f32) -> f32 {
+    let (sin, cos) = angle.sin_cos();
+    let extent = cos.abs() + sin.abs();
+    (0.5 + ((uv.x - 0.5) * cos + (uv.y - 0.5) * sin) / extent).clamp(0.0, 1.0)
+}
+
+/// The local space of a shape, which a `Fill` is spread over: its center on
+/// screen, its rotation in radians (as in `Triangle::rad`) and its half size
+/// before `X_SCALE`.
+#[derive(Clone, Copy, Debug)]
+pub struct FillFrame {
+    pub center: Vec2<f32>,
+    pub rad: f32,
+    pub half_size: Vec2<f32>,
+}
+
+impl FillFrame {
+    /// Local `uv` of the terminal cell at `p`, undoing the shape's rotation
+    /// and `X_SCALE`.
+    pub fn uv(&self, p: Vec2<f32>) -> Vec2<f32> {
+        let local = Vec2::new((p.x - self.center.x) / X_SCALE, self.center.y - p.y).rotate(-self.rad);
+        Vec2::new(
+            0.5 + local.x / self.half_size.x.max(f32::EPSILON) * 0.5,
+            0.5 - local.y / self.half_size.y.max(f32::EPSILON) * 0.5,
+        )
+    }
+
+    /// The terminal position of `uv`, the inverse of `FillFrame::uv`.
+    pub fn point(&self, uv: Vec2<f32>) -> Vec2<f32> {
+        let local = Vec2::new((uv.x - 0.5) * 2.0 * self.half_size.x, (0.5 - uv.y) * 2.0 * self.half_size.y).rotate(self.rad);
+        Vec2::new(self.center.x + local.x * X_SCALE, self.center.y - local.y)
+    }
+}
+
+/// How a shape paints its inside, borrowed from its fields.
+pub struct Paint<'a> {
+    pub fill: &'a Fill,
+    pub frame: FillFrame,
+    pub opacity: f32,
+    pub blend: BlendMode,
+    pub z_index: i32,
+    pub shading: &'a Shading,
+    pub antialiasing: AntiAliasing,
+}
+
+impl Paint<'_> {
+    /// Colour, fade and shade `pixels` pushed for the inside of the shape,
+    /// `covered` being what `AntiAliasing::rasterize` returned for them.
+    pub fn apply(&self, pixels: &mut [Pixel], covered: &[f32]) {
+        for pixel in pixels.iter_mut() {
+            pixel.fg = self.fill.color_at(self.frame.uv(Vec2::new(pixel.x as f32, pixel.y as f32)));
+        }
+        pixel::apply_style(pixels, self.opacity, self.blend, self.z_index);
+        self.antialiasing.fade(pixels, covered);
+        self.shading.apply(pixels);
+    }
+}
@@ -1,15 +1,22 @@
 use std::io::stdout;
 
 use crate::{
-    shapes::{Orientation, Shape, pixel::{Pixel, flush_pixels}}, types::{pos2::Pos2, vec2::Vec2}
+    shapes::{Orientation, Shape, pixel::{FULL_BLOCK, Pixel, flush_pixels}, stroke}, types::{color::{BlendMode, Color}, pos2::Pos2, vec2::Vec2}
 };
-use crossterm::style::Color;
 use crossterm::terminal;
 
 pub struct Line {
     pub pos1: Pos2,
     pub pos2: Pos2,
     pub color: Color,
+    /// Multiplies the alpha of `color`, from 0 (invisible) to 1.
+    pub opacity: f32,
+    /// How the line is combined with what is below it.
+    pub blend: BlendMode,
+    /// Thickness in terminal rows. Up to 1 draws a line one cell thick.
+    pub width: f32,
+    /// Drawn in every cell of the line.
+    pub glyph: char,
     pub z_index: i32,
     pub children: Vec<Box<dyn Shape>>,
 }
@@ -20,6 +27,10 @@ impl Line {
             pos1: pos1.into(),
             pos2: pos2.into(),
             color,
+            opacity: 1.0,
+            blend: BlendMode::Normal,
+            width: 1.0,
+            glyph: FULL_BLOCK,
             z_index: 0,
             children: vec![],
         }
@@ -37,8 +48,12 @@ impl Clone for Line {
             pos1: self.pos1,
             pos2: self.pos2,
             color: self.color,
+            opacity: self.opacity,
+            blend: self.blend,
+            width: self.width,
+            glyph: self.glyph,
             z_index: self.z_index,
-            children: self.children.iter().map(|c| c.clone()).collect(),
+            children: self.children.to_vec(),
         }
     }
 }
@@ -59,42 +74,12 @@ impl Shape for Line {
     }
 
     fn rasterize(&self, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
-        let term_width = term_size.0 as i32;
-        let term_height = term_size.1 as i32;
-
-        let pos1: Vec2<f32> = self.pos1.into();
-        let pos2: Vec2<f32> = self.pos2.into();
-
-        let x0 = pos1.x as i32;
-        let y0 = pos1.y as i32;
-        let x1 = pos2.x as i32;
-        let y1 = pos2.y as i32;
-
-        let dx = (x1 - x0).abs();
-        let dy = -(y1 - y0).abs();
-        let sx: i32 = if x0 < x1 { 1 } else { -1 };
-        let sy = if y0 < y1 { 1 } else { -1 };
-        let mut err = dx + dy;
-        let mut x = x0;
-        let mut y = y0;
-
-        loop {
-            if x >= 0 && x < term_width && y >= 0 && y < term_height {
-                out.push(Pixel::new(x as u16, y as u16, '█', self.color, self.z_index));
-            }
-
-            if x == x1 && y == y1 {
-                break;
-            }
-            let e2 = err * 2;
-            if e2 >= dy {
-                err += dy;
-                x += sx;
-            }
-            if e2 <= dx {
-                err += dx;
-                y += sy;
-            }
+        let color = self.color.faded(self.opacity);
+        let points = [self.pos1.into(), self.pos2.into()];
+        for (x, y) in stroke::path_cells(&points, false, self.width, stroke::Join::Bevel, term_size) {
+            let mut pixel = Pixel::new(x, y, self.glyph, color, self.z_index);
+            pixel.blend = self.blend;
+            out.push(pixel);
         }
     }
 
@@ -191,3 +176,45 @@ impl Shape for Line {
         other_p.x >= min_x && other_p.x <= max_x && other_p.y >= min_y && other_p.y <= max_y
     }
 }
+
+/// The cells on the line from `pos1` to `pos2` (Bresenham), leaving out the
+/// ones outside of the terminal. `term_size` is (width, height) in terminal
+/// cells.
+pub fn cells(pos1: Vec2<f32>, pos2: Vec2<f32>, term_size: (u16, u16)) -> Vec<(u16, u16)> {
+    let term_width = term_size.0 as i32;
+    let term_height = term_size.1 as i32;
+
+    let x0 = pos1.x as i32;
+    let y0 = pos1.y as i32;
+    let x1 = pos2.x as i32;
+    let y1 = pos2.y as i32;
+
+    let dx = (x1 - x0).abs();
+    let dy = -(y1 - y0).abs();
+    let sx: i32 = if x0 < x1 { 1 } else { -1 };
+    let sy = if y0 < y1 { 1 } else { -1 };
+    let mut err = dx + dy;
+    let mut x = x0;
+    let mut y = y0;
+    let mut cells = Vec::with_capacity((dx - dy + 1) as usize);
+
+    loop {
+        if x >= 0 && x < term_width && y >= 0 && y < term_height {
+            cells.push((x as u16, y as u16));
+        }
+
+        if x == x1 && y == y1 {
+            break;
+        }
+        let e2 = err * 2;
+        if e2 >= dy {
+            err += dy;
+            x += sx;
+        }
+        if e2 <= dx {
+            err += dx;
+            y += sy;
+        }
+    }
+    cells
+}
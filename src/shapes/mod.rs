@@ -1,19 +1,34 @@
 use std::f32::consts::PI;
 
-use crate::{shapes::pixel::Pixel, types::{pos2::Pos2, vec2::Vec2}};
+use std::io::stdout;
 
+use crossterm::terminal;
+
+use crate::{shapes::pixel::{Pixel, flush_pixels}, types::{pos2::Pos2, vec2::Vec2}};
+
+pub mod antialias;
+pub mod arc;
+pub mod bezier;
 pub mod circle;
+pub mod ellipse;
+pub mod fill;
 pub mod line;
 pub mod rectangle;
+pub mod regular_polygon;
+pub mod rounded_rect;
+pub mod shading;
+pub mod stroke;
+pub mod style;
 pub mod triangle;
 pub mod pixel;
-
-use std::io::{StdoutLock, Write};
-use crossterm::{
-    cursor::MoveTo,
-    queue,
-    style::{Color, Print, SetForegroundColor},
-};
+pub mod path;
+pub mod polygon;
+pub mod polyline;
+pub mod pie;
+pub mod ring;
+pub mod sector;
+pub mod spline;
+pub mod star;
 
 #[derive(Clone, Copy)]
 pub enum Orientation {
@@ -103,7 +118,7 @@ pub trait Shape {
 
     /// Set the primary position of the shape. Default implementation is a no-op.
     /// Concrete shapes should override this to update their internal position/state.
-    fn set_pos(&mut self, pos: Pos2) {}
+    fn set_pos(&mut self, _pos: Pos2) {}
 
     fn collides_with(&self, other: &dyn Shape) -> bool;
 
@@ -117,6 +132,34 @@ impl Clone for Box<dyn Shape> {
     }
 }
 
+/// Rasterize `shape` and flush it in one go, then draw its `children` on top.
+pub fn draw(shape: &dyn Shape, children: &[Box<dyn Shape>]) {
+    let mut pixels: Vec<Pixel> = Vec::with_capacity(1024);
+    shape.rasterize(&mut pixels, terminal::size().unwrap());
+    flush_pixels(&mut stdout().lock(), &mut pixels);
+
+    for child in children {
+        child.draw();
+    }
+}
+
+/// Sort `children` by z-index, keep the relative ones positioned against
+/// `parent_pos` and update them all.
+pub fn update_children(children: &mut [Box<dyn Shape>], parent_pos: Pos2) {
+    children.sort_by_key(|child| child.z_index());
+
+    let parent_pos: Vec2<f32> = parent_pos.into();
+    for child in children {
+        let relative_pos = child.pos().to_relative(parent_pos);
+
+        if let Pos2::Relative(_) = relative_pos {
+            child.set_pos(relative_pos);
+        }
+
+        child.update();
+    }
+}
+
 /// if it outputs 0.0, its inside the triangle
 pub fn edge(a: Vec2<f32>, b: Vec2<f32>, p: Vec2<f32>) -> f32 {
     (p.x - a.x) * (b.y - a.y) - (p.y - a.y) * (b.x - a.x)
@@ -129,3 +172,24 @@ pub fn inside_triangle(a: Vec2<f32>, b: Vec2<f32>, c: Vec2<f32>, p: Vec2<f32>) -
 
     (e1 >= 0.0 && e2 >= 0.0 && e3 >= 0.0) || (e1 <= 0.0 && e2 <= 0.0 && e3 <= 0.0)
 }
+
+/// Which of a 4x4 grid of sample points spread over the cell at `p` are
+/// inside the triangle, one bit each in row-major order. The cell reaches
+/// half a cell to every side of `p`, which is what `inside_triangle` tests
+/// when rasterizing without anti-aliasing.
+pub fn triangle_coverage(a: Vec2<f32>, b: Vec2<f32>, c: Vec2<f32>, p: Vec2<f32>) -> u16 {
+    coverage(p, |sample| inside_triangle(a, b, c, sample))
+}
+
+/// Which of a 4x4 grid of sample points spread over the cell at `p` are
+/// `inside` a shape, as `triangle_coverage` does for triangles.
+pub fn coverage(p: Vec2<f32>, inside: impl Fn(Vec2<f32>) -> bool) -> u16 {
+    let mut mask = 0;
+    for bit in 0..16 {
+        let offset = Vec2::new((bit % 4) as f32 + 0.5, (bit / 4) as f32 + 0.5) / 4.0 - 0.5;
+        if inside(p + offset) {
+            mask |= 1 << bit;
+        }
+    }
+    mask
+}
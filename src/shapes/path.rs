@@ -0,0 +1,168 @@
+use crate::{
+    X_SCALE,
+    shapes::{Orientation, fill::FillFrame, pixel::Pixel, stroke::Stroke, triangle::Triangle},
+    types::vec2::Vec2,
+};
+
+/// How far, in terminal cells, `flatten` lets its segments stray from the
+/// curve.
+pub const TOLERANCE: f32 = 0.25;
+
+/// A curve through terminal positions, drawn by cutting it into straight
+/// segments. It can also be followed as a movement path with `point_at` or,
+/// at a steady speed, `point_at_distance`.
+pub trait Path {
+    /// The point `t` of the way along, from the start at 0 to the end at 1.
+    fn point_at(&self, t: f32) -> Vec2<f32>;
+
+    /// Whether a segment runs from the end back to the start.
+    fn closed(&self) -> bool {
+        false
+    }
+
+    /// The curve cut into straight segments, close enough to look smooth.
+    fn flattened(&self) -> Vec<Vec2<f32>> {
+        flatten(|t| self.point_at(t), TOLERANCE)
+    }
+
+    /// The length along the curve, in rows. Columns count as `1 / X_SCALE`
+    /// rows, so lengths match what is on screen.
+    fn length(&self) -> f32 {
+        segments(&self.flattened(), self.closed()).map(|(a, b)| distance(a, b)).sum()
+    }
+
+    /// The point `distance` rows along the curve (see `length`), clamped to
+    /// its ends. Unlike `point_at`, equal steps of `distance` always cover
+    /// equal lengths of the curve.
+    fn point_at_distance(&self, distance: f32) -> Vec2<f32> {
+        let points = self.flattened();
+        let mut left = distance.max(0.0);
+        let mut last = points.first().copied().unwrap_or(Vec2::splat(0.0));
+        for (a, b) in segments(&points, self.closed()) {
+            let length = self::distance(a, b);
+            if left <= length {
+                return a + (b - a) * (left / length.max(f32::EPSILON));
+            }
+            left -= length;
+            last = b;
+        }
+        last
+    }
+}
+
+/// Cut the curve `point_at` (from `t` = 0 to 1) into straight segments,
+/// halving them until their middle is within `tolerance` cells of the curve,
+/// and return the points between them.
+pub fn flatten(point_at: impl Fn(f32) -> Vec2<f32>, tolerance: f32) -> Vec<Vec2<f32>> {
+    // Start from a few pieces, so that an S bend whose middle happens to lie
+    // on the line between its ends isn't taken for a straight one.
+    const PIECES: usize = 4;
+    let mut points = vec![point_at(0.0)];
+    for i in 0..PIECES {
+        let (t0, t1) = (i as f32 / PIECES as f32, (i + 1) as f32 / PIECES as f32);
+        let start = points[points.len() - 1];
+        subdivide(&point_at, (t0, start), (t1, point_at(t1)), tolerance, 0, &mut points);
+    }
+    points
+}
+
+fn subdivide(
+    point_at: &impl Fn(f32) -> Vec2<f32>,
+    (t0, p0): (f32, Vec2<f32>),
+    (t1, p1): (f32, Vec2<f32>),
+    tolerance: f32,
+    depth: u32,
+    out: &mut Vec<Vec2<f32>>,
+) {
+    const MAX_DEPTH: u32 = 10;
+    let middle = (t0 + t1) / 2.0;
+    let pm = point_at(middle);
+    let off = pm - (p0 + p1) / 2.0;
+    if depth >= MAX_DEPTH || off.x * off.x + off.y * off.y <= tolerance * tolerance {
+        out.push(p1);
+        return;
+    }
+    subdivide(point_at, (t0, p0), (middle, pm), tolerance, depth + 1, out);
+    subdivide(point_at, (middle, pm), (t1, p1), tolerance, depth + 1, out);
+}
+
+/// Stroke the path, leaving opacity, blending and z-index to the caller.
+pub fn rasterize(path: &impl Path, stroke: &Stroke, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
+    let points = path.flattened();
+    stroke.rasterize(&points, path.closed(), None, frame(&points), out, term_size);
+}
+
+/// The area a stroke's fill is spread over: the box around `points`.
+pub fn frame(points: &[Vec2<f32>]) -> FillFrame {
+    let (min, max) = bounds(points);
+    FillFrame {
+        center: (min + max) / 2.0,
+        rad: 0.0,
+        half_size: Vec2::new((max.x - min.x) / X_SCALE, max.y - min.y) / 2.0,
+    }
+}
+
+/// The middle of the box around `points`, which the curve shapes use as
+/// their position.
+pub fn center(points: &[Vec2<f32>]) -> Vec2<f32> {
+    let (min, max) = bounds(points);
+    (min + max) / 2.0
+}
+
+/// `points` turned clockwise to `orientation` around the middle of their box,
+/// keeping their proportions on screen like rotating a shape does. The curve
+/// shapes keep the points they were given and turn a copy on every `update`,
+/// so turning them back and forth doesn't make them drift.
+pub fn orient(points: &[Vec2<f32>], orientation: Orientation) -> Vec<Vec2<f32>> {
+    let pivot = center(points);
+    let rad = Triangle::orientation_rad(orientation);
+    points
+        .iter()
+        .map(|point| {
+            let local = Vec2::new((point.x - pivot.x) / X_SCALE, pivot.y - point.y).rotate(rad);
+            Vec2::new(pivot.x + local.x * X_SCALE, pivot.y - local.y)
+        })
+        .collect()
+}
+
+/// Move `points` so that the middle of their box ends up at `pos`.
+pub fn move_to(points: &mut [Vec2<f32>], pos: Vec2<f32>) {
+    let delta = pos - center(points);
+    for point in points {
+        *point += delta;
+    }
+}
+
+/// Whether `p` is within `reach` rows of the path through `points`.
+pub fn near(points: &[Vec2<f32>], closed: bool, p: Vec2<f32>, reach: f32) -> bool {
+    segments(points, closed).any(|(a, b)| {
+        let (a, b, p) = (square(a), square(b), square(p));
+        let ab = b - a;
+        let t = (((p.x - a.x) * ab.x + (p.y - a.y) * ab.y) / (ab.x * ab.x + ab.y * ab.y).max(f32::EPSILON)).clamp(0.0, 1.0);
+        let off = p - (a + ab * t);
+        off.x * off.x + off.y * off.y <= reach * reach
+    })
+}
+
+fn bounds(points: &[Vec2<f32>]) -> (Vec2<f32>, Vec2<f32>) {
+    if points.is_empty() {
+        return (Vec2::splat(0.0), Vec2::splat(0.0));
+    }
+    points.iter().fold((Vec2::splat(f32::INFINITY), Vec2::splat(f32::NEG_INFINITY)), |(min, max), p| {
+        (Vec2::new(min.x.min(p.x), min.y.min(p.y)), Vec2::new(max.x.max(p.x), max.y.max(p.y)))
+    })
+}
+
+fn segments(points: &[Vec2<f32>], closed: bool) -> impl Iterator<Item = (Vec2<f32>, Vec2<f32>)> + '_ {
+    let closing = (closed && points.len() > 2).then(|| (points[points.len() - 1], points[0]));
+    points.windows(2).map(|pair| (pair[0], pair[1])).chain(closing)
+}
+
+fn square(p: Vec2<f32>) -> Vec2<f32> {
+    Vec2::new(p.x / X_SCALE, p.y)
+}
+
+fn distance(a: Vec2<f32>, b: Vec2<f32>) -> f32 {
+    let d = square(b) - square(a);
+    (d.x * d.x + d.y * d.y).sqrt()
+}
@@ -0,0 +1,119 @@
+use crate::{
+    shapes::{
+        self, Orientation, Shape,
+        fill::{Fill, FillFrame},
+        pixel::Pixel,
+        sector::Sector,
+        style::ShapeStyle,
+        triangle::Triangle,
+    },
+    types::{pos2::Pos2, vec2::Vec2},
+};
+
+/// A filled slice of a circle, like one entry of a radial menu or a pie
+/// chart.
+#[derive(Clone)]
+pub struct Pie {
+    pub center: Pos2,
+    pub radius: f32,
+    /// Angle the slice starts at, in radians clockwise from straight up.
+    pub start: f32,
+    /// Angle the slice ends at, in radians clockwise from straight up.
+    pub end: f32,
+    pub orientation: Orientation,
+    pub style: ShapeStyle,
+    pub children: Vec<Box<dyn Shape>>,
+}
+
+impl Pie {
+    pub fn new(center: Pos2, radius: f32, start: f32, end: f32, fill: impl Into<Fill>) -> Self {
+        Self {
+            center,
+            radius,
+            start,
+            end,
+            orientation: Orientation::Up,
+            style: ShapeStyle::new(fill),
+            children: vec![],
+        }
+    }
+
+    pub fn push(&mut self, child: Box<dyn Shape>) {
+        self.children.push(child);
+    }
+
+    /// The region covered by the slice.
+    pub fn sector(&self) -> Sector {
+        Sector {
+            center: self.center.into(),
+            rad: Triangle::orientation_rad(self.orientation),
+            radii: Vec2::splat(self.radius),
+            inner: 0.0,
+            angles: Some((self.start, self.end)),
+        }
+    }
+
+    /// The center, then points along the curved edge, on screen.
+    pub fn outline(&self) -> Vec<Vec2<f32>> {
+        let sector = self.sector();
+        let mut points = vec![sector.center];
+        let (start, sweep) = sector.sweep();
+        points.extend(sector.curve(start, start + sweep, 1.0));
+        points
+    }
+
+    /// The area the fill is spread over: the bounding square of the whole
+    /// circle, so slices of one pie share a gradient.
+    pub fn fill_frame(&self) -> FillFrame {
+        self.sector().fill_frame()
+    }
+}
+
+impl Shape for Pie {
+    fn draw(&self) {
+        shapes::draw(self, &self.children);
+    }
+
+    fn update(&mut self) {
+        shapes::update_children(&mut self.children, self.center);
+    }
+
+    /// The inside, then the outline on top as `draw_mode` says.
+    fn rasterize(&self, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
+        let frame = self.fill_frame();
+        if self.style.draw_mode.fills() {
+            self.sector().rasterize(&self.style.paint(frame), out, term_size);
+        }
+        self.style.rasterize_stroke(&[self.outline()], frame, out, term_size);
+    }
+
+
+    fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+    }
+
+    fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    fn pos(&self) -> Pos2 {
+        self.center
+    }
+
+    fn set_pos(&mut self, pos: Pos2) {
+        self.center = pos;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.style.z_index
+    }
+
+    fn box_clone(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    /// Whether the other shape's position is inside the slice.
+    fn collides_with(&self, other: &dyn Shape) -> bool {
+        self.sector().contains(other.pos().into())
+    }
+}
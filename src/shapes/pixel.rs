@@ -1,53 +1,189 @@
-use std::io::{StdoutLock, Write};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    io::{StdoutLock, Write},
+};
 
-use crossterm::{cursor::MoveTo, queue, style::{Color, Print, SetForegroundColor}};
+use crossterm::{
+    cursor::MoveTo,
+    queue,
+    style::{Attribute, Print, ResetColor, SetAttribute, SetBackgroundColor, SetForegroundColor},
+};
+
+use crate::{
+    clip,
+    color_depth::Quantizer,
+    types::color::{BlendMode, Color},
+    ui::text::style::TextAttributes,
+};
+
+/// The glyph that covers its whole cell, so its foreground colour is what
+/// the cell looks like from then on.
+pub const FULL_BLOCK: char = '█';
+
+thread_local! {
+    /// The colour each cell shows behind glyphs drawn on it: the colour of a
+    /// full block, or else the background colour. Used to blend translucent
+    /// pixels and to give glyphs without a background of their own the one
+    /// of whatever is below them.
+    ///
+    /// It describes a single frame: `utils::clear_screen` empties it along
+    /// with the terminal.
+    static CANVAS: RefCell<HashMap<(u16, u16), Color>> = RefCell::new(HashMap::new());
+}
+
+/// Forget what was drawn so far. Only `utils::clear_screen` should call
+/// this, so the canvas never outlives what is on screen.
+pub(crate) fn clear_canvas() {
+    CANVAS.with_borrow_mut(HashMap::clear);
+}
+
+/// Work out the colours a glyph drawn on the cell (`x`, `y`) ends up with,
+/// and remember what the cell looks like afterwards.
+///
+/// A transparent `bg` keeps the colour already in the cell (or the
+/// terminal's default if nothing was drawn there), and a translucent one is
+/// blended with it. A translucent `fg` is blended with the resulting
+/// background. Cells nothing was drawn on count as black for blending.
+/// `fills` tells whether the glyph covers the whole cell, like `FULL_BLOCK`.
+pub fn composite(x: u16, y: u16, fills: bool, fg: Color, bg: Color, blend: BlendMode) -> (Color, Color) {
+    CANVAS.with_borrow_mut(|canvas| {
+        let below = canvas.get(&(x, y)).copied();
+        let backdrop = below.unwrap_or(Color::BLACK);
+        let mix = |color: Color, backdrop: Color| {
+            if color.a == 255 && blend == BlendMode::Normal { color } else { color.blend(backdrop, blend) }
+        };
+
+        let bg = if bg.is_transparent() { below.unwrap_or(Color::RESET) } else { mix(bg, backdrop) };
+        let fg = mix(fg, if bg.is_transparent() { backdrop } else { bg });
+
+        let surface = if fills { fg } else { bg };
+        if !surface.is_transparent() {
+            canvas.insert((x, y), surface);
+        }
+        (fg, bg)
+    })
+}
 
 /// A single raster pixel produced by shapes when rasterizing into a batch.
 ///
 /// `z` is the z-index for that pixel (lower is drawn first). `x` and `y` are
 /// terminal coordinates (u16) suitable for `crossterm::cursor::MoveTo`.
+/// Colours that aren't opaque, or whose `blend` isn't `Normal`, are blended
+/// with the cell's colour so far (see `composite`).
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Pixel {
     pub x: u16,
     pub y: u16,
     pub ch: char,
-    pub color: Color,
+    pub fg: Color,
+    /// `Color::RESET` keeps the background of whatever is below the pixel.
+    pub bg: Color,
+    pub attributes: TextAttributes,
     pub z: i32,
+    pub blend: BlendMode,
 }
 
 impl Pixel {
-    pub fn new(x: u16, y: u16, ch: char, color: Color, z: i32) -> Self {
-        Self { x, y, ch, color, z }
+    pub fn new(x: u16, y: u16, ch: char, fg: Color, z: i32) -> Self {
+        Self {
+            x,
+            y,
+            ch,
+            fg,
+            bg: Color::RESET,
+            attributes: TextAttributes::default(),
+            z,
+            blend: BlendMode::Normal,
+        }
+    }
+}
+
+/// Fade `pixels` by `opacity` and give them `blend` and `z`, as shapes do
+/// with the pixels they rasterize.
+pub fn apply_style(pixels: &mut [Pixel], opacity: f32, blend: BlendMode, z: i32) {
+    for pixel in pixels {
+        pixel.fg = pixel.fg.faded(opacity);
+        pixel.bg = pixel.bg.faded(opacity);
+        pixel.blend = blend;
+        pixel.z = z;
     }
 }
 
 /// Flush a batch of pixels to a locked stdout in a single, ordered pass.
 ///
 /// This helper will:
+/// - drop pixels outside of the current `clip` rect,
 /// - sort pixels by (z, y, x) so that z-index ordering is respected and output is
 ///   mostly row-major for better grouping,
-/// - minimize `SetForegroundColor` calls by only issuing a new color command
-///   when it changes,
+/// - keep only the last pixel pushed for each cell and z-index, so a shape
+///   whose parts overlap (like the triangles of a `Rectangle`) doesn't blend
+///   with itself,
+/// - `composite` each pixel with what is already in its cell: the pixels
+///   below it in this batch, or else what earlier batches and `Text`s drew
+///   there this frame (see `CANVAS`),
+/// - turn colours into ones the terminal can show, dithering them if asked to
+///   (see `color_depth`),
+/// - minimize colour and attribute commands by only issuing them when they
+///   change,
 /// - perform a single `flush()` at the end and clear the pixel buffer.
 pub fn flush_pixels(stdout: &mut StdoutLock<'_>, pixels: &mut Vec<Pixel>) {
+    pixels.retain(|p| clip::is_visible(p.x, p.y));
     if pixels.is_empty() {
         stdout.flush().ok();
         return;
     }
 
     // Sort by z (asc), then y (asc), then x (asc) to respect z-index and draw
-    // in a mostly row-major order for better write locality.
+    // in a mostly row-major order for better write locality. The sort is
+    // stable, so the last pixel pushed for a cell comes last.
     pixels.sort_by_key(|p| (p.z, p.y, p.x));
+    pixels.dedup_by(|next, kept| {
+        let same = (next.z, next.y, next.x) == (kept.z, kept.y, kept.x);
+        if same {
+            *kept = *next;
+        }
+        same
+    });
 
-    let mut last_color: Option<Color> = None;
+    // Backgrounds get their own quantizer so their dithering errors don't
+    // spill into the foregrounds.
+    let (mut fg_quantizer, mut bg_quantizer) = (Quantizer::new(), Quantizer::new());
+    let mut last_z = pixels[0].z;
+    let mut last_style = None;
     for p in pixels.iter() {
-        if last_color != Some(p.color) {
-            queue!(stdout, SetForegroundColor(p.color)).unwrap();
-            last_color = Some(p.color);
+        if p.z != last_z {
+            fg_quantizer.reset();
+            bg_quantizer.reset();
+            last_z = p.z;
+        }
+
+        let (fg, bg) = composite(p.x, p.y, p.ch == FULL_BLOCK, p.fg, p.bg, p.blend);
+        let style = (fg_quantizer.quantize(fg, p.x, p.y), bg_quantizer.quantize(bg, p.x, p.y), p.attributes);
+        if last_style != Some(style) {
+            let (fg, bg, attributes) = style;
+            queue!(stdout, SetAttribute(Attribute::Reset), SetForegroundColor(fg), SetBackgroundColor(bg)).unwrap();
+            queue_attributes(stdout, attributes);
+            last_style = Some(style);
         }
         queue!(stdout, MoveTo(p.x, p.y), Print(p.ch)).unwrap();
     }
 
+    // Leave the terminal as we found it, or e.g. clearing the screen would
+    // fill it with the last background colour.
+    queue!(stdout, SetAttribute(Attribute::Reset), ResetColor).unwrap();
     stdout.flush().unwrap();
     pixels.clear();
 }
+
+pub(crate) fn queue_attributes(stdout: &mut impl Write, attributes: TextAttributes) {
+    if attributes.bold {
+        queue!(stdout, SetAttribute(Attribute::Bold)).unwrap();
+    }
+    if attributes.italic {
+        queue!(stdout, SetAttribute(Attribute::Italic)).unwrap();
+    }
+    if attributes.underline {
+        queue!(stdout, SetAttribute(Attribute::Underlined)).unwrap();
+    }
+}
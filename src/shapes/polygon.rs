@@ -0,0 +1,267 @@
+use crate::{
+    X_SCALE,
+    shapes::{
+        self, Orientation, Shape,
+        edge,
+        fill::{Fill, FillFrame},
+        inside_triangle,
+        pixel::Pixel,
+        style::ShapeStyle,
+        triangle::Triangle,
+    },
+    types::{pos2::Pos2, vec2::Vec2},
+};
+
+/// Any simple polygon, convex or not, drawn as the triangles `triangulate`
+/// cuts it into.
+#[derive(Clone)]
+pub struct Polygon {
+    pub center: Pos2,
+    /// The outline, relative to `center` before rotating it, with y pointing
+    /// up like the corners of a `Triangle`. As with the other shapes, x is
+    /// stretched by `X_SCALE` on screen so the polygon keeps its proportions.
+    /// The outline shouldn't cross itself.
+    pub vertices: Vec<Vec2<f32>>,
+    pub orientation: Orientation,
+    pub style: ShapeStyle,
+    triangles: Vec<Triangle>,
+    pub children: Vec<Box<dyn Shape>>,
+}
+
+impl Polygon {
+    pub fn new(center: Pos2, vertices: Vec<Vec2<f32>>, fill: impl Into<Fill>) -> Self {
+        Self {
+            center,
+            vertices,
+            orientation: Orientation::Up,
+            style: ShapeStyle::new(fill),
+            triangles: vec![],
+            children: vec![],
+        }
+    }
+
+    pub fn push(&mut self, child: Box<dyn Shape>) {
+        self.children.push(child);
+    }
+
+    /// Where the local point `vertex` ends up on screen.
+    pub fn to_screen(&self, vertex: Vec2<f32>) -> Vec2<f32> {
+        let center: Vec2<f32> = self.center.into();
+        let rotated = vertex.rotate(Triangle::orientation_rad(self.orientation));
+        Vec2::new(center.x + rotated.x * X_SCALE, center.y - rotated.y)
+    }
+
+    /// The vertices on screen.
+    pub fn outline(&self) -> Vec<Vec2<f32>> {
+        self.vertices.iter().map(|&vertex| self.to_screen(vertex)).collect()
+    }
+
+    /// The area the fill is spread over: the polygon's bounding box, turning
+    /// with it.
+    pub fn fill_frame(&self) -> FillFrame {
+        let (mut min, mut max) = (Vec2::splat(f32::INFINITY), Vec2::splat(f32::NEG_INFINITY));
+        for vertex in &self.vertices {
+            min = Vec2::new(min.x.min(vertex.x), min.y.min(vertex.y));
+            max = Vec2::new(max.x.max(vertex.x), max.y.max(vertex.y));
+        }
+        if self.vertices.is_empty() {
+            (min, max) = (Vec2::splat(0.0), Vec2::splat(0.0));
+        }
+        FillFrame {
+            center: self.to_screen((min + max) / 2.0),
+            rad: Triangle::orientation_rad(self.orientation),
+            half_size: (max - min) / 2.0,
+        }
+    }
+}
+
+impl Shape for Polygon {
+    fn draw(&self) {
+        shapes::draw(self, &self.children);
+    }
+
+    fn update(&mut self) {
+        let frame = self.fill_frame();
+        self.triangles = triangulate(&self.vertices)
+            .into_iter()
+            .map(|[a, b, c]| {
+                let mut triangle = Triangle::from_vertices(self.center, self.orientation, [self.vertices[a], self.vertices[b], self.vertices[c]], self.style.fill.clone());
+                triangle.style = self.style.clone();
+                triangle.fill_frame = Some(frame);
+                triangle.update();
+                triangle
+            })
+            .collect();
+
+        shapes::update_children(&mut self.children, self.center);
+    }
+
+    /// The triangles making up the inside, then the outline on top as
+    /// `draw_mode` says.
+    fn rasterize(&self, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
+        if self.style.draw_mode.fills() {
+            Triangle::rasterize_inside(&self.triangles, out, term_size);
+        }
+        self.style.rasterize_stroke(&[self.outline()], self.fill_frame(), out, term_size);
+    }
+
+    fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+    }
+
+    fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    fn pos(&self) -> Pos2 {
+        self.center
+    }
+
+    fn set_pos(&mut self, pos: Pos2) {
+        self.center = pos;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.style.z_index
+    }
+
+    fn box_clone(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    /// Whether the other shape's position is inside one of the triangles, as
+    /// of the last `update`.
+    fn collides_with(&self, other: &dyn Shape) -> bool {
+        let p: Vec2<f32> = other.pos().into();
+        self.triangles.iter().any(|triangle| {
+            let [a, b, c] = triangle.vertices.to_arr();
+            inside_triangle(a, b, c, p)
+        })
+    }
+}
+
+/// Cut a simple polygon into triangles by ear clipping, returning the indices
+/// of their corners in `vertices`. Works with either winding order. If the
+/// outline crosses itself there may be no ear left to clip, and the rest is
+/// cut into a fan instead.
+pub fn triangulate(vertices: &[Vec2<f32>]) -> Vec<[usize; 3]> {
+    let mut remaining: Vec<usize> = (0..vertices.len()).collect();
+    let mut triangles = Vec::with_capacity(vertices.len().saturating_sub(2));
+
+    // Twice the signed area. Its sign gives the winding order, which decides
+    // the sign `edge` gives the convex corners.
+    let area: f32 = (0..vertices.len())
+        .map(|i| {
+            let (a, b) = (vertices[i], vertices[(i + 1) % vertices.len()]);
+            a.x * b.y - b.x * a.y
+        })
+        .sum();
+    let winding = if area < 0.0 { 1.0 } else { -1.0 };
+
+    while remaining.len() > 3 {
+        let n = remaining.len();
+        let ear = (0..n).find(|&i| {
+            let (prev, current, next) = (remaining[(i + n - 1) % n], remaining[i], remaining[(i + 1) % n]);
+            let (a, b, c) = (vertices[prev], vertices[current], vertices[next]);
+            // Reflex (and flat) corners aren't ears.
+            if edge(a, b, c) * winding <= 0.0 {
+                return false;
+            }
+            // Nothing else may be inside the ear, apart from copies of its
+            // corners (where a hole's bridge meets the outline, say).
+            let corner = |p: Vec2<f32>| [a, b, c].iter().any(|q| q.x == p.x && q.y == p.y);
+            remaining.iter().all(|&other| {
+                [prev, current, next].contains(&other) || corner(vertices[other]) || !inside_triangle(a, b, c, vertices[other])
+            })
+        });
+
+        match ear {
+            Some(i) => {
+                triangles.push([remaining[(i + n - 1) % n], remaining[i], remaining[(i + 1) % n]]);
+                remaining.remove(i);
+            }
+            None => break,
+        }
+    }
+
+    for i in 1..remaining.len().saturating_sub(1) {
+        triangles.push([remaining[0], remaining[i], remaining[i + 1]]);
+    }
+    triangles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(points: &[(f32, f32)]) -> Vec<Vec2<f32>> {
+        points.iter().map(|&(x, y)| Vec2::new(x, y)).collect()
+    }
+
+    fn area(vertices: &[Vec2<f32>]) -> f32 {
+        (0..vertices.len())
+            .map(|i| {
+                let (a, b) = (vertices[i], vertices[(i + 1) % vertices.len()]);
+                a.x * b.y - b.x * a.y
+            })
+            .sum::<f32>()
+            .abs()
+            / 2.0
+    }
+
+    /// The triangles cover the polygon exactly: one fewer than it has
+    /// corners, none of them flat, and together as big as the polygon.
+    fn check(vertices: &[Vec2<f32>]) {
+        let triangles = triangulate(vertices);
+        assert_eq!(triangles.len(), vertices.len() - 2);
+        let mut total = 0.0;
+        for [a, b, c] in triangles {
+            let triangle_area = area(&[vertices[a], vertices[b], vertices[c]]);
+            assert!(triangle_area > 1e-6, "flat triangle {a} {b} {c}");
+            total += triangle_area;
+        }
+        assert!((total - area(vertices)).abs() < 1e-4, "triangles cover {total}, polygon is {}", area(vertices));
+    }
+
+    fn reversed(vertices: &[Vec2<f32>]) -> Vec<Vec2<f32>> {
+        vertices.iter().rev().copied().collect()
+    }
+
+    #[test]
+    fn convex() {
+        let hexagon: Vec<_> = (0..6).map(|i| Vec2::new((i as f32).cos(), (i as f32).sin())).collect();
+        let square = points(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]);
+        for vertices in [hexagon, square] {
+            check(&vertices);
+            check(&reversed(&vertices));
+        }
+    }
+
+    #[test]
+    fn concave() {
+        let l = points(&[(-4.0, 4.0), (-1.0, 4.0), (-1.0, -1.0), (4.0, -1.0), (4.0, -4.0), (-4.0, -4.0)]);
+        let star: Vec<_> = (0..10)
+            .map(|i| {
+                let angle = i as f32 * std::f32::consts::TAU / 10.0;
+                let radius = if i % 2 == 0 { 5.0 } else { 2.0 };
+                Vec2::new(angle.sin(), angle.cos()) * radius
+            })
+            .collect();
+        for vertices in [l, star] {
+            check(&vertices);
+            check(&reversed(&vertices));
+        }
+    }
+
+    #[test]
+    fn collinear_vertices() {
+        // A rectangle with extra corners in the middle of its long edges, and
+        // one with a row of them along a single edge.
+        let split = points(&[(0.0, 0.0), (2.0, 0.0), (4.0, 0.0), (4.0, 2.0), (2.0, 2.0), (0.0, 2.0)]);
+        let row = points(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (3.0, 0.0), (3.0, 1.0), (0.0, 1.0)]);
+        for vertices in [split, row] {
+            check(&vertices);
+            check(&reversed(&vertices));
+        }
+    }
+}
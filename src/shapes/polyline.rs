@@ -0,0 +1,135 @@
+use std::io::stdout;
+
+use crossterm::terminal;
+
+use crate::{
+    shapes::{
+        Orientation, Shape,
+        fill::Fill,
+        path::{self, Path},
+        pixel::{self, Pixel, flush_pixels},
+        stroke::Stroke,
+    },
+    types::{color::BlendMode, pos2::Pos2, vec2::Vec2},
+};
+
+/// Straight segments through a list of points, joined as `stroke.join` says.
+#[derive(Clone)]
+pub struct Polyline {
+    /// Terminal positions the line goes through, in order.
+    pub points: Vec<Vec2<f32>>,
+    /// Whether a segment runs from the last point back to the first.
+    pub closed: bool,
+    pub stroke: Stroke,
+    /// Multiplies the alpha of the stroke, from 0 (invisible) to 1.
+    pub opacity: f32,
+    /// How the line is combined with what is below it.
+    pub blend: BlendMode,
+    /// Turns the points around the middle of their box.
+    pub orientation: Orientation,
+    pub z_index: i32,
+    /// `points` turned to `orientation`, as of the last `update`.
+    oriented: Vec<Vec2<f32>>,
+    pub children: Vec<Box<dyn Shape>>,
+}
+
+impl Polyline {
+    pub fn new(points: Vec<Vec2<f32>>, fill: impl Into<Fill>) -> Self {
+        Self {
+            oriented: points.clone(),
+            points,
+            closed: false,
+            stroke: Stroke::new(fill),
+            opacity: 1.0,
+            blend: BlendMode::Normal,
+            orientation: Orientation::Up,
+            z_index: 0,
+            children: vec![],
+        }
+    }
+
+    pub fn push(&mut self, child: Box<dyn Shape>) {
+        self.children.push(child);
+    }
+}
+
+impl Path for Polyline {
+    /// Measured along the line, so `t` moves at a steady speed.
+    fn point_at(&self, t: f32) -> Vec2<f32> {
+        self.point_at_distance(t * self.length())
+    }
+
+    fn closed(&self) -> bool {
+        self.closed
+    }
+
+    fn flattened(&self) -> Vec<Vec2<f32>> {
+        self.oriented.clone()
+    }
+}
+
+impl Shape for Polyline {
+    fn draw(&self) {
+        let mut pixels: Vec<Pixel> = Vec::with_capacity(1024);
+        self.rasterize(&mut pixels, terminal::size().unwrap());
+        flush_pixels(&mut stdout().lock(), &mut pixels);
+
+        for child in &self.children {
+            child.draw();
+        }
+    }
+
+    fn update(&mut self) {
+        self.oriented = path::orient(&self.points, self.orientation);
+
+        self.children.sort_by_key(|child| child.z_index());
+
+        let parent_pos: Vec2<f32> = self.pos().into();
+        for child in &mut self.children {
+            let relative_pos = child.pos().to_relative(parent_pos);
+
+            if let Pos2::Relative(_) = relative_pos {
+                child.set_pos(relative_pos);
+            }
+
+            child.update();
+        }
+    }
+
+    fn rasterize(&self, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
+        let start = out.len();
+        path::rasterize(self, &self.stroke, out, term_size);
+        pixel::apply_style(&mut out[start..], self.opacity, self.blend, self.z_index);
+    }
+
+    fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+    }
+
+    fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// The middle of the box around the points.
+    fn pos(&self) -> Pos2 {
+        path::center(&self.points).into()
+    }
+
+    /// Move all the points so their middle ends up at `pos`.
+    fn set_pos(&mut self, pos: Pos2) {
+        path::move_to(&mut self.points, pos.into());
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn box_clone(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    /// Whether the other shape's position is on the line.
+    fn collides_with(&self, other: &dyn Shape) -> bool {
+        path::near(&self.oriented, self.closed, other.pos().into(), self.stroke.width.max(1.0) / 2.0)
+    }
+}
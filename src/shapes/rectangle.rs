@@ -1,25 +1,28 @@
+use std::io::stdout;
+
+use crossterm::terminal;
+
 use crate::{
-    shapes::{Orientation, Shape, inside_triangle, triangle::Triangle},
+    shapes::{Orientation, Shape, fill::{Fill, FillFrame}, inside_triangle, pixel::{Pixel, flush_pixels}, style::ShapeStyle, triangle::Triangle},
     types::{pos2::Pos2, vec2::Vec2},
 };
-use crossterm::style::Color;
 
 pub struct Rectangle {
     pub pos: Pos2,
     pub size: Vec2<f32>,
     pub orientation: Orientation,
-    pub color: Color,
-    pub z_index: i32,
+    pub style: ShapeStyle,
     triangles: [Triangle; 2],
     pub children: Vec<Box<dyn Shape>>,
 }
 
 impl Rectangle {
-    pub fn new(pos: Pos2, mut size: Vec2<f32>, color: Color) -> Self {
+    pub fn new(pos: Pos2, mut size: Vec2<f32>, fill: impl Into<Fill>) -> Self {
+        let style = ShapeStyle::new(fill);
         let orientation = Orientation::Left;
         size.swap();
-        let upper = Triangle::new(pos, orientation, size, color);
-        let bottom = Triangle::new(pos, orientation.opposite(), size, color);
+        let upper = Triangle::new(pos, orientation, size, style.fill.clone());
+        let bottom = Triangle::new(pos, orientation.opposite(), size, style.fill.clone());
         // upper.vertices.bottom_right += size;
         // bottom.vertices.bottom_right += size;
 
@@ -27,8 +30,7 @@ impl Rectangle {
             pos,
             size,
             orientation,
-            color,
-            z_index: 0,
+            style,
             triangles: [upper, bottom],
             children: vec![],
         }
@@ -38,10 +40,27 @@ impl Rectangle {
         self.children.push(child);
     }
 
+    /// The area the fill is spread over: the whole rectangle, turning with it.
+    pub fn fill_frame(&self) -> FillFrame {
+        // The triangles are turned a quarter further than the rectangle, with
+        // their width and height swapped to make up for it.
+        FillFrame {
+            center: self.pos.into(),
+            rad: Triangle::orientation_rad(self.orientation) - Triangle::orientation_rad(Orientation::Left),
+            half_size: Vec2::new(self.size.y, self.size.x),
+        }
+    }
+
+    /// The corners, clockwise from the top left one.
+    pub fn outline(&self) -> Vec<Vec2<f32>> {
+        let frame = self.fill_frame();
+        [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)].map(|(u, v)| frame.point(Vec2::new(u, v))).to_vec()
+    }
+
     /// Inherent getter for this rectangle's z-index. This is useful when you
     /// have a concrete `Rectangle` (e.g. `Vec<Rectangle>`) and want to sort it.
     pub fn z_index(&self) -> i32 {
-        self.z_index
+        self.style.z_index
     }
 }
 
@@ -51,30 +70,31 @@ impl Clone for Rectangle {
         // (Triangle contains a StdoutLock which is not Clone). Children are cloned
         // via the Box<dyn Shape> Clone implementation (requires each concrete
         // shape to implement `box_clone`).
-        let upper = Triangle::new(self.pos, self.orientation, self.size, self.color);
-        let bottom = Triangle::new(self.pos, self.orientation.opposite(), self.size, self.color);
+        let upper = Triangle::new(self.pos, self.orientation, self.size, self.style.fill.clone());
+        let bottom = Triangle::new(self.pos, self.orientation.opposite(), self.size, self.style.fill.clone());
 
         Self {
             pos: self.pos,
             size: self.size,
             orientation: self.orientation,
-            color: self.color,
-            z_index: self.z_index,
+            style: self.style.clone(),
             triangles: [upper, bottom],
-            children: self.children.iter().map(|c| c.clone()).collect(),
+            children: self.children.to_vec(),
         }
     }
 }
 
 impl Shape for Rectangle {
     fn update(&mut self) {
-        let upper = Triangle::new(self.pos, self.orientation, self.size, self.color);
-        let bottom = Triangle::new(self.pos, self.orientation.opposite(), self.size, self.color);
+        let upper = Triangle::new(self.pos, self.orientation, self.size, self.style.fill.clone());
+        let bottom = Triangle::new(self.pos, self.orientation.opposite(), self.size, self.style.fill.clone());
 
         self.triangles = [upper, bottom];
-        self.triangles.sort_by_key(|triangle| triangle.z_index);
 
+        let frame = self.fill_frame();
         for triangle in &mut self.triangles {
+            triangle.style = self.style.clone();
+            triangle.fill_frame = Some(frame);
             triangle.update();
         }
 
@@ -93,9 +113,11 @@ impl Shape for Rectangle {
     }
 
     fn draw(&self) {
-        for triangle in &self.triangles {
-            triangle.draw();
-        }
+        // Both triangles go in one batch, so their shared edge isn't blended
+        // twice when the rectangle is translucent.
+        let mut pixels: Vec<Pixel> = Vec::with_capacity(1024);
+        self.rasterize(&mut pixels, terminal::size().unwrap());
+        flush_pixels(&mut stdout().lock(), &mut pixels);
 
         // is this considered recursive or..??
         for child in &self.children {
@@ -103,6 +125,15 @@ impl Shape for Rectangle {
         }
     }
 
+    /// The triangles making up the inside, then the outline on top as
+    /// `draw_mode` says.
+    fn rasterize(&self, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
+        if self.style.draw_mode.fills() {
+            Triangle::rasterize_inside(&self.triangles, out, term_size);
+        }
+        self.style.rasterize_stroke(&[self.outline()], self.fill_frame(), out, term_size);
+    }
+
     fn set_orientation(&mut self, orientation: Orientation) {
         self.orientation = orientation;
     }
@@ -116,7 +147,7 @@ impl Shape for Rectangle {
     }
 
     fn z_index(&self) -> i32 {
-        self.z_index
+        self.style.z_index
     }
 
     fn collides_with(&self, other: &dyn Shape) -> bool {
@@ -126,8 +157,8 @@ impl Shape for Rectangle {
         //
         // This approach correctly handles rotated rectangles because the
         // Triangle::update_geometry applies rotation and converts to screen coords.
-        let mut upper = Triangle::new(self.pos, self.orientation, self.size, self.color);
-        let mut bottom = Triangle::new(self.pos, self.orientation.opposite(), self.size, self.color);
+        let mut upper = Triangle::new(self.pos, self.orientation, self.size, self.style.fill.clone());
+        let mut bottom = Triangle::new(self.pos, self.orientation.opposite(), self.size, self.style.fill.clone());
         upper.update();
         bottom.update();
 
@@ -0,0 +1,116 @@
+use std::f32::consts::TAU;
+
+use crate::{
+    shapes::{
+        self, Orientation, Shape,
+        fill::{Fill, FillFrame},
+        pixel::Pixel,
+        polygon::Polygon,
+        style::ShapeStyle,
+    },
+    types::{pos2::Pos2, vec2::Vec2},
+};
+
+/// A polygon with `sides` equal sides and corners, such as a hexagon. The
+/// first corner points straight up before rotating it.
+#[derive(Clone)]
+pub struct RegularPolygon {
+    pub center: Pos2,
+    pub sides: usize,
+    /// Distance from the center to each corner, in rows.
+    pub radius: f32,
+    pub orientation: Orientation,
+    pub style: ShapeStyle,
+    polygon: Polygon,
+    pub children: Vec<Box<dyn Shape>>,
+}
+
+impl RegularPolygon {
+    pub fn new(center: Pos2, sides: usize, radius: f32, fill: impl Into<Fill>) -> Self {
+        let style = ShapeStyle::new(fill);
+        Self {
+            center,
+            sides,
+            radius,
+            orientation: Orientation::Up,
+            polygon: Polygon::new(center, vec![], style.fill.clone()),
+            style,
+            children: vec![],
+        }
+    }
+
+    pub fn push(&mut self, child: Box<dyn Shape>) {
+        self.children.push(child);
+    }
+
+    /// The corners relative to `center` before rotating, clockwise from the
+    /// top one, with y pointing up like `Polygon::vertices`.
+    pub fn vertices(&self) -> Vec<Vec2<f32>> {
+        (0..self.sides)
+            .map(|i| {
+                let angle = i as f32 * TAU / self.sides as f32;
+                Vec2::new(angle.sin(), angle.cos()) * self.radius
+            })
+            .collect()
+    }
+
+    /// The corners on screen.
+    pub fn outline(&self) -> Vec<Vec2<f32>> {
+        self.polygon.outline()
+    }
+
+    /// The area the fill is spread over: the polygon's bounding box, turning
+    /// with it.
+    pub fn fill_frame(&self) -> FillFrame {
+        self.polygon.fill_frame()
+    }
+}
+
+impl Shape for RegularPolygon {
+    fn draw(&self) {
+        shapes::draw(self, &self.children);
+    }
+
+    fn update(&mut self) {
+        let mut polygon = Polygon::new(self.center, self.vertices(), self.style.fill.clone());
+        polygon.orientation = self.orientation;
+        polygon.style = self.style.clone();
+        polygon.update();
+        self.polygon = polygon;
+
+        shapes::update_children(&mut self.children, self.center);
+    }
+
+    fn rasterize(&self, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
+        self.polygon.rasterize(out, term_size);
+    }
+
+    fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+    }
+
+    fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    fn pos(&self) -> Pos2 {
+        self.center
+    }
+
+    fn set_pos(&mut self, pos: Pos2) {
+        self.center = pos;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.style.z_index
+    }
+
+    fn box_clone(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    /// Whether the other shape's position is inside, as of the last `update`.
+    fn collides_with(&self, other: &dyn Shape) -> bool {
+        self.polygon.collides_with(other)
+    }
+}
@@ -0,0 +1,115 @@
+use std::f32::consts::TAU;
+
+use crate::{
+    shapes::{
+        self, Orientation, Shape,
+        fill::{Fill, FillFrame},
+        pixel::Pixel,
+        sector::Sector,
+        style::ShapeStyle,
+        triangle::Triangle,
+    },
+    types::{pos2::Pos2, vec2::Vec2},
+};
+
+/// A circle with a round hole in the middle.
+#[derive(Clone)]
+pub struct Ring {
+    pub center: Pos2,
+    /// Radius of the hole, in rows.
+    pub inner_radius: f32,
+    /// Radius of the outer edge, in rows.
+    pub outer_radius: f32,
+    pub orientation: Orientation,
+    pub style: ShapeStyle,
+    pub children: Vec<Box<dyn Shape>>,
+}
+
+impl Ring {
+    pub fn new(center: Pos2, inner_radius: f32, outer_radius: f32, fill: impl Into<Fill>) -> Self {
+        Self {
+            center,
+            inner_radius,
+            outer_radius,
+            orientation: Orientation::Up,
+            style: ShapeStyle::new(fill),
+            children: vec![],
+        }
+    }
+
+    pub fn push(&mut self, child: Box<dyn Shape>) {
+        self.children.push(child);
+    }
+
+    /// The region covered by the ring.
+    pub fn sector(&self) -> Sector {
+        Sector {
+            center: self.center.into(),
+            rad: Triangle::orientation_rad(self.orientation),
+            radii: Vec2::splat(self.outer_radius),
+            inner: self.inner_radius / self.outer_radius.max(f32::EPSILON),
+            angles: None,
+        }
+    }
+
+    /// Points around the outer and the inner edge, on screen.
+    pub fn outlines(&self) -> [Vec<Vec2<f32>>; 2] {
+        let sector = self.sector();
+        [sector.curve(0.0, TAU, 1.0), sector.curve(0.0, TAU, sector.inner)]
+    }
+
+    /// The area the fill is spread over: the ring's bounding square, turning
+    /// with `orientation`.
+    pub fn fill_frame(&self) -> FillFrame {
+        self.sector().fill_frame()
+    }
+}
+
+impl Shape for Ring {
+    fn draw(&self) {
+        shapes::draw(self, &self.children);
+    }
+
+    fn update(&mut self) {
+        shapes::update_children(&mut self.children, self.center);
+    }
+
+    /// The inside, then the outlines on top as `draw_mode` says.
+    fn rasterize(&self, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
+        let frame = self.fill_frame();
+        if self.style.draw_mode.fills() {
+            self.sector().rasterize(&self.style.paint(frame), out, term_size);
+        }
+        self.style.rasterize_stroke(&self.outlines(), frame, out, term_size);
+    }
+
+
+    fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+    }
+
+    fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    fn pos(&self) -> Pos2 {
+        self.center
+    }
+
+    fn set_pos(&mut self, pos: Pos2) {
+        self.center = pos;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.style.z_index
+    }
+
+    fn box_clone(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    /// Whether the other shape's position is on the ring, not in the hole.
+    fn collides_with(&self, other: &dyn Shape) -> bool {
+        self.sector().contains(other.pos().into())
+    }
+}
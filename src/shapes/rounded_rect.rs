@@ -0,0 +1,138 @@
+use std::f32::consts::FRAC_PI_2;
+
+use crate::{
+    shapes::{
+        self, Orientation, Shape,
+        fill::{Fill, FillFrame},
+        pixel::Pixel,
+        polygon::Polygon,
+        style::ShapeStyle,
+    },
+    types::{pos2::Pos2, vec2::Vec2},
+};
+
+/// A rectangle with rounded corners, for panels and buttons.
+#[derive(Clone)]
+pub struct RoundedRect {
+    pub pos: Pos2,
+    /// Half the width and height, like `Rectangle::size`.
+    pub size: Vec2<f32>,
+    /// Radius of each corner in rows, clockwise from the top left one. They
+    /// are kept to the shorter of the half width and height, and 0 leaves a
+    /// corner square.
+    pub radii: [f32; 4],
+    pub orientation: Orientation,
+    pub style: ShapeStyle,
+    polygon: Polygon,
+    pub children: Vec<Box<dyn Shape>>,
+}
+
+impl RoundedRect {
+    /// A rectangle with all four corners rounded by `radius`.
+    pub fn new(pos: Pos2, size: Vec2<f32>, radius: f32, fill: impl Into<Fill>) -> Self {
+        let style = ShapeStyle::new(fill);
+        Self {
+            pos,
+            size,
+            radii: [radius; 4],
+            orientation: Orientation::Up,
+            polygon: Polygon::new(pos, vec![], style.fill.clone()),
+            style,
+            children: vec![],
+        }
+    }
+
+    pub fn push(&mut self, child: Box<dyn Shape>) {
+        self.children.push(child);
+    }
+
+    /// The outline relative to `pos` before rotating, clockwise from the top
+    /// left corner, with y pointing up like `Polygon::vertices`.
+    pub fn vertices(&self) -> Vec<Vec2<f32>> {
+        let max_radius = self.size.x.min(self.size.y).max(0.0);
+        // Each corner's direction from the center, and the angle its curve
+        // starts at (clockwise from straight up).
+        let corners = [(-1.0, 1.0, 3.0 * FRAC_PI_2), (1.0, 1.0, 0.0), (1.0, -1.0, FRAC_PI_2), (-1.0, -1.0, 2.0 * FRAC_PI_2)];
+
+        let mut vertices = Vec::new();
+        for ((sx, sy, start), radius) in corners.into_iter().zip(self.radii) {
+            let radius = radius.clamp(0.0, max_radius);
+            let center = Vec2::new(sx * (self.size.x - radius), sy * (self.size.y - radius));
+            // About one point every cell along the curve, and just the corner
+            // itself when it isn't rounded.
+            let steps = (radius * 2.0).ceil() as usize;
+            for i in 0..=steps {
+                let angle = start + FRAC_PI_2 * i as f32 / steps.max(1) as f32;
+                vertices.push(center + Vec2::new(angle.sin(), angle.cos()) * radius);
+            }
+        }
+        // Curves as big as the rectangle meet the next one in the same point,
+        // which would leave a corner with no area for `triangulate`.
+        let same = |a: &Vec2<f32>, b: &Vec2<f32>| (a.x - b.x).abs() < 1e-4 && (a.y - b.y).abs() < 1e-4;
+        vertices.dedup_by(|a, b| same(a, b));
+        if vertices.len() > 1 && same(&vertices[0], &vertices[vertices.len() - 1]) {
+            vertices.pop();
+        }
+        vertices
+    }
+
+    /// The outline on screen.
+    pub fn outline(&self) -> Vec<Vec2<f32>> {
+        self.polygon.outline()
+    }
+
+    /// The area the fill is spread over: the whole rectangle, turning with
+    /// it.
+    pub fn fill_frame(&self) -> FillFrame {
+        self.polygon.fill_frame()
+    }
+}
+
+impl Shape for RoundedRect {
+    fn draw(&self) {
+        shapes::draw(self, &self.children);
+    }
+
+    fn update(&mut self) {
+        let mut polygon = Polygon::new(self.pos, self.vertices(), self.style.fill.clone());
+        polygon.orientation = self.orientation;
+        polygon.style = self.style.clone();
+        polygon.update();
+        self.polygon = polygon;
+
+        shapes::update_children(&mut self.children, self.pos);
+    }
+
+    fn rasterize(&self, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
+        self.polygon.rasterize(out, term_size);
+    }
+
+    fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+    }
+
+    fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    fn pos(&self) -> Pos2 {
+        self.pos
+    }
+
+    fn set_pos(&mut self, pos: Pos2) {
+        self.pos = pos;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.style.z_index
+    }
+
+    fn box_clone(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    /// Whether the other shape's position is inside, as of the last `update`.
+    fn collides_with(&self, other: &dyn Shape) -> bool {
+        self.polygon.collides_with(other)
+    }
+}
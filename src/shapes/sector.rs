@@ -0,0 +1,98 @@
+use std::f32::consts::TAU;
+
+use crate::{
+    X_SCALE,
+    shapes::{
+        antialias::Coverage,
+        fill::{FillFrame, Paint},
+        pixel::Pixel,
+    },
+    types::vec2::Vec2,
+};
+
+/// The part of an ellipse between two radii and two angles, which `Ellipse`,
+/// `Arc`, `Pie` and `Ring` are all drawn as. Whether a cell is inside is
+/// worked out from its distance and angle to the center, so the edges stay
+/// smooth at any size instead of showing the triangles of a fan.
+///
+/// Angles are in radians, clockwise from straight up like on a clock face,
+/// and turn with `rad` like the shapes' `orientation`.
+#[derive(Debug, Clone, Copy)]
+pub struct Sector {
+    pub center: Vec2<f32>,
+    pub rad: f32,
+    /// Horizontal and vertical radius of the outer edge, in rows. As with the
+    /// other shapes, x is stretched by `X_SCALE` on screen.
+    pub radii: Vec2<f32>,
+    /// Where the inside starts, as a fraction of `radii`: 0 for no hole.
+    pub inner: f32,
+    /// Start and end angle, or `None` for all the way around. The sector
+    /// runs clockwise from the start to the end.
+    pub angles: Option<(f32, f32)>,
+}
+
+impl Sector {
+    /// The terminal position `p` in the sector's local space, with y pointing
+    /// up and x no longer stretched.
+    pub fn local(&self, p: Vec2<f32>) -> Vec2<f32> {
+        Vec2::new((p.x - self.center.x) / X_SCALE, self.center.y - p.y).rotate(-self.rad)
+    }
+
+    pub fn contains(&self, p: Vec2<f32>) -> bool {
+        let local = self.local(p);
+        let (x, y) = (local.x / self.radii.x.max(f32::EPSILON), local.y / self.radii.y.max(f32::EPSILON));
+        let distance = (x * x + y * y).sqrt();
+        if distance > 1.0 || distance < self.inner {
+            return false;
+        }
+        let (start, sweep) = self.sweep();
+        sweep >= TAU || (x.atan2(y) - start).rem_euclid(TAU) <= sweep
+    }
+
+    /// The start angle and how far the sector runs clockwise from it. An end
+    /// before the start wraps round past 12 o'clock rather than swapping them.
+    pub fn sweep(&self) -> (f32, f32) {
+        match self.angles {
+            Some((start, end)) if (end - start).abs() < TAU => (start, (end - start).rem_euclid(TAU)),
+            Some((start, _)) => (start, TAU),
+            None => (0.0, TAU),
+        }
+    }
+
+    /// The terminal position at `angle` on the ellipse `scale` times the size
+    /// of the outer edge.
+    pub fn point(&self, angle: f32, scale: f32) -> Vec2<f32> {
+        let local = Vec2::new(angle.sin() * self.radii.x, angle.cos() * self.radii.y).rotate(self.rad) * scale;
+        Vec2::new(self.center.x + local.x * X_SCALE, self.center.y - local.y)
+    }
+
+    /// Points along the ellipse `scale` times the size of the outer edge,
+    /// from `from` to `to` radians, close enough together for a stroke.
+    pub fn curve(&self, from: f32, to: f32, scale: f32) -> Vec<Vec2<f32>> {
+        // Roughly one point every cell along the edge.
+        let circumference = TAU * (self.radii.x * X_SCALE).max(self.radii.y) * scale;
+        let steps = ((to - from).abs() / TAU * circumference).ceil().max(8.0) as usize;
+        (0..=steps).map(|i| self.point(from + (to - from) * i as f32 / steps as f32, scale)).collect()
+    }
+
+    /// The area a fill is spread over: the box around the whole ellipse.
+    pub fn fill_frame(&self) -> FillFrame {
+        FillFrame { center: self.center, rad: self.rad, half_size: self.radii }
+    }
+
+    /// The samples of each cell inside the sector.
+    pub fn coverage(&self, term_size: (u16, u16)) -> Coverage {
+        let reach = self.radii.x.max(self.radii.y);
+        let half = Vec2::new(reach * X_SCALE, reach) + 1.0;
+        let mut coverage = Coverage::default();
+        coverage.add_shape(self.center - half, self.center + half, term_size, |p| self.contains(p));
+        coverage
+    }
+
+    /// Push the cells inside the sector, painted with `paint`.
+    pub fn rasterize(&self, paint: &Paint, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
+        let start = out.len();
+        let covered = paint.antialiasing.rasterize(&self.coverage(term_size), paint.z_index, out);
+        paint.apply(&mut out[start..], &covered);
+    }
+}
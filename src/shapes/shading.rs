@@ -0,0 +1,63 @@
+use crate::{shapes::pixel::Pixel, types::color::Color};
+
+/// How the inside of a shape is drawn.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Shading {
+    /// Every cell is a full block in the fill's colour.
+    #[default]
+    Solid,
+    /// Every cell is a glyph from the ramp, picked by how bright and opaque
+    /// the fill is there. Whatever is below the shape shows through between
+    /// the glyphs.
+    Ramp(Ramp),
+}
+
+impl Shading {
+    /// Swap the glyphs of already coloured and faded `pixels` for ramp ones.
+    pub fn apply(&self, pixels: &mut [Pixel]) {
+        let Shading::Ramp(ramp) = self else { return };
+        for pixel in pixels {
+            pixel.ch = ramp.glyph(pixel.fg.luminance() * pixel.fg.opacity());
+            // The glyph stands for the alpha now, so it isn't blended away too.
+            pixel.fg = ramp.color.unwrap_or(pixel.fg.with_alpha(255));
+        }
+    }
+}
+
+/// Glyphs ordered from the emptiest to the densest, standing for intensities
+/// from 0 to 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ramp {
+    pub glyphs: Vec<char>,
+    /// Draws every glyph in this colour instead of the fill's, e.g. for
+    /// monochrome terminals.
+    pub color: Option<Color>,
+}
+
+impl Ramp {
+    /// The classic ten step ramp.
+    pub const STANDARD: &'static str = " .:-=+*#%@";
+    /// Paul Bourke's 70 step ramp, for finer shading on bigger shapes.
+    pub const DETAILED: &'static str = " .'`^\",:;Il!i><~+_-?][}{1)(|\\/tfjrxnuvczXYUJCLQ0OZmwqpdbkhao*#MW&8%B@$";
+    /// Unicode shade blocks.
+    pub const BLOCKS: &'static str = " ░▒▓█";
+
+    pub fn new(glyphs: &str) -> Self {
+        Self {
+            glyphs: glyphs.chars().collect(),
+            color: None,
+        }
+    }
+
+    /// The glyph for `intensity`, from 0 (the first glyph) to 1 (the last).
+    pub fn glyph(&self, intensity: f32) -> char {
+        let Some(last) = self.glyphs.len().checked_sub(1) else { return ' ' };
+        self.glyphs[(intensity.clamp(0.0, 1.0) * last as f32).round() as usize]
+    }
+}
+
+impl Default for Ramp {
+    fn default() -> Self {
+        Self::new(Self::STANDARD)
+    }
+}
@@ -0,0 +1,177 @@
+use std::io::stdout;
+
+use crossterm::terminal;
+
+use crate::{
+    shapes::{
+        Orientation, Shape,
+        fill::Fill,
+        path::{self, Path, TOLERANCE},
+        pixel::{self, Pixel, flush_pixels},
+        stroke::Stroke,
+    },
+    types::{color::BlendMode, pos2::Pos2, vec2::Vec2},
+};
+
+/// A smooth Catmull-Rom spline going through every one of its points.
+#[derive(Clone)]
+pub struct CatmullRom {
+    /// Terminal positions the curve goes through, in order.
+    pub points: Vec<Vec2<f32>>,
+    /// Whether the curve carries on from the last point back round to the
+    /// first.
+    pub closed: bool,
+    pub stroke: Stroke,
+    /// Multiplies the alpha of the stroke, from 0 (invisible) to 1.
+    pub opacity: f32,
+    /// How the curve is combined with what is below it.
+    pub blend: BlendMode,
+    /// Turns the points around the middle of their box.
+    pub orientation: Orientation,
+    pub z_index: i32,
+    /// `points` turned to `orientation`, as of the last `update`.
+    oriented: Vec<Vec2<f32>>,
+    pub children: Vec<Box<dyn Shape>>,
+}
+
+impl CatmullRom {
+    pub fn new(points: Vec<Vec2<f32>>, fill: impl Into<Fill>) -> Self {
+        Self {
+            oriented: points.clone(),
+            points,
+            closed: false,
+            stroke: Stroke::new(fill),
+            opacity: 1.0,
+            blend: BlendMode::Normal,
+            orientation: Orientation::Up,
+            z_index: 0,
+            children: vec![],
+        }
+    }
+
+    pub fn push(&mut self, child: Box<dyn Shape>) {
+        self.children.push(child);
+    }
+
+    /// How many pieces the curve is made of, one between each pair of
+    /// neighbouring points.
+    fn pieces(&self) -> usize {
+        match self.oriented.len() {
+            0 | 1 => 0,
+            n if self.closed => n,
+            n => n - 1,
+        }
+    }
+
+    /// The point `u` of the way along piece `i`, which runs from point `i` to
+    /// the next one.
+    fn piece_at(&self, i: usize, u: f32) -> Vec2<f32> {
+        let n = self.oriented.len() as isize;
+        // Open curves repeat their end points to have a neighbour on both
+        // sides.
+        let point = |j: isize| self.oriented[if self.closed { j.rem_euclid(n) } else { j.clamp(0, n - 1) } as usize];
+        let i = i as isize;
+        let (p0, p1, p2, p3) = (point(i - 1), point(i), point(i + 1), point(i + 2));
+
+        let (u2, u3) = (u * u, u * u * u);
+        (p1 * 2.0 + (p2 - p0) * u + (p0 * 2.0 - p1 * 5.0 + p2 * 4.0 - p3) * u2 + (p1 * 3.0 - p0 - p2 * 3.0 + p3) * u3) * 0.5
+    }
+}
+
+impl Path for CatmullRom {
+    /// Each piece between two points takes up an equal share of `t`, however
+    /// long it is. Use `point_at_distance` for a steady speed.
+    fn point_at(&self, t: f32) -> Vec2<f32> {
+        let pieces = self.pieces();
+        if pieces == 0 {
+            return self.oriented.first().copied().unwrap_or(Vec2::splat(0.0));
+        }
+        let along = t.clamp(0.0, 1.0) * pieces as f32;
+        let i = (along as usize).min(pieces - 1);
+        self.piece_at(i, along - i as f32)
+    }
+
+    fn closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Each piece is flattened on its own, so the curve stays smooth however
+    /// many points it has.
+    fn flattened(&self) -> Vec<Vec2<f32>> {
+        let mut points = self.oriented.first().copied().into_iter().collect::<Vec<_>>();
+        for i in 0..self.pieces() {
+            points.extend(path::flatten(|u| self.piece_at(i, u), TOLERANCE).into_iter().skip(1));
+        }
+        // A closed curve ends where it started, and `closed` joins it up.
+        if self.closed && points.len() > 1 {
+            points.pop();
+        }
+        points
+    }
+}
+
+impl Shape for CatmullRom {
+    fn draw(&self) {
+        let mut pixels: Vec<Pixel> = Vec::with_capacity(1024);
+        self.rasterize(&mut pixels, terminal::size().unwrap());
+        flush_pixels(&mut stdout().lock(), &mut pixels);
+
+        for child in &self.children {
+            child.draw();
+        }
+    }
+
+    fn update(&mut self) {
+        self.oriented = path::orient(&self.points, self.orientation);
+
+        self.children.sort_by_key(|child| child.z_index());
+
+        let parent_pos: Vec2<f32> = self.pos().into();
+        for child in &mut self.children {
+            let relative_pos = child.pos().to_relative(parent_pos);
+
+            if let Pos2::Relative(_) = relative_pos {
+                child.set_pos(relative_pos);
+            }
+
+            child.update();
+        }
+    }
+
+    fn rasterize(&self, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
+        let start = out.len();
+        path::rasterize(self, &self.stroke, out, term_size);
+        pixel::apply_style(&mut out[start..], self.opacity, self.blend, self.z_index);
+    }
+
+    fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+    }
+
+    fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// The middle of the box around the points.
+    fn pos(&self) -> Pos2 {
+        path::center(&self.points).into()
+    }
+
+    /// Move all the points so their middle ends up at `pos`.
+    fn set_pos(&mut self, pos: Pos2) {
+        path::move_to(&mut self.points, pos.into());
+    }
+
+    fn z_index(&self) -> i32 {
+        self.z_index
+    }
+
+    fn box_clone(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    /// Whether the other shape's position is on the curve.
+    fn collides_with(&self, other: &dyn Shape) -> bool {
+        path::near(&self.flattened(), self.closed, other.pos().into(), self.stroke.width.max(1.0) / 2.0)
+    }
+}
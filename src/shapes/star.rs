@@ -0,0 +1,121 @@
+use std::f32::consts::TAU;
+
+use crate::{
+    shapes::{
+        self, Orientation, Shape,
+        fill::{Fill, FillFrame},
+        pixel::Pixel,
+        polygon::Polygon,
+        style::ShapeStyle,
+    },
+    types::{pos2::Pos2, vec2::Vec2},
+};
+
+/// A star with `points` tips, the first one pointing straight up before
+/// rotating it.
+#[derive(Clone)]
+pub struct Star {
+    pub center: Pos2,
+    pub points: usize,
+    /// Distance from the center to the corners between the tips, in rows.
+    pub inner: f32,
+    /// Distance from the center to the tips, in rows.
+    pub outer: f32,
+    pub orientation: Orientation,
+    pub style: ShapeStyle,
+    polygon: Polygon,
+    pub children: Vec<Box<dyn Shape>>,
+}
+
+impl Star {
+    pub fn new(center: Pos2, points: usize, inner: f32, outer: f32, fill: impl Into<Fill>) -> Self {
+        let style = ShapeStyle::new(fill);
+        Self {
+            center,
+            points,
+            inner,
+            outer,
+            orientation: Orientation::Up,
+            polygon: Polygon::new(center, vec![], style.fill.clone()),
+            style,
+            children: vec![],
+        }
+    }
+
+    pub fn push(&mut self, child: Box<dyn Shape>) {
+        self.children.push(child);
+    }
+
+    /// The tips and the corners between them relative to `center` before
+    /// rotating, clockwise from the top tip, with y pointing up like
+    /// `Polygon::vertices`.
+    pub fn vertices(&self) -> Vec<Vec2<f32>> {
+        (0..self.points * 2)
+            .map(|i| {
+                let angle = i as f32 * TAU / (self.points * 2) as f32;
+                let radius = if i.is_multiple_of(2) { self.outer } else { self.inner };
+                Vec2::new(angle.sin(), angle.cos()) * radius
+            })
+            .collect()
+    }
+
+    /// The tips and the corners between them, on screen.
+    pub fn outline(&self) -> Vec<Vec2<f32>> {
+        self.polygon.outline()
+    }
+
+    /// The area the fill is spread over: the star's bounding box, turning
+    /// with it.
+    pub fn fill_frame(&self) -> FillFrame {
+        self.polygon.fill_frame()
+    }
+}
+
+impl Shape for Star {
+    fn draw(&self) {
+        shapes::draw(self, &self.children);
+    }
+
+    fn update(&mut self) {
+        let mut polygon = Polygon::new(self.center, self.vertices(), self.style.fill.clone());
+        polygon.orientation = self.orientation;
+        polygon.style = self.style.clone();
+        polygon.update();
+        self.polygon = polygon;
+
+        shapes::update_children(&mut self.children, self.center);
+    }
+
+    fn rasterize(&self, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
+        self.polygon.rasterize(out, term_size);
+    }
+
+    fn set_orientation(&mut self, orientation: Orientation) {
+        self.orientation = orientation;
+    }
+
+    fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    fn pos(&self) -> Pos2 {
+        self.center
+    }
+
+    fn set_pos(&mut self, pos: Pos2) {
+        self.center = pos;
+    }
+
+    fn z_index(&self) -> i32 {
+        self.style.z_index
+    }
+
+    fn box_clone(&self) -> Box<dyn Shape> {
+        Box::new(self.clone())
+    }
+
+    /// Whether the other shape's position is inside, as of the last `update`.
+    fn collides_with(&self, other: &dyn Shape) -> bool {
+        self.polygon.collides_with(other)
+    }
+}
@@ -0,0 +1,186 @@
+use std::collections::HashSet;
+
+use crate::{
+    X_SCALE,
+    shapes::{fill::{Fill, FillFrame}, inside_triangle, line, pixel::{FULL_BLOCK, Pixel}},
+    types::vec2::Vec2,
+};
+
+/// The outline of a shape.
+#[derive(Clone)]
+pub struct Stroke {
+    /// Laid out over the whole shape, like the shape's own fill.
+    pub fill: Fill,
+    /// Thickness in terminal rows, centered on the outline. Up to 1 draws a
+    /// line one cell thick.
+    pub width: f32,
+    /// Drawn in every cell of the outline. Glyphs that don't fill their cell
+    /// get the shape's fill (or whatever is below) as their background.
+    pub glyph: char,
+    /// How the corners are drawn when `width` is more than 1.
+    pub join: Join,
+}
+
+impl Stroke {
+    pub fn new(fill: impl Into<Fill>) -> Self {
+        Self {
+            fill: fill.into(),
+            width: 1.0,
+            glyph: FULL_BLOCK,
+            join: Join::Bevel,
+        }
+    }
+
+    /// Rasterize the path through `points` (back to the first one if `closed`)
+    /// into the output pixel buffer, coloured by `fill` laid out over `frame`.
+    /// `under` is the fill the stroke is drawn over, if any.
+    pub fn rasterize(
+        &self,
+        points: &[Vec2<f32>],
+        closed: bool,
+        under: Option<&Fill>,
+        frame: FillFrame,
+        out: &mut Vec<Pixel>,
+        term_size: (u16, u16),
+    ) {
+        for (x, y) in path_cells(points, closed, self.width, self.join, term_size) {
+            let uv = frame.uv(Vec2::new(x as f32, y as f32));
+            let mut pixel = Pixel::new(x, y, self.glyph, self.fill.color_at(uv), 0);
+            if let Some(under) = under && self.glyph != FULL_BLOCK {
+                pixel.bg = under.color_at(uv);
+            }
+            out.push(pixel);
+        }
+    }
+}
+
+/// How a thick path turns its corners.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Join {
+    /// The corner is cut off straight.
+    #[default]
+    Bevel,
+    /// The outer edges are carried on until they meet in a point, or cut off
+    /// like `Bevel` when that would stick out more than twice the width.
+    Miter,
+    /// The corner is rounded off.
+    Round,
+}
+
+/// Whether a shape is filled, outlined or both.
+#[derive(Clone, Default)]
+pub enum DrawMode {
+    /// Only the inside, in the shape's `fill`.
+    #[default]
+    Fill,
+    /// Only the outline, leaving the inside see-through.
+    Stroke(Stroke),
+    /// The inside with the outline on top.
+    FillAndStroke(Stroke),
+}
+
+impl DrawMode {
+    pub fn fills(&self) -> bool {
+        matches!(self, DrawMode::Fill | DrawMode::FillAndStroke(_))
+    }
+
+    pub fn stroke(&self) -> Option<&Stroke> {
+        match self {
+            DrawMode::Fill => None,
+            DrawMode::Stroke(stroke) | DrawMode::FillAndStroke(stroke) => Some(stroke),
+        }
+    }
+}
+
+/// The cells covered by the path through `points`, `width` rows thick,
+/// without duplicates. Thick paths are expanded into a quad per segment, with
+/// the gaps at the corners between them filled in as `join` says.
+pub fn path_cells(points: &[Vec2<f32>], closed: bool, width: f32, join: Join, term_size: (u16, u16)) -> Vec<(u16, u16)> {
+    let mut segments: Vec<(Vec2<f32>, Vec2<f32>)> = points.windows(2).map(|pair| (pair[0], pair[1])).collect();
+    if closed && points.len() > 2 {
+        segments.push((points[points.len() - 1], points[0]));
+    }
+
+    let mut seen = HashSet::new();
+    let mut cells = Vec::new();
+    let mut push = |cell: (u16, u16)| {
+        if seen.insert(cell) {
+            cells.push(cell);
+        }
+    };
+
+    if width <= 1.0 {
+        for (a, b) in segments {
+            line::cells(a, b, term_size).into_iter().for_each(&mut push);
+        }
+        return cells;
+    }
+
+    // Expand in a space where cells are square, so the stroke is as thick
+    // horizontally as it is vertically.
+    let square = |p: Vec2<f32>| Vec2::new(p.x / X_SCALE, p.y);
+    let half = width / 2.0;
+    let normal = |(a, b): (Vec2<f32>, Vec2<f32>)| {
+        let (dx, dy) = (b.x - a.x, b.y - a.y);
+        let length = (dx * dx + dy * dy).sqrt().max(f32::EPSILON);
+        Vec2::new(-dy / length * half, dx / length * half)
+    };
+    let segments: Vec<(Vec2<f32>, Vec2<f32>)> = segments.into_iter().map(|(a, b)| (square(a), square(b))).collect();
+
+    let mut triangles: Vec<[Vec2<f32>; 3]> = Vec::new();
+    for &(a, b) in &segments {
+        let n = normal((a, b));
+        triangles.push([a + n, b + n, b - n]);
+        triangles.push([a + n, b - n, a - n]);
+    }
+    let joins = if closed { segments.len() } else { segments.len().saturating_sub(1) };
+    for i in 0..joins {
+        let (current, next) = (segments[i], segments[(i + 1) % segments.len()]);
+        let (n1, n2, corner) = (normal(current), normal(next), current.1);
+        match join {
+            Join::Bevel => {
+                triangles.push([corner, corner + n1, corner + n2]);
+                triangles.push([corner, corner - n1, corner - n2]);
+            }
+            Join::Miter => {
+                // The outer edges meet on the bisector of the normals, further
+                // out the sharper the corner is.
+                let bisector = n1 + n2;
+                let length = (bisector.x * bisector.x + bisector.y * bisector.y).sqrt();
+                let cos = length / 2.0 / half;
+                for side in [1.0, -1.0] {
+                    if cos * 4.0 > 1.0 {
+                        let tip = corner + bisector / length * (half / cos) * side;
+                        triangles.push([corner, corner + n1 * side, tip]);
+                        triangles.push([corner, tip, corner + n2 * side]);
+                    } else {
+                        triangles.push([corner, corner + n1 * side, corner + n2 * side]);
+                    }
+                }
+            }
+            Join::Round => {
+                const STEPS: usize = 16;
+                let step = |i: usize| Vec2::new(half, 0.0).rotate(i as f32 * std::f32::consts::TAU / STEPS as f32);
+                for i in 0..STEPS {
+                    triangles.push([corner, corner + step(i), corner + step(i + 1)]);
+                }
+            }
+        }
+    }
+
+    let (term_width, term_height) = (term_size.0 as i32, term_size.1 as i32);
+    for [a, b, c] in triangles {
+        let min_x = (a.x.min(b.x).min(c.x) * X_SCALE).floor() as i32;
+        let max_x = (a.x.max(b.x).max(c.x) * X_SCALE).ceil() as i32;
+        let min_y = a.y.min(b.y).min(c.y).floor() as i32;
+        let max_y = a.y.max(b.y).max(c.y).ceil() as i32;
+        for py in min_y.max(0)..=max_y.min(term_height - 1) {
+            for px in min_x.max(0)..=max_x.min(term_width - 1) {
+                if inside_triangle(a, b, c, square(Vec2::new(px as f32, py as f32))) {
+                    push((px as u16, py as u16));
+                }
+            }
+        }
+    }
+    cells
+}
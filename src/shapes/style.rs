@@ -0,0 +1,68 @@
+use crate::{
+    shapes::{
+        antialias::AntiAliasing,
+        fill::{Fill, FillFrame, Paint},
+        pixel::{self, Pixel},
+        shading::Shading,
+        stroke::DrawMode,
+    },
+    types::{color::BlendMode, vec2::Vec2},
+};
+
+/// How a shape that can be filled and outlined is painted, kept in the
+/// `style` of every shape drawn from triangles, a `Sector` or a `Polygon`.
+#[derive(Clone)]
+pub struct ShapeStyle {
+    /// What the inside is painted with, spread over the shape's fill frame.
+    pub fill: Fill,
+    /// Whether the shape is filled with `fill`, outlined or both.
+    pub draw_mode: DrawMode,
+    /// Whether the inside is drawn with full blocks or ASCII art.
+    pub shading: Shading,
+    /// How the cells the edges only partly cover are drawn.
+    pub antialiasing: AntiAliasing,
+    /// Multiplies the alpha of the fill and outline, from 0 (invisible) to 1.
+    pub opacity: f32,
+    /// How the shape is combined with what is below it.
+    pub blend: BlendMode,
+    pub z_index: i32,
+}
+
+impl ShapeStyle {
+    pub fn new(fill: impl Into<Fill>) -> Self {
+        Self {
+            fill: fill.into(),
+            draw_mode: DrawMode::Fill,
+            shading: Shading::Solid,
+            antialiasing: AntiAliasing::Off,
+            opacity: 1.0,
+            blend: BlendMode::Normal,
+            z_index: 0,
+        }
+    }
+
+    /// How the inside is painted, with `fill` spread over `frame`.
+    pub fn paint(&self, frame: FillFrame) -> Paint<'_> {
+        Paint {
+            fill: &self.fill,
+            frame,
+            opacity: self.opacity,
+            blend: self.blend,
+            z_index: self.z_index,
+            shading: &self.shading,
+            antialiasing: self.antialiasing,
+        }
+    }
+
+    /// Stroke each closed outline on top of the inside, if `draw_mode` says
+    /// so.
+    pub fn rasterize_stroke(&self, outlines: &[Vec<Vec2<f32>>], frame: FillFrame, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
+        let Some(stroke) = self.draw_mode.stroke() else { return };
+        let start = out.len();
+        let under = self.draw_mode.fills().then_some(&self.fill);
+        for outline in outlines {
+            stroke.rasterize(outline, true, under, frame, out, term_size);
+        }
+        pixel::apply_style(&mut out[start..], self.opacity, self.blend, self.z_index);
+    }
+}
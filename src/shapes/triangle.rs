@@ -1,7 +1,6 @@
 use crate::{
-    X_SCALE, shapes::{Orientation, Shape, inside_triangle, line::Line, pixel::{Pixel, flush_pixels}}, types::{pos2::Pos2, vec2::Vec2}
+    X_SCALE, shapes::{Orientation, Shape, antialias::{AntiAliasing, Coverage}, fill::{Fill, FillFrame}, inside_triangle, line::Line, pixel::{Pixel, flush_pixels}, style::ShapeStyle}, types::{color::Color, pos2::Pos2, vec2::Vec2}
 };
-use crossterm::style::Color;
 use crossterm::terminal;
 use std::f32::consts::{FRAC_PI_2, PI};
 
@@ -14,8 +13,11 @@ pub struct Triangle {
     pub local_center: Pos2,
     /// `center` is the absolute/world center used for geometry updates and rendering.
     pub center: Pos2,
-    pub z_index: i32,
-    pub color: Color,
+    pub style: ShapeStyle,
+    /// The area `style.fill` is spread over. `None` uses the triangle's own;
+    /// shapes made of several triangles set their own, so the fill runs
+    /// across all of them.
+    pub fill_frame: Option<FillFrame>,
     lines: Vec<Line>,
     pub children: Vec<Box<dyn Shape>>,
 }
@@ -45,7 +47,7 @@ impl From<&[Vec2<f32>; 3]> for TriangleVertices {
 }
 
 impl Triangle {
-    pub fn new(center: Pos2, orientation: Orientation, size: Vec2<f32>, color: Color) -> Self {
+    pub fn new(center: Pos2, orientation: Orientation, size: Vec2<f32>, fill: impl Into<Fill>) -> Self {
         let p1 = Vec2::new(-1.0, 1.0) * size; // top left
         let p2 = Vec2::new(-1.0, -1.0) * size; // bottom left
         let p3 = Vec2::new(1.0, -1.0) * size; // bottom right
@@ -60,24 +62,30 @@ impl Triangle {
             orientation,
             local_center: center,
             center,
-            lines: vec![
-                Line::new(p1, p2, color),
-                Line::new(p2, p3, color),
-                Line::new(p3, p1, color),
-            ],
-            color,
-            z_index: 0,
+            lines: edges([p1, p2, p3]),
+            style: ShapeStyle::new(fill),
+            fill_frame: None,
             children: vec![],
         }
     }
 
+    /// A triangle with the given corners, relative to `center` before rotating
+    /// it, with y pointing up.
+    pub fn from_vertices(center: Pos2, orientation: Orientation, vertices: [Vec2<f32>; 3], fill: impl Into<Fill>) -> Self {
+        let mut triangle = Self::new(center, orientation, Vec2::splat(0.0), fill);
+        triangle.base_vertices = TriangleVertices::from(&vertices);
+        triangle.vertices = triangle.base_vertices;
+        triangle.lines = edges(vertices);
+        triangle
+    }
+
     pub fn push(&mut self, child: Box<dyn Shape>) {
         self.children.push(child);
     }
 
-    /// Rasterize triangle interior into the output pixel buffer.
-    /// `term_size` is (width, height) in terminal cells.
-    fn rasterize(&self, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
+    /// Rasterize triangle interior into the output pixel buffer, leaving the
+    /// colour to `rasterize`. `term_size` is (width, height) in terminal cells.
+    fn rasterize_fill(&self, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
         let (term_width, term_height) = (term_size.0 as i32, term_size.1 as i32);
 
         let vertices = self.vertices.to_arr();
@@ -93,21 +101,42 @@ impl Triangle {
         for py in min_y..=max_y {
             for px in min_x..=max_x {
                 let p = Vec2::new(px as f32, py as f32);
-                if inside_triangle(vertices[0], vertices[1], vertices[2], p) {
-                    if px >= 0 && py >= 0 && px < term_width && py < term_height {
-                        out.push(Pixel::new(
-                            px as u16,
-                            py as u16,
-                            '█',
-                            self.color,
-                            self.z_index,
-                        ));
-                    }
+                if inside_triangle(vertices[0], vertices[1], vertices[2], p)
+                    && px >= 0 && py >= 0 && px < term_width && py < term_height
+                {
+                    out.push(Pixel::new(px as u16, py as u16, '█', Color::RESET, self.style.z_index));
                 }
             }
         }
     }
 
+    /// Rasterize the inside of `triangles` as a single shape, in the style and
+    /// fill frame of the first one. Shapes made of several triangles give all
+    /// of them the same.
+    pub fn rasterize_inside(triangles: &[Triangle], out: &mut Vec<Pixel>, term_size: (u16, u16)) {
+        let Some(first) = triangles.first() else { return };
+        let start = out.len();
+
+        let style = &first.style;
+        let covered = if style.antialiasing == AntiAliasing::Off {
+            for triangle in triangles {
+                triangle.rasterize_fill(out, term_size);
+                for line in &triangle.lines {
+                    line.rasterize(out, term_size);
+                }
+            }
+            Vec::new()
+        } else {
+            let mut coverage = Coverage::default();
+            for triangle in triangles {
+                coverage.add_triangle(triangle.vertices.to_arr(), term_size);
+            }
+            style.antialiasing.rasterize(&coverage, style.z_index, out)
+        };
+
+        style.paint(first.fill_frame()).apply(&mut out[start..], &covered);
+    }
+
     fn update_geometry(&mut self) {
         let rad = self.rad();
 
@@ -135,11 +164,7 @@ impl Triangle {
         self.vertices.bottom_left = sp2;
         self.vertices.bottom_right = sp3;
 
-        self.lines = vec![
-            Line::new(sp1, sp2, self.color),
-            Line::new(sp2, sp3, self.color),
-            Line::new(sp3, sp1, self.color),
-        ];
+        self.lines = edges([sp1, sp2, sp3]);
         // Sort line draw order deterministically by their vertical midpoint so
         // overlapping/overdraw can be consistent. Lower midpoint (smaller y)
         // will be drawn first.
@@ -152,7 +177,12 @@ impl Triangle {
     }
 
     pub fn rad(&self) -> f32 {
-        match self.orientation {
+        Self::orientation_rad(self.orientation)
+    }
+
+    /// Rotation of a triangle with the given `orientation`, in radians.
+    pub fn orientation_rad(orientation: Orientation) -> f32 {
+        match orientation {
             Orientation::Up => 0.0,
             Orientation::Right => PI / 2.0,       // 90
             Orientation::Down => PI,              // 180
@@ -161,6 +191,16 @@ impl Triangle {
         }
     }
 
+    /// The area the fill is spread over: `fill_frame`, or else the triangle's
+    /// own bounds.
+    pub fn fill_frame(&self) -> FillFrame {
+        self.fill_frame.unwrap_or_else(|| FillFrame {
+            center: self.center.into(),
+            rad: self.rad(),
+            half_size: Vec2::new(self.base_vertices.bottom_right.x, self.base_vertices.top_left.y),
+        })
+    }
+
     fn to_screen_coords(v: Vec2<f32>, center: Vec2<f32>) -> Vec2<f32> {
         Vec2::new(v.x + center.x, -v.y + center.y)
     }
@@ -168,24 +208,29 @@ impl Triangle {
 
 impl Shape for Triangle {
     fn draw(&self) {
-        // Rasterize the filled interior into a temporary pixel buffer and flush
-        // it in a single batched write.
+        // Rasterize the filled interior and the edges into a temporary pixel
+        // buffer and flush it in a single batched write, so translucent
+        // triangles don't blend with their own edges.
         let (w, h) = terminal::size().unwrap();
         let mut pixels: Vec<Pixel> = Vec::with_capacity(1024);
         self.rasterize(&mut pixels, (w, h));
         let mut out = std::io::stdout().lock();
         flush_pixels(&mut out, &mut pixels);
 
-        // Draw border lines on top (each `Line::draw` will rasterize and flush).
-        for line in &self.lines {
-            line.draw();
-        }
-
         for child in &self.children {
             child.draw();
         }
     }
 
+    /// The inside (see `rasterize_inside`), then the outline on top as
+    /// `draw_mode` says. Children are left out.
+    fn rasterize(&self, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
+        if self.style.draw_mode.fills() {
+            Self::rasterize_inside(std::slice::from_ref(self), out, term_size);
+        }
+        self.style.rasterize_stroke(&[self.vertices.to_arr().to_vec()], self.fill_frame(), out, term_size);
+    }
+
     fn update(&mut self) {
         self.update_geometry();
 
@@ -233,7 +278,7 @@ impl Shape for Triangle {
     }
 
     fn z_index(&self) -> i32 {
-        self.z_index
+        self.style.z_index
     }
 
     fn box_clone(&self) -> Box<dyn Shape> {
@@ -249,21 +294,21 @@ impl Shape for Triangle {
 
 impl Clone for Triangle {
     fn clone(&self) -> Self {
-        let verts = self.vertices.to_arr();
         Self {
             base_vertices: self.base_vertices,
             vertices: self.vertices,
             orientation: self.orientation,
             local_center: self.local_center,
             center: self.center,
-            z_index: self.z_index,
-            color: self.color,
-            lines: vec![
-                Line::new(verts[0], verts[1], self.color),
-                Line::new(verts[1], verts[2], self.color),
-                Line::new(verts[2], verts[0], self.color),
-            ],
-            children: self.children.iter().map(|c| c.clone()).collect(),
+            style: self.style.clone(),
+            fill_frame: self.fill_frame,
+            lines: edges(self.vertices.to_arr()),
+            children: self.children.to_vec(),
         }
     }
 }
+
+/// The outline of a triangle. Its colour is set per pixel by `rasterize`.
+fn edges([a, b, c]: [Vec2<f32>; 3]) -> Vec<Line> {
+    vec![Line::new(a, b, Color::RESET), Line::new(b, c, Color::RESET), Line::new(c, a, Color::RESET)]
+}
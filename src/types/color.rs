@@ -1 +1,465 @@
-pub type Color = crossterm::style::Color;
+use std::{fmt, str::FromStr};
+
+use color_eyre::{Report, Result, eyre::eyre};
+
+use crate::color_depth;
+
+/// An RGBA colour with 8 bits per channel. Alpha 255 is opaque and alpha 0
+/// fully transparent; a fully transparent colour is drawn with the
+/// terminal's default colour, which is what `Color::RESET` stands for.
+///
+/// Colours are only turned into crossterm colours when they are written to
+/// the terminal, through the `From` impl.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+/// How a colour is combined with the colour below it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlendMode {
+    /// The colour is painted over the one below.
+    #[default]
+    Normal,
+    /// Channels are added up, which can only lighten. Good for light and fire.
+    Add,
+    /// Channels are multiplied, which can only darken.
+    Multiply,
+    /// The inverse of multiplying the inverses, which can only lighten.
+    Screen,
+}
+
+impl Color {
+    /// The terminal's default colour.
+    pub const RESET: Self = Self::rgba(0, 0, 0, 0);
+
+    // The usual xterm values for the 16 ANSI colours.
+    pub const BLACK: Self = Self::rgb(0, 0, 0);
+    pub const DARK_RED: Self = Self::rgb(128, 0, 0);
+    pub const DARK_GREEN: Self = Self::rgb(0, 128, 0);
+    pub const DARK_YELLOW: Self = Self::rgb(128, 128, 0);
+    pub const DARK_BLUE: Self = Self::rgb(0, 0, 128);
+    pub const DARK_MAGENTA: Self = Self::rgb(128, 0, 128);
+    pub const DARK_CYAN: Self = Self::rgb(0, 128, 128);
+    pub const GREY: Self = Self::rgb(192, 192, 192);
+    pub const DARK_GREY: Self = Self::rgb(128, 128, 128);
+    pub const RED: Self = Self::rgb(255, 0, 0);
+    pub const GREEN: Self = Self::rgb(0, 255, 0);
+    pub const YELLOW: Self = Self::rgb(255, 255, 0);
+    pub const BLUE: Self = Self::rgb(0, 0, 255);
+    pub const MAGENTA: Self = Self::rgb(255, 0, 255);
+    pub const CYAN: Self = Self::rgb(0, 255, 255);
+    pub const WHITE: Self = Self::rgb(255, 255, 255);
+
+    /// The 16 ANSI colours, in the order of their codes.
+    pub const ANSI: [Self; 16] = [
+        Self::BLACK, Self::DARK_RED, Self::DARK_GREEN, Self::DARK_YELLOW,
+        Self::DARK_BLUE, Self::DARK_MAGENTA, Self::DARK_CYAN, Self::GREY,
+        Self::DARK_GREY, Self::RED, Self::GREEN, Self::YELLOW,
+        Self::BLUE, Self::MAGENTA, Self::CYAN, Self::WHITE,
+    ];
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b, a: 255 }
+    }
+
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// The colour with code `value` in the xterm 256 colour palette.
+    pub const fn ansi(value: u8) -> Self {
+        match value {
+            0..=15 => Self::ANSI[value as usize],
+            // 6x6x6 colour cube.
+            16..=231 => {
+                const fn level(i: u8) -> u8 {
+                    if i == 0 { 0 } else { 55 + i * 40 }
+                }
+                let i = value - 16;
+                Self::rgb(level(i / 36), level(i / 6 % 6), level(i % 6))
+            }
+            // Greyscale ramp.
+            232..=255 => {
+                let grey = 8 + (value - 232) * 10;
+                Self::rgb(grey, grey, grey)
+            }
+        }
+    }
+
+    /// Parse `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`. The `#` is optional.
+    pub fn from_hex(hex: &str) -> Result<Self> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        let invalid = || eyre!("invalid hex colour `{hex}`");
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+        let channel = |i: usize, len: usize| {
+            let value = u8::from_str_radix(&digits[i * len..(i + 1) * len], 16).unwrap();
+            // Short forms repeat each digit: `f` means `ff`.
+            if len == 1 { value * 17 } else { value }
+        };
+        match digits.len() {
+            3 => Ok(Self::rgb(channel(0, 1), channel(1, 1), channel(2, 1))),
+            4 => Ok(Self::rgba(channel(0, 1), channel(1, 1), channel(2, 1), channel(3, 1))),
+            6 => Ok(Self::rgb(channel(0, 2), channel(1, 2), channel(2, 2))),
+            8 => Ok(Self::rgba(channel(0, 2), channel(1, 2), channel(2, 2), channel(3, 2))),
+            _ => Err(invalid()),
+        }
+    }
+
+    /// `#rrggbb`, or `#rrggbbaa` when the colour isn't opaque.
+    pub fn to_hex(self) -> String {
+        let Self { r, g, b, a } = self;
+        if a == 255 {
+            format!("#{r:02x}{g:02x}{b:02x}")
+        } else {
+            format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+        }
+    }
+
+    /// `hue` in degrees, `saturation` and `value` from 0 to 1.
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Self {
+        let (s, v) = (saturation.clamp(0.0, 1.0), value.clamp(0.0, 1.0));
+        let chroma = v * s;
+        Self::from_hue(hue, chroma, v - chroma)
+    }
+
+    /// Hue in degrees, saturation and value from 0 to 1. Alpha is left out.
+    pub fn to_hsv(self) -> (f32, f32, f32) {
+        let (max, min, hue) = self.hue();
+        let saturation = if max == 0.0 { 0.0 } else { (max - min) / max };
+        (hue, saturation, max)
+    }
+
+    /// `hue` in degrees, `saturation` and `lightness` from 0 to 1.
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        let (s, l) = (saturation.clamp(0.0, 1.0), lightness.clamp(0.0, 1.0));
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        Self::from_hue(hue, chroma, l - chroma / 2.0)
+    }
+
+    /// Hue in degrees, saturation and lightness from 0 to 1. Alpha is left out.
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        let (max, min, hue) = self.hue();
+        let lightness = (max + min) / 2.0;
+        let saturation = if max == min { 0.0 } else { (max - min) / (1.0 - (2.0 * lightness - 1.0).abs()) };
+        (hue, saturation, lightness)
+    }
+
+    /// The opaque colour with `hue` and `chroma`, with `min` added to each channel.
+    fn from_hue(hue: f32, chroma: f32, min: f32) -> Self {
+        let sector = hue.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+        let (r, g, b) = match sector as u8 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let channel = |c: f32| ((c + min) * 255.0).round() as u8;
+        Self::rgb(channel(r), channel(g), channel(b))
+    }
+
+    /// Largest and smallest channel from 0 to 1, and the hue in degrees.
+    fn hue(self) -> (f32, f32, f32) {
+        let [r, g, b] = [self.r, self.g, self.b].map(|c| c as f32 / 255.0);
+        let (max, min) = (r.max(g).max(b), r.min(g).min(b));
+        let delta = max - min;
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        (max, min, hue)
+    }
+
+    pub const fn with_alpha(self, a: u8) -> Self {
+        Self { a, ..self }
+    }
+
+    /// The colour with its alpha multiplied by `opacity` (from 0 to 1).
+    pub fn faded(self, opacity: f32) -> Self {
+        self.with_alpha((self.a as f32 * opacity.clamp(0.0, 1.0)).round() as u8)
+    }
+
+    /// Alpha from 0 to 1.
+    pub fn opacity(self) -> f32 {
+        self.a as f32 / 255.0
+    }
+
+    pub const fn is_transparent(self) -> bool {
+        self.a == 0
+    }
+
+    /// Perceived brightness from 0 (black) to 1 (white), ignoring alpha.
+    pub fn luminance(self) -> f32 {
+        (0.2126 * self.r as f32 + 0.7152 * self.g as f32 + 0.0722 * self.b as f32) / 255.0
+    }
+
+    /// Mix two colours, alpha included, `t = 0` being `self` and `t = 1` being `to`.
+    pub fn lerp(self, to: Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        Self::rgba(mix(self.r, to.r), mix(self.g, to.g), mix(self.b, to.b), mix(self.a, to.a))
+    }
+
+    /// Raise the HSL lightness by `amount` (from 0 to 1).
+    pub fn lighten(self, amount: f32) -> Self {
+        let (h, s, l) = self.to_hsl();
+        Self::from_hsl(h, s, l + amount).with_alpha(self.a)
+    }
+
+    /// Lower the HSL lightness by `amount` (from 0 to 1).
+    pub fn darken(self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Add up the colour channels, keeping `self`'s alpha.
+    pub fn additive(self, other: Self) -> Self {
+        self.map_channels(other, |a, b| (a + b).min(1.0))
+    }
+
+    /// Multiply the colour channels, keeping `self`'s alpha.
+    pub fn multiply(self, other: Self) -> Self {
+        self.map_channels(other, |a, b| a * b)
+    }
+
+    /// Multiply the inverted colour channels and invert the result, keeping
+    /// `self`'s alpha.
+    pub fn screen(self, other: Self) -> Self {
+        self.map_channels(other, |a, b| 1.0 - (1.0 - a) * (1.0 - b))
+    }
+
+    fn map_channels(self, other: Self, f: impl Fn(f32, f32) -> f32) -> Self {
+        let channel = |a: u8, b: u8| (f(a as f32 / 255.0, b as f32 / 255.0) * 255.0).round() as u8;
+        Self::rgba(channel(self.r, other.r), channel(self.g, other.g), channel(self.b, other.b), self.a)
+    }
+
+    /// Paint `self` over `backdrop` with `mode`, taking both alphas into
+    /// account (the usual "source over" compositing).
+    pub fn blend(self, backdrop: Self, mode: BlendMode) -> Self {
+        let mixed = match mode {
+            BlendMode::Normal => self,
+            BlendMode::Add => self.additive(backdrop),
+            BlendMode::Multiply => self.multiply(backdrop),
+            BlendMode::Screen => self.screen(backdrop),
+        };
+        if backdrop.a == 0 {
+            return mixed;
+        }
+        let alpha = self.opacity() + backdrop.opacity() * (1.0 - self.opacity());
+        let rgb = backdrop.lerp(mixed, self.opacity() / alpha);
+        rgb.with_alpha((alpha * 255.0).round() as u8)
+    }
+}
+
+impl Default for Color {
+    fn default() -> Self {
+        Self::RESET
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+/// Accepts hex colours (see `from_hex`), `reset` and the names of the ANSI
+/// colours, like `red` or `dark_grey`.
+impl FromStr for Color {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if s.starts_with('#') {
+            return Self::from_hex(s);
+        }
+        let color = match s.to_ascii_lowercase().as_str() {
+            "reset" => Self::RESET,
+            "black" => Self::BLACK,
+            "dark_red" => Self::DARK_RED,
+            "dark_green" => Self::DARK_GREEN,
+            "dark_yellow" => Self::DARK_YELLOW,
+            "dark_blue" => Self::DARK_BLUE,
+            "dark_magenta" => Self::DARK_MAGENTA,
+            "dark_cyan" => Self::DARK_CYAN,
+            "grey" | "gray" => Self::GREY,
+            "dark_grey" | "dark_gray" => Self::DARK_GREY,
+            "red" => Self::RED,
+            "green" => Self::GREEN,
+            "yellow" => Self::YELLOW,
+            "blue" => Self::BLUE,
+            "magenta" => Self::MAGENTA,
+            "cyan" => Self::CYAN,
+            "white" => Self::WHITE,
+            _ => return Err(eyre!("unknown colour `{s}`")),
+        };
+        Ok(color)
+    }
+}
+
+/// Fully transparent colours become `Reset`, everything else the closest
+/// colour the terminal can show (see `color_depth`), with the alpha dropped.
+impl From<Color> for crossterm::style::Color {
+    fn from(color: Color) -> Self {
+        color_depth::current().to_crossterm(color)
+    }
+}
+
+/// Named colours get their usual xterm values.
+impl From<crossterm::style::Color> for Color {
+    fn from(color: crossterm::style::Color) -> Self {
+        use crossterm::style::Color as C;
+        match color {
+            C::Reset => Self::RESET,
+            C::Black => Self::BLACK,
+            C::DarkGrey => Self::DARK_GREY,
+            C::Red => Self::RED,
+            C::DarkRed => Self::DARK_RED,
+            C::Green => Self::GREEN,
+            C::DarkGreen => Self::DARK_GREEN,
+            C::Yellow => Self::YELLOW,
+            C::DarkYellow => Self::DARK_YELLOW,
+            C::Blue => Self::BLUE,
+            C::DarkBlue => Self::DARK_BLUE,
+            C::Magenta => Self::MAGENTA,
+            C::DarkMagenta => Self::DARK_MAGENTA,
+            C::Cyan => Self::CYAN,
+            C::DarkCyan => Self::DARK_CYAN,
+            C::White => Self::WHITE,
+            C::Grey => Self::GREY,
+            C::Rgb { r, g, b } => Self::rgb(r, g, b),
+            C::AnsiValue(value) => Self::ansi(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every colour of the xterm cube, plus a few greys and odd ones.
+    fn samples() -> impl Iterator<Item = Color> {
+        (16..=255).map(Color::ansi).chain([Color::rgb(12, 200, 77), Color::rgb(250, 1, 130), Color::rgb(3, 4, 5)])
+    }
+
+    fn assert_close(a: Color, b: Color) {
+        let close = |x: u8, y: u8| x.abs_diff(y) <= 1;
+        assert!(close(a.r, b.r) && close(a.g, b.g) && close(a.b, b.b) && a.a == b.a, "{a} != {b}");
+    }
+
+    #[test]
+    fn hex() {
+        assert_eq!(Color::from_hex("#f80").unwrap(), Color::rgb(255, 136, 0));
+        assert_eq!(Color::from_hex("f808").unwrap(), Color::rgba(255, 136, 0, 136));
+        assert_eq!(Color::from_hex("#12ab9F").unwrap(), Color::rgb(0x12, 0xab, 0x9f));
+        assert_eq!(Color::from_hex("#12ab9f80").unwrap(), Color::rgba(0x12, 0xab, 0x9f, 0x80));
+        for invalid in ["", "#", "#12", "#12345", "#1234567", "#ggg", "#+12"] {
+            assert!(Color::from_hex(invalid).is_err(), "{invalid}");
+        }
+
+        assert_eq!(Color::rgb(0x12, 0xab, 0x9f).to_hex(), "#12ab9f");
+        assert_eq!(Color::rgba(0x12, 0xab, 0x9f, 0x80).to_string(), "#12ab9f80");
+        for color in samples() {
+            assert_eq!(Color::from_hex(&color.to_hex()).unwrap(), color);
+        }
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!("red".parse::<Color>().unwrap(), Color::RED);
+        assert_eq!("Dark_Gray".parse::<Color>().unwrap(), Color::DARK_GREY);
+        assert_eq!("reset".parse::<Color>().unwrap(), Color::RESET);
+        assert_eq!("#00f".parse::<Color>().unwrap(), Color::BLUE);
+        assert!("crimson".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn ansi_palette() {
+        assert_eq!(Color::ansi(1), Color::DARK_RED);
+        assert_eq!(Color::ansi(16), Color::BLACK);
+        assert_eq!(Color::ansi(21), Color::BLUE);
+        assert_eq!(Color::ansi(196), Color::RED);
+        assert_eq!(Color::ansi(110), Color::rgb(135, 175, 215));
+        assert_eq!(Color::ansi(231), Color::WHITE);
+        assert_eq!(Color::ansi(232), Color::rgb(8, 8, 8));
+        assert_eq!(Color::ansi(255), Color::rgb(238, 238, 238));
+    }
+
+    #[test]
+    fn hsv() {
+        assert_eq!(Color::from_hsv(0.0, 1.0, 1.0), Color::RED);
+        assert_eq!(Color::from_hsv(120.0, 1.0, 1.0), Color::GREEN);
+        assert_eq!(Color::from_hsv(-120.0, 1.0, 0.5), Color::DARK_BLUE);
+        assert_eq!(Color::from_hsv(300.0, 0.0, 1.0), Color::WHITE);
+        assert_eq!(Color::YELLOW.to_hsv(), (60.0, 1.0, 1.0));
+        assert_eq!(Color::BLACK.to_hsv(), (0.0, 0.0, 0.0));
+        for color in samples() {
+            let (h, s, v) = color.to_hsv();
+            assert_close(Color::from_hsv(h, s, v), color);
+        }
+    }
+
+    #[test]
+    fn hsl() {
+        assert_eq!(Color::from_hsl(0.0, 1.0, 0.5), Color::RED);
+        assert_eq!(Color::from_hsl(180.0, 1.0, 0.5), Color::CYAN);
+        assert_eq!(Color::from_hsl(42.0, 0.0, 1.0), Color::WHITE);
+        assert_eq!(Color::from_hsl(42.0, 1.0, 0.0), Color::BLACK);
+        assert_eq!(Color::MAGENTA.to_hsl(), (300.0, 1.0, 0.5));
+        assert_eq!(Color::WHITE.to_hsl(), (0.0, 0.0, 1.0));
+        for color in samples() {
+            let (h, s, l) = color.to_hsl();
+            assert_close(Color::from_hsl(h, s, l), color);
+        }
+    }
+
+    #[test]
+    fn lerp_lighten_and_fade() {
+        assert_eq!(Color::BLACK.lerp(Color::WHITE, 0.5), Color::rgb(128, 128, 128));
+        assert_eq!(Color::BLACK.lerp(Color::WHITE, 2.0), Color::WHITE);
+        assert_eq!(Color::BLACK.lerp(Color::WHITE, -1.0), Color::BLACK);
+        assert_eq!(Color::RED.lerp(Color::RESET, 0.5), Color::rgba(128, 0, 0, 128));
+
+        assert_eq!(Color::BLACK.lighten(1.0), Color::WHITE);
+        assert_eq!(Color::RED.darken(0.5), Color::BLACK);
+        assert_eq!(Color::RED.with_alpha(10).lighten(0.0), Color::RED.with_alpha(10));
+
+        assert_eq!(Color::RED.faded(0.5).a, 128);
+        assert_eq!(Color::RED.faded(2.0), Color::RED);
+        assert!(Color::RED.faded(0.0).is_transparent());
+    }
+
+    #[test]
+    fn blend_normal() {
+        assert_eq!(Color::RED.blend(Color::BLUE, BlendMode::Normal), Color::RED);
+        assert_eq!(Color::RED.with_alpha(128).blend(Color::BLUE, BlendMode::Normal), Color::rgb(128, 0, 127));
+        // Two halves make three quarters.
+        let half = Color::WHITE.with_alpha(128);
+        assert_eq!(half.blend(Color::BLACK.with_alpha(128), BlendMode::Normal), Color::rgba(170, 170, 170, 192));
+        // Nothing below: the colour stays as it is.
+        assert_eq!(half.blend(Color::RESET, BlendMode::Normal), half);
+    }
+
+    #[test]
+    fn blend_modes() {
+        let (a, b) = (Color::rgb(200, 100, 0), Color::rgb(100, 100, 100));
+        assert_eq!(a.blend(b, BlendMode::Add), Color::rgb(255, 200, 100));
+        assert_eq!(Color::rgb(255, 128, 0).blend(Color::rgb(128, 255, 255), BlendMode::Multiply), Color::rgb(128, 128, 0));
+        assert_eq!(Color::WHITE.blend(b, BlendMode::Multiply), b);
+        assert_eq!(Color::rgb(128, 0, 255).blend(Color::rgb(128, 0, 0), BlendMode::Screen), Color::rgb(192, 0, 255));
+        assert_eq!(Color::BLACK.blend(b, BlendMode::Screen), b);
+        // A translucent light only adds half of itself.
+        assert_eq!(Color::rgba(100, 100, 100, 128).blend(b, BlendMode::Add), Color::rgb(150, 150, 150));
+    }
+}
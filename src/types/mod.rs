@@ -2,3 +2,5 @@ pub mod vec2;
 pub mod number;
 pub mod pos2;
 pub mod color;
+pub mod palette;
+pub mod rect;
@@ -0,0 +1,97 @@
+use crate::types::color::Color;
+
+/// A fixed set of colours, e.g. to give a game the look of an old console or
+/// to snap colours to with `nearest`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    pub name: &'static str,
+    pub colors: &'static [Color],
+}
+
+impl Palette {
+    /// The PICO-8 fantasy console's 16 colours, in the order of their indices.
+    pub const PICO_8: Self = Self {
+        name: "PICO-8",
+        colors: &[
+            Color::rgb(0x00, 0x00, 0x00), // black
+            Color::rgb(0x1d, 0x2b, 0x53), // dark blue
+            Color::rgb(0x7e, 0x25, 0x53), // dark purple
+            Color::rgb(0x00, 0x87, 0x51), // dark green
+            Color::rgb(0xab, 0x52, 0x36), // brown
+            Color::rgb(0x5f, 0x57, 0x4f), // dark grey
+            Color::rgb(0xc2, 0xc3, 0xc7), // light grey
+            Color::rgb(0xff, 0xf1, 0xe8), // white
+            Color::rgb(0xff, 0x00, 0x4d), // red
+            Color::rgb(0xff, 0xa3, 0x00), // orange
+            Color::rgb(0xff, 0xec, 0x27), // yellow
+            Color::rgb(0x00, 0xe4, 0x36), // green
+            Color::rgb(0x29, 0xad, 0xff), // blue
+            Color::rgb(0x83, 0x76, 0x9c), // lavender
+            Color::rgb(0xff, 0x77, 0xa8), // pink
+            Color::rgb(0xff, 0xcc, 0xaa), // light peach
+        ],
+    };
+
+    /// The original Game Boy's four shades of green, darkest first.
+    pub const GAME_BOY: Self = Self {
+        name: "Game Boy",
+        colors: &[
+            Color::rgb(0x0f, 0x38, 0x0f),
+            Color::rgb(0x30, 0x62, 0x30),
+            Color::rgb(0x8b, 0xac, 0x0f),
+            Color::rgb(0x9b, 0xbc, 0x0f),
+        ],
+    };
+
+    /// The full 16 colour CGA palette, brown included.
+    pub const CGA: Self = Self {
+        name: "CGA",
+        colors: &[
+            Color::rgb(0x00, 0x00, 0x00), // black
+            Color::rgb(0x00, 0x00, 0xaa), // blue
+            Color::rgb(0x00, 0xaa, 0x00), // green
+            Color::rgb(0x00, 0xaa, 0xaa), // cyan
+            Color::rgb(0xaa, 0x00, 0x00), // red
+            Color::rgb(0xaa, 0x00, 0xaa), // magenta
+            Color::rgb(0xaa, 0x55, 0x00), // brown
+            Color::rgb(0xaa, 0xaa, 0xaa), // light grey
+            Color::rgb(0x55, 0x55, 0x55), // dark grey
+            Color::rgb(0x55, 0x55, 0xff), // light blue
+            Color::rgb(0x55, 0xff, 0x55), // light green
+            Color::rgb(0x55, 0xff, 0xff), // light cyan
+            Color::rgb(0xff, 0x55, 0x55), // light red
+            Color::rgb(0xff, 0x55, 0xff), // light magenta
+            Color::rgb(0xff, 0xff, 0x55), // yellow
+            Color::rgb(0xff, 0xff, 0xff), // white
+        ],
+    };
+
+    /// The 16 ANSI colours with their xterm values.
+    pub const ANSI: Self = Self { name: "ANSI", colors: &Color::ANSI };
+
+    pub const ALL: [Self; 4] = [Self::PICO_8, Self::GAME_BOY, Self::CGA, Self::ANSI];
+
+    /// Look a palette up by `name`, ignoring case.
+    pub fn by_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|palette| palette.name.eq_ignore_ascii_case(name))
+    }
+
+    /// The colour at `index`, wrapping around.
+    pub fn get(&self, index: usize) -> Color {
+        self.colors[index % self.colors.len()]
+    }
+
+    /// Index of the colour closest to `color`, by squared RGB distance.
+    pub fn nearest_index(&self, color: Color) -> usize {
+        let distance = |other: &Color| {
+            let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+            d(color.r, other.r) + d(color.g, other.g) + d(color.b, other.b)
+        };
+        (0..self.colors.len()).min_by_key(|&i| distance(&self.colors[i])).unwrap_or(0)
+    }
+
+    /// The colour closest to `color`, keeping its alpha.
+    pub fn nearest(&self, color: Color) -> Color {
+        self.colors[self.nearest_index(color)].with_alpha(color.a)
+    }
+}
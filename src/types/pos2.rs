@@ -8,9 +8,9 @@ pub enum Pos2 {
     Relative(Vec2<f32>),
 }
 
-impl Into<Vec2<f32>> for Pos2 {
-    fn into(self) -> Vec2<f32> {
-        match self {
+impl From<Pos2> for Vec2<f32> {
+    fn from(val: Pos2) -> Self {
+        match val {
             Pos2::Absolute(pos) => pos,
             Pos2::Relative(pos) => pos,
         }
@@ -0,0 +1,49 @@
+use crate::types::vec2::Vec2;
+
+/// An axis-aligned rectangle in terminal cells, described by its top-left
+/// corner and its size.
+#[derive(Debug, Clone, Copy)]
+pub struct Rect {
+    pub pos: Vec2<f32>,
+    pub size: Vec2<f32>,
+}
+
+impl Rect {
+    pub fn new(pos: Vec2<f32>, size: Vec2<f32>) -> Self {
+        Self { pos, size }
+    }
+
+    pub fn left(&self) -> f32 {
+        self.pos.x
+    }
+
+    pub fn top(&self) -> f32 {
+        self.pos.y
+    }
+
+    pub fn right(&self) -> f32 {
+        self.pos.x + self.size.x
+    }
+
+    pub fn bottom(&self) -> f32 {
+        self.pos.y + self.size.y
+    }
+
+    pub fn contains(&self, point: Vec2<f32>) -> bool {
+        point.x >= self.left() && point.x < self.right() && point.y >= self.top() && point.y < self.bottom()
+    }
+
+    /// Returns the overlapping area of both rectangles, or `None` if they
+    /// don't overlap at all.
+    pub fn intersect(&self, other: &Rect) -> Option<Rect> {
+        let left = self.left().max(other.left());
+        let top = self.top().max(other.top());
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+
+        if right <= left || bottom <= top {
+            return None;
+        }
+        Some(Rect::new(Vec2::new(left, top), Vec2::new(right - left, bottom - top)))
+    }
+}
@@ -235,11 +235,9 @@ where
     }
 }
 
-impl Into<Pos2> for Vec2<f32> {
+impl From<Vec2<f32>> for Pos2 {
     /// This `into` function will always returns an absolute position
-    fn into(self) -> Pos2 {
-        match self {
-            Vec2 { x, y } => Pos2::Absolute(Vec2 { x, y }),
-        }
+    fn from(val: Vec2<f32>) -> Self {
+        Pos2::Absolute(val)
     }
 }
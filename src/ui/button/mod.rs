@@ -0,0 +1,155 @@
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    key::{KeyCode, MouseButton},
+    types::{color::Color, pos2::Pos2, rect::Rect, vec2::Vec2},
+    ui::{
+        ElementState, InteractiveUIElement, UIElement,
+        event::EventResult,
+        layout::{LayoutItem, Sizing},
+        style::border::{Border, BorderSet},
+        text::{Text, style::{TextAlign, TextStyle, VerticalAlign}},
+        theme::{self, Theme},
+    },
+};
+
+/// A bordered, clickable label. It is highlighted while `Active` (focused),
+/// `Hovered` or `Pressed`, and greyed out while `Disabled`.
+pub struct Button {
+    pub pos: Pos2,
+    pub size: Vec2<f32>,
+    pub label: String,
+    pub state: ElementState,
+    /// Defaults to the theme's `text` colour.
+    pub color: Option<Color>,
+    /// Defaults to the theme's `focus_border` colour.
+    pub active_color: Option<Color>,
+    pub layout_item: LayoutItem,
+    /// Called when the button is clicked, or activated with Enter or Space
+    /// while focused.
+    pub on_click: Option<Box<dyn FnMut()>>,
+    pub tooltip: Option<String>,
+}
+
+impl Button {
+    pub fn new(pos: Pos2, label: String) -> Self {
+        // Room for the border and a space on each side of the label.
+        let width = label.width() as f32 + 4.0;
+        Self {
+            pos,
+            size: Vec2::new(width, 3.0),
+            label,
+            state: ElementState::Inactive,
+            color: None,
+            active_color: None,
+            layout_item: LayoutItem::new(Sizing::Fixed(width), Sizing::Fixed(3.0)),
+            on_click: None,
+            tooltip: None,
+        }
+    }
+
+    fn click(&mut self) -> EventResult {
+        match &mut self.on_click {
+            Some(on_click) => {
+                on_click();
+                EventResult::Handled
+            }
+            None => EventResult::Ignored,
+        }
+    }
+
+    pub fn rect(&self) -> Rect {
+        Rect::new(self.pos.into(), self.size)
+    }
+
+    fn current_color(&self, theme: &Theme) -> Color {
+        match self.state {
+            ElementState::Active | ElementState::Hovered => self.active_color.unwrap_or(theme.focus_border),
+            ElementState::Pressed => theme.primary,
+            ElementState::Inactive => self.color.unwrap_or(theme.text),
+            ElementState::Disabled => theme.disabled,
+        }
+    }
+}
+
+impl UIElement for Button {
+    fn draw(&self) {
+        let theme = theme::current();
+        let color = self.current_color(&theme);
+
+        let mut border = Border::new(self.pos, self.size);
+        border.color = Some(color);
+        if matches!(self.state, ElementState::Active | ElementState::Pressed) {
+            border.set = Some(BorderSet::HEAVY);
+        }
+        border.draw();
+
+        let mut label = Text::new(self.pos + Vec2::new(1.0, 1.0), self.size - Vec2::new(2.0, 2.0), self.label.clone());
+        let mut style = TextStyle::new(1, color, Color::RESET);
+        style.align = TextAlign::Center;
+        style.vertical_align = VerticalAlign::Middle;
+        style.attributes.bold = !matches!(self.state, ElementState::Inactive | ElementState::Disabled);
+        label.text_style = Some(style);
+        label.draw();
+    }
+
+    fn update(&mut self) {}
+
+    fn pos(&self) -> Pos2 {
+        self.pos
+    }
+
+    fn size(&self) -> Vec2<f32> {
+        self.size
+    }
+
+    fn set_pos(&mut self, pos: Pos2) {
+        self.pos = pos;
+    }
+
+    fn set_size(&mut self, size: Vec2<f32>) {
+        self.size = size;
+    }
+
+    fn layout_item(&self) -> LayoutItem {
+        self.layout_item
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn tooltip(&self) -> Option<&str> {
+        self.tooltip.as_deref()
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.state != ElementState::Disabled
+    }
+
+    fn set_state(&mut self, state: ElementState) {
+        if self.state != ElementState::Disabled {
+            self.state = state;
+        }
+    }
+
+    fn on_click(&mut self, _pos: Vec2<f32>, button: MouseButton) -> EventResult {
+        if button != MouseButton::Left {
+            return EventResult::Ignored;
+        }
+        self.click()
+    }
+
+    fn on_key(&mut self, key: KeyCode) -> EventResult {
+        match key {
+            KeyCode::Enter | KeyCode::Char(' ') => self.click(),
+            _ => EventResult::Ignored,
+        }
+    }
+}
+
+impl InteractiveUIElement for Button {
+    fn state(&self) -> &ElementState {
+        &self.state
+    }
+}
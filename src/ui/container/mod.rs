@@ -1,11 +1,34 @@
 pub mod style;
 
-use crate::{types::{pos2::Pos2, vec2::Vec2}, ui::{UIElement, container::style::ContainerStyle, style::border::Border}};
+use color_eyre::Result;
+
+use crate::{
+    key::{KeyCode, MouseButton},
+    types::{pos2::Pos2, rect::Rect, vec2::Vec2},
+    ui::{
+        UIElement,
+        container::style::ContainerStyle,
+        event::{ClickHandler, EventResult, KeyHandler},
+        layout::{Layout, LayoutItem},
+    },
+    utils::get_terminal_size,
+};
+
 pub struct UIContainer {
     pub pos: Pos2,
     pub size: Vec2<f32>,
     pub children: Vec<Box<dyn UIElement>>,
     pub style: Option<ContainerStyle>,
+    /// How children are arranged. When `None`, children are drawn wherever
+    /// their own `pos` says.
+    pub layout: Option<Layout>,
+    /// How this container is sized and placed by its own parent (or by the
+    /// terminal, see `fit_to_terminal`).
+    pub layout_item: LayoutItem,
+    /// Gets clicks on the container and the ones its children ignored.
+    pub on_click: Option<ClickHandler>,
+    /// Gets key presses while a child is focused and didn't handle them.
+    pub on_key: Option<KeyHandler>,
 }
 
 impl UIContainer {
@@ -13,13 +36,59 @@ impl UIContainer {
         Self {
             pos, size,
             children: Vec::new(),
-            style: None
+            style: None,
+            layout: None,
+            layout_item: LayoutItem::default(),
+            on_click: None,
+            on_key: None,
         }
     }
 
     pub fn add_child(&mut self, child: Box<dyn UIElement>) {
         self.children.push(child);
     }
+
+    /// The area children are laid out in: the container minus its style's
+    /// padding.
+    pub fn content_rect(&self) -> Rect {
+        let rect = Rect::new(self.pos.into(), self.size);
+        match &self.style {
+            Some(style) => style.padding().shrink(rect),
+            None => rect,
+        }
+    }
+
+    /// Recompute the position and size of every child from this container's
+    /// current `pos` and `size`. Does nothing if `layout` is `None`.
+    pub fn apply_layout(&mut self) {
+        let Some(layout) = self.layout else { return };
+
+        let items: Vec<(LayoutItem, Vec2<f32>)> = self
+            .children
+            .iter()
+            .map(|child| (child.layout_item(), child.intrinsic_size()))
+            .collect();
+        let area = self.content_rect();
+
+        for (child, rect) in self.children.iter_mut().zip(layout.compute(area, &items)) {
+            child.set_pos(rect.pos.into());
+            child.set_size(rect.size);
+        }
+    }
+
+    /// Size and place this container inside the whole terminal using its own
+    /// `layout_item`, then lay out its children. Call this every frame on the
+    /// root container so that the layout follows terminal resizes.
+    pub fn fit_to_terminal(&mut self) -> Result<()> {
+        let term_size = get_terminal_size()?.to_f32();
+        let screen = Rect::new(Vec2::zero(), term_size);
+
+        let rect = Layout::default().compute(screen, &[(self.layout_item, self.intrinsic_size())])[0];
+        self.pos = rect.pos.into();
+        self.size = rect.size;
+        self.apply_layout();
+        Ok(())
+    }
 }
 
 impl UIElement for UIContainer {
@@ -28,18 +97,20 @@ impl UIElement for UIContainer {
             child.draw();
         }
 
-        if let Some(style) = &self.style {
-            match style {
-                ContainerStyle { border } => {
-                    if let Some(border) = border {
-                        border.draw();
-                    }
-                }
-            }
+        if let Some(ContainerStyle { border: Some(border), .. }) = &self.style {
+            border.draw();
         }
     }
 
     fn update(&mut self) {
+        self.apply_layout();
+
+        // Keep the border wrapped around the container, wherever the layout put it.
+        if let Some(ContainerStyle { border: Some(border), .. }) = &mut self.style {
+            border.pos = self.pos;
+            border.size = self.size;
+        }
+
         for child in &mut self.children {
             child.update();
         }
@@ -52,4 +123,51 @@ impl UIElement for UIContainer {
     fn size(&self) -> Vec2<f32> {
         self.size
     }
+
+    fn set_pos(&mut self, pos: Pos2) {
+        self.pos = pos;
+    }
+
+    fn set_size(&mut self, size: Vec2<f32>) {
+        self.size = size;
+    }
+
+    /// With a layout, the size that fits the children. Otherwise the current
+    /// size, as children are placed wherever their own `pos` says.
+    fn intrinsic_size(&self) -> Vec2<f32> {
+        let Some(layout) = self.layout else { return self.size };
+        let items: Vec<(LayoutItem, Vec2<f32>)> = self
+            .children
+            .iter()
+            .map(|child| (child.layout_item(), child.intrinsic_size()))
+            .collect();
+        let padding = self.style.as_ref().map(ContainerStyle::padding).unwrap_or_default();
+        layout.measure(&items) + Vec2::new(padding.horizontal(), padding.vertical())
+    }
+
+    fn layout_item(&self) -> LayoutItem {
+        self.layout_item
+    }
+
+    fn children(&self) -> &[Box<dyn UIElement>] {
+        &self.children
+    }
+
+    fn children_mut(&mut self) -> &mut [Box<dyn UIElement>] {
+        &mut self.children
+    }
+
+    fn on_click(&mut self, pos: Vec2<f32>, button: MouseButton) -> EventResult {
+        match &mut self.on_click {
+            Some(on_click) => on_click(pos, button),
+            None => EventResult::Ignored,
+        }
+    }
+
+    fn on_key(&mut self, key: KeyCode) -> EventResult {
+        match &mut self.on_key {
+            Some(on_key) => on_key(key),
+            None => EventResult::Ignored,
+        }
+    }
 }
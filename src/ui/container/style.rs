@@ -1,11 +1,24 @@
-use crate::ui::style::border::Border;
+use crate::ui::{layout::Spacing, style::border::Border, theme};
 
 pub struct ContainerStyle {
     pub border: Option<Border>,
+    /// Space between the edges, border included, and the content. `None`
+    /// uses the current theme's `padding` when there is a border, and no
+    /// space otherwise.
+    pub padding: Option<Spacing>,
 }
 
 impl ContainerStyle {
     pub fn new(border: Option<Border>) -> Self {
-        Self { border }
+        Self { border, padding: None }
+    }
+
+    /// The padding in effect, see `padding`.
+    pub fn padding(&self) -> Spacing {
+        match (self.padding, &self.border) {
+            (Some(padding), _) => padding,
+            (None, Some(_)) => theme::current().padding,
+            (None, None) => Spacing::default(),
+        }
     }
 }
@@ -0,0 +1,226 @@
+use crate::{
+    key::{InputEvent, KeyCode, KeyInput, MouseButton},
+    types::{color::Color, rect::Rect, vec2::Vec2},
+    ui::{
+        ElementState, UIElement,
+        button::Button,
+        container::{UIContainer, style::ContainerStyle},
+        layout::{Align, Direction, Justify, Layout, LayoutItem, Sizing},
+        style::border::{Border, TitleAlign},
+        text::{Text, style::{TextAlign, TextStyle}},
+        theme::{self, Theme},
+    },
+};
+
+/// The button a dialog was closed with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DialogResult {
+    Ok,
+    /// Also returned when the dialog is closed with Escape.
+    Cancel,
+}
+
+/// What is drawn between the scene and a dialog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backdrop {
+    /// The scene stays fully visible.
+    None,
+    /// The scene is covered with a dark shade pattern.
+    Dim,
+    /// The scene is hidden behind a solid colour.
+    Block(Color),
+}
+
+/// A modal panel with a message and a row of buttons, shown on top of
+/// everything else by `UIRoot`. While it is open it gets all the input.
+pub struct Dialog {
+    /// Identifies the dialog in the `DialogResponse` it produces.
+    pub id: String,
+    pub title: String,
+    pub message: String,
+    pub buttons: Vec<(String, DialogResult)>,
+    /// Index into `buttons` of the focused button.
+    pub focused: usize,
+    pub size: Vec2<f32>,
+    pub backdrop: Backdrop,
+    /// Border and text colour. Defaults to the theme's `text` colour.
+    pub color: Option<Color>,
+    /// Fills the panel so the backdrop and scene don't show through it.
+    /// Defaults to the theme's `surface` colour.
+    pub background: Option<Color>,
+    panel: UIContainer,
+    button_rects: Vec<Rect>,
+    /// The button the left mouse button went down on.
+    pressed: Option<usize>,
+}
+
+impl Dialog {
+    pub fn new(id: impl Into<String>, title: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            message: message.into(),
+            buttons: vec![(String::from("OK"), DialogResult::Ok)],
+            focused: 0,
+            size: Vec2::new(40.0, 9.0),
+            backdrop: Backdrop::Dim,
+            color: None,
+            background: None,
+            panel: UIContainer::new(Vec2::zero().into(), Vec2::zero()),
+            button_rects: vec![],
+            pressed: None,
+        }
+    }
+
+    /// A dialog with OK and Cancel buttons, focusing Cancel.
+    pub fn confirm(id: impl Into<String>, title: impl Into<String>, message: impl Into<String>) -> Self {
+        let mut dialog = Self::new(id, title, message);
+        dialog.buttons.push((String::from("Cancel"), DialogResult::Cancel));
+        dialog.focused = 1;
+        dialog
+    }
+
+    /// Move focus with Left/Right/Tab, confirm with Enter or a click, and
+    /// cancel with Escape. Takes every event from `input` and consumes every
+    /// key, so only what happens while the dialog is open counts and the
+    /// scene doesn't see the keys. Returns the result once the dialog should
+    /// close.
+    pub fn handle_input(&mut self, input: &mut KeyInput) -> Option<DialogResult> {
+        for event in input.take_events() {
+            if let InputEvent::Key(key) = event {
+                input.consume(&key);
+            }
+            if let Some(result) = self.handle_event(event) {
+                return Some(result);
+            }
+        }
+        None
+    }
+
+    fn handle_event(&mut self, event: InputEvent) -> Option<DialogResult> {
+        let count = self.buttons.len();
+        match event {
+            InputEvent::Key(KeyCode::Esc) => return Some(DialogResult::Cancel),
+            _ if count == 0 => {}
+            InputEvent::Key(KeyCode::Left | KeyCode::BackTab) => self.focused = (self.focused + count - 1) % count,
+            InputEvent::Key(KeyCode::Right | KeyCode::Tab) => self.focused = (self.focused + 1) % count,
+            InputEvent::Key(KeyCode::Enter) => return self.buttons.get(self.focused).map(|(_, result)| *result),
+            // A click is a press and release over the same button.
+            InputEvent::MouseDown(MouseButton::Left, pos) => {
+                self.pressed = self.button_at(pos.to_f32());
+                if let Some(i) = self.pressed {
+                    self.focused = i;
+                }
+            }
+            InputEvent::MouseUp(MouseButton::Left, pos) => {
+                if let Some(i) = self.pressed.take()
+                    && self.button_at(pos.to_f32()) == Some(i)
+                {
+                    return Some(self.buttons[i].1);
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn button_at(&self, pos: Vec2<f32>) -> Option<usize> {
+        self.button_rects.iter().position(|rect| rect.contains(pos)).filter(|&i| i < self.buttons.len())
+    }
+
+    /// Where each button was placed by the last `layout`.
+    pub fn button_rects(&self) -> &[Rect] {
+        &self.button_rects
+    }
+
+    /// Rebuild the panel (so it picks up changes to the fields) and center it
+    /// inside `screen`.
+    pub fn layout(&mut self, screen: Rect) {
+        let size = Vec2::new(self.size.x.min(screen.size.x), self.size.y.min(screen.size.y));
+        let centered = screen.pos + (screen.size - size) / 2.0;
+        let pos = Vec2::new(centered.x.floor(), centered.y.floor());
+
+        let theme = theme::current();
+        let color = self.color.unwrap_or(theme.text);
+
+        let mut border = Border::new(pos.into(), size);
+        border.color = Some(color);
+        border.title = Some(self.title.clone());
+        border.title_align = TitleAlign::Center;
+
+        let mut panel = UIContainer::new(pos.into(), size);
+        panel.style = Some(ContainerStyle::new(Some(border)));
+        panel.layout = Some(Layout {
+            direction: Direction::Column,
+            gap: 1.0,
+            align: Align::Stretch,
+            justify: Justify::Start,
+            ..Default::default()
+        });
+
+        let mut message = Text::new(Vec2::zero().into(), Vec2::zero(), self.message.clone());
+        let mut message_style = TextStyle::new(1, color, Color::RESET);
+        message_style.align = TextAlign::Center;
+        message.text_style = Some(message_style);
+        message.layout_item.height = Sizing::Fill(1.0);
+        panel.add_child(Box::new(message));
+
+        let mut row = UIContainer::new(Vec2::zero().into(), Vec2::zero());
+        row.layout_item = LayoutItem::new(Sizing::Auto, Sizing::Fixed(3.0));
+        row.layout = Some(Layout {
+            direction: Direction::Row,
+            gap: BUTTON_GAP,
+            justify: Justify::Center,
+            ..Default::default()
+        });
+        for (i, (label, _)) in self.buttons.iter().enumerate() {
+            let mut button = Button::new(Vec2::zero().into(), label.clone());
+            button.state = if i == self.focused { ElementState::Active } else { ElementState::Inactive };
+            button.color = self.color;
+            button.active_color = Some(theme.focus_border);
+            row.add_child(Box::new(button));
+        }
+        panel.add_child(Box::new(row));
+
+        // Lays out the row, and the row its buttons, so they are where they
+        // get drawn.
+        panel.update();
+        let row = panel.children.last().unwrap();
+        self.button_rects = row
+            .children()
+            .iter()
+            .map(|button| Rect::new(button.pos().into(), button.size()))
+            .collect();
+        self.panel = panel;
+    }
+
+    pub fn draw(&self, screen: Rect) {
+        let theme = theme::current();
+        draw_backdrop(self.backdrop, screen, &theme);
+
+        let mut fill = Text::new(self.panel.pos, self.panel.size, String::new());
+        fill.text_style = Some(TextStyle::new(1, theme.text, self.background.unwrap_or(theme.surface)));
+        fill.draw();
+        self.panel.draw();
+    }
+}
+
+const BUTTON_GAP: f32 = 2.0;
+
+fn draw_backdrop(backdrop: Backdrop, screen: Rect, theme: &Theme) {
+    let (ch, color) = match backdrop {
+        Backdrop::None => return,
+        Backdrop::Dim => ('░', theme.disabled),
+        Backdrop::Block(color) => (' ', color),
+    };
+
+    let mut fill = Text::new(screen.pos.into(), screen.size, String::new());
+    let mut style = TextStyle::new(1, color, Color::RESET);
+    if let Backdrop::Block(color) = backdrop {
+        style.bg_color = color;
+    }
+    fill.text_style = Some(style);
+    let row: String = std::iter::repeat_n(ch, screen.size.x as usize).collect();
+    fill.content = vec![row; screen.size.y as usize].join("\n");
+    fill.draw();
+}
@@ -0,0 +1,509 @@
+use crate::{
+    key::{InputEvent, KeyCode, KeyInput, MouseButton},
+    types::{rect::Rect, vec2::Vec2},
+    ui::{ElementState, UIElement},
+};
+
+/// Whether an element dealt with an event. `Ignored` events bubble up to the
+/// element's parent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventResult {
+    Handled,
+    Ignored,
+}
+
+pub type ClickHandler = Box<dyn FnMut(Vec2<f32>, MouseButton) -> EventResult>;
+pub type KeyHandler = Box<dyn FnMut(KeyCode) -> EventResult>;
+
+/// Child indices leading from the root to an element: `[]` is the root
+/// itself, `[2, 0]` the first child of the root's third child.
+pub type ElementPath = Vec<usize>;
+
+/// Turns `KeyInput` events into calls to the `on_*` methods of a tree of
+/// `UIElement`s, and keeps track of which element is hovered, pressed and
+/// focused. An element that `captures_input` gets all the clicks and first
+/// go at every key. Elements are found by their `ElementPath`, so changing
+/// the children of a container can move focus to another element.
+#[derive(Debug, Default)]
+pub struct EventDispatcher {
+    hovered: Option<ElementPath>,
+    pressed: Option<(ElementPath, MouseButton)>,
+    focused: Option<ElementPath>,
+}
+
+impl EventDispatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn hovered(&self) -> Option<&[usize]> {
+        self.hovered.as_deref()
+    }
+
+    /// The element a mouse button went down on, until it is released.
+    pub fn pressed(&self) -> Option<&[usize]> {
+        self.pressed.as_ref().map(|(path, _)| path.as_slice())
+    }
+
+    pub fn focused(&self) -> Option<&[usize]> {
+        self.focused.as_deref()
+    }
+
+    /// Handle everything that happened since the last call: hover changes,
+    /// clicks (press and release over the same element) and key presses.
+    /// Keys go to the focused element, or the root when nothing is focused.
+    /// Unhandled Tab/BackTab presses move focus around, and any other
+    /// unhandled key is offered to every element's `on_shortcut`.
+    pub fn dispatch(&mut self, root: &mut dyn UIElement, input: &mut KeyInput) {
+        let mouse = input.mouse_pos().to_f32();
+        let hovered = target_at(root, mouse);
+        self.hover(root, hovered);
+
+        for event in input.take_events() {
+            match event {
+                InputEvent::MouseDown(button, pos) => self.mouse_down(root, button, pos.to_f32()),
+                InputEvent::MouseUp(button, pos) => self.mouse_up(root, button, pos.to_f32()),
+                InputEvent::Key(key) => {
+                    if self.key(root, key) == EventResult::Handled {
+                        input.consume(&key);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Move focus to the element at `path`, or clear it with `None`.
+    pub fn focus(&mut self, root: &mut dyn UIElement, path: Option<ElementPath>) {
+        if self.focused == path {
+            return;
+        }
+        let previous = std::mem::replace(&mut self.focused, path);
+        if let Some(previous) = previous {
+            if let Some(element) = get_mut(root, &previous) {
+                element.on_focus(false);
+            }
+            self.refresh_state(root, &previous);
+        }
+        if let Some(focused) = self.focused.clone() {
+            if let Some(element) = get_mut(root, &focused) {
+                element.on_focus(true);
+            }
+            self.refresh_state(root, &focused);
+        }
+    }
+
+    /// Focus the next (or previous) focusable element in tree order, wrapping
+    /// around. Returns `false` if there is nothing to focus.
+    pub fn focus_next(&mut self, root: &mut dyn UIElement, backwards: bool) -> bool {
+        let mut paths = Vec::new();
+        collect_focusable(root, &mut vec![], &mut paths);
+        if paths.is_empty() {
+            return false;
+        }
+
+        let current = self.focused.as_ref().and_then(|focused| paths.iter().position(|path| path == focused));
+        let next = match (current, backwards) {
+            (None, false) => 0,
+            (None, true) => paths.len() - 1,
+            (Some(i), false) => (i + 1) % paths.len(),
+            (Some(i), true) => (i + paths.len() - 1) % paths.len(),
+        };
+        self.focus(root, Some(paths.swap_remove(next)));
+        true
+    }
+
+    fn hover(&mut self, root: &mut dyn UIElement, target: Option<ElementPath>) {
+        if self.hovered == target {
+            return;
+        }
+        let previous = std::mem::replace(&mut self.hovered, target);
+        if let Some(previous) = previous {
+            if let Some(element) = get_mut(root, &previous) {
+                element.on_hover_leave();
+            }
+            self.refresh_state(root, &previous);
+        }
+        if let Some(hovered) = self.hovered.clone() {
+            if let Some(element) = get_mut(root, &hovered) {
+                element.on_hover_enter();
+            }
+            self.refresh_state(root, &hovered);
+        }
+    }
+
+    fn mouse_down(&mut self, root: &mut dyn UIElement, button: MouseButton, pos: Vec2<f32>) {
+        if let Some((previous, _)) = self.pressed.take() {
+            self.refresh_state(root, &previous);
+        }
+        let Some(target) = target_at(root, pos) else { return };
+
+        // Focus the innermost focusable element that was clicked, if any.
+        let focus = (0..=target.len())
+            .rev()
+            .map(|depth| &target[..depth])
+            .find(|path| get(root, path).is_some_and(|element| element.focusable()))
+            .map(<[usize]>::to_vec);
+        self.focus(root, focus);
+
+        self.pressed = Some((target.clone(), button));
+        self.refresh_state(root, &target);
+    }
+
+    fn mouse_up(&mut self, root: &mut dyn UIElement, button: MouseButton, pos: Vec2<f32>) {
+        let Some((target, pressed_button)) = self.pressed.take() else { return };
+        self.refresh_state(root, &target);
+
+        if pressed_button == button && target_at(root, pos).as_ref() == Some(&target) {
+            bubble(root, &target, |element| element.on_click(pos, button));
+        }
+    }
+
+    fn key(&mut self, root: &mut dyn UIElement, key: KeyCode) -> EventResult {
+        if let Some(capturing) = find_capturing(root)
+            && bubble(root, &capturing, |element| element.on_key(key)) == EventResult::Handled
+        {
+            return EventResult::Handled;
+        }
+
+        let target = self.focused.clone().unwrap_or_default();
+        if bubble(root, &target, |element| element.on_key(key)) == EventResult::Handled {
+            return EventResult::Handled;
+        }
+
+        let moved = match key {
+            KeyCode::Tab => self.focus_next(root, false),
+            KeyCode::BackTab => self.focus_next(root, true),
+            _ => false,
+        };
+        if moved {
+            return EventResult::Handled;
+        }
+        shortcut(root, key)
+    }
+
+    /// Tell the element at `path` what state it is in now.
+    fn refresh_state(&self, root: &mut dyn UIElement, path: &[usize]) {
+        let is = |other: Option<&[usize]>| other == Some(path);
+        let state = if is(self.pressed.as_ref().map(|(pressed, _)| pressed.as_slice())) {
+            ElementState::Pressed
+        } else if is(self.hovered.as_deref()) {
+            ElementState::Hovered
+        } else if is(self.focused.as_deref()) {
+            ElementState::Active
+        } else {
+            ElementState::Inactive
+        };
+        if let Some(element) = get_mut(root, path) {
+            element.set_state(state);
+        }
+    }
+}
+
+/// Where a pointer event at `point` goes: the element capturing input if
+/// there is one, or else whatever is at `point`.
+fn target_at(root: &dyn UIElement, point: Vec2<f32>) -> Option<ElementPath> {
+    find_capturing(root).or_else(|| hit_test(root, point))
+}
+
+/// Path to the first enabled element that `captures_input`.
+pub fn find_capturing(element: &dyn UIElement) -> Option<ElementPath> {
+    if !element.is_enabled() {
+        return None;
+    }
+    if element.captures_input() {
+        return Some(vec![]);
+    }
+    element.children().iter().enumerate().find_map(|(i, child)| {
+        let mut path = find_capturing(child.as_ref())?;
+        path.insert(0, i);
+        Some(path)
+    })
+}
+
+/// Offer `key` to `element` and then its children, until one handles it.
+fn shortcut(element: &mut dyn UIElement, key: KeyCode) -> EventResult {
+    if !element.is_enabled() {
+        return EventResult::Ignored;
+    }
+    if element.on_shortcut(key) == EventResult::Handled {
+        return EventResult::Handled;
+    }
+    for child in element.children_mut() {
+        if shortcut(child.as_mut(), key) == EventResult::Handled {
+            return EventResult::Handled;
+        }
+    }
+    EventResult::Ignored
+}
+
+/// Path to the innermost enabled element at `point`. Later children are drawn
+/// on top, so they are hit first.
+pub fn hit_test(element: &dyn UIElement, point: Vec2<f32>) -> Option<ElementPath> {
+    if !element.is_enabled() || !Rect::new(element.pos().into(), element.size()).contains(point) {
+        return None;
+    }
+    for (i, child) in element.children().iter().enumerate().rev() {
+        if !element.is_child_visible(i) {
+            continue;
+        }
+        if let Some(mut path) = hit_test(child.as_ref(), point) {
+            path.insert(0, i);
+            return Some(path);
+        }
+    }
+    Some(vec![])
+}
+
+pub fn get<'a>(root: &'a dyn UIElement, path: &[usize]) -> Option<&'a dyn UIElement> {
+    let Some((first, rest)) = path.split_first() else { return Some(root) };
+    get(root.children().get(*first)?.as_ref(), rest)
+}
+
+pub fn get_mut<'a>(root: &'a mut dyn UIElement, path: &[usize]) -> Option<&'a mut dyn UIElement> {
+    let Some((first, rest)) = path.split_first() else { return Some(root) };
+    get_mut(root.children_mut().get_mut(*first)?.as_mut(), rest)
+}
+
+/// Call `handler` on the element at `path`, then on each of its parents in
+/// turn until one of them handles it.
+fn bubble(
+    root: &mut dyn UIElement,
+    path: &[usize],
+    mut handler: impl FnMut(&mut dyn UIElement) -> EventResult,
+) -> EventResult {
+    for depth in (0..=path.len()).rev() {
+        if let Some(element) = get_mut(root, &path[..depth])
+            && handler(element) == EventResult::Handled
+        {
+            return EventResult::Handled;
+        }
+    }
+    EventResult::Ignored
+}
+
+fn collect_focusable(element: &dyn UIElement, path: &mut ElementPath, out: &mut Vec<ElementPath>) {
+    if !element.is_enabled() {
+        return;
+    }
+    if element.focusable() {
+        out.push(path.clone());
+    }
+    for (i, child) in element.children().iter().enumerate() {
+        if !element.is_child_visible(i) {
+            continue;
+        }
+        path.push(i);
+        collect_focusable(child.as_ref(), path, out);
+        path.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::*;
+    use crate::types::pos2::Pos2;
+
+    type Log = Rc<RefCell<Vec<String>>>;
+
+    /// An element that writes down every event it gets.
+    struct Probe {
+        name: &'static str,
+        rect: Rect,
+        children: Vec<Box<dyn UIElement>>,
+        focusable: bool,
+        /// Whether clicks and keys stop here or bubble up.
+        handles: bool,
+        hidden: Vec<usize>,
+        log: Log,
+    }
+
+    impl Probe {
+        fn new(name: &'static str, pos: (f32, f32), size: (f32, f32), log: &Log) -> Self {
+            Self {
+                name,
+                rect: Rect::new(Vec2::new(pos.0, pos.1), Vec2::new(size.0, size.1)),
+                children: Vec::new(),
+                focusable: false,
+                handles: false,
+                hidden: Vec::new(),
+                log: Rc::clone(log),
+            }
+        }
+
+        fn record(&self, event: impl std::fmt::Display) -> EventResult {
+            self.log.borrow_mut().push(format!("{} {event}", self.name));
+            if self.handles { EventResult::Handled } else { EventResult::Ignored }
+        }
+    }
+
+    impl UIElement for Probe {
+        fn pos(&self) -> Pos2 { self.rect.pos.into() }
+        fn size(&self) -> Vec2<f32> { self.rect.size }
+        fn draw(&self) {}
+        fn update(&mut self) {}
+        fn children(&self) -> &[Box<dyn UIElement>] { &self.children }
+        fn children_mut(&mut self) -> &mut [Box<dyn UIElement>] { &mut self.children }
+        fn is_child_visible(&self, index: usize) -> bool { !self.hidden.contains(&index) }
+        fn focusable(&self) -> bool { self.focusable }
+
+        fn set_state(&mut self, state: ElementState) {
+            self.record(format!("{state:?}"));
+        }
+
+        fn on_click(&mut self, _pos: Vec2<f32>, _button: MouseButton) -> EventResult {
+            self.record("click")
+        }
+
+        fn on_hover_enter(&mut self) {
+            self.record("enter");
+        }
+
+        fn on_hover_leave(&mut self) {
+            self.record("leave");
+        }
+
+        fn on_key(&mut self, key: KeyCode) -> EventResult {
+            self.record(key)
+        }
+
+        fn on_focus(&mut self, focused: bool) {
+            self.record(if focused { "focus" } else { "blur" });
+        }
+    }
+
+    /// A 20x10 root with `a` on the left (holding `inner`), `b` on the right
+    /// and `hidden` covering both.
+    fn tree() -> (Probe, Log) {
+        let log = Log::default();
+        let mut root = Probe::new("root", (0.0, 0.0), (20.0, 10.0), &log);
+
+        let mut a = Probe::new("a", (0.0, 0.0), (10.0, 10.0), &log);
+        a.focusable = true;
+        a.handles = true;
+        a.children.push(Box::new(Probe::new("inner", (2.0, 2.0), (4.0, 4.0), &log)));
+        root.children.push(Box::new(a));
+
+        let mut b = Probe::new("b", (10.0, 0.0), (10.0, 10.0), &log);
+        b.focusable = true;
+        root.children.push(Box::new(b));
+
+        let mut hidden = Probe::new("hidden", (0.0, 0.0), (20.0, 10.0), &log);
+        hidden.focusable = true;
+        hidden.handles = true;
+        root.children.push(Box::new(hidden));
+        root.hidden.push(2);
+
+        (root, log)
+    }
+
+    fn take(log: &Log) -> Vec<String> {
+        log.borrow_mut().drain(..).collect()
+    }
+
+    #[test]
+    fn hit_test_finds_the_deepest_visible_element() {
+        let (mut root, _) = tree();
+        assert_eq!(hit_test(&root, Vec2::new(3.0, 3.0)), Some(vec![0, 0]));
+        assert_eq!(hit_test(&root, Vec2::new(8.0, 3.0)), Some(vec![0]));
+        assert_eq!(hit_test(&root, Vec2::new(15.0, 5.0)), Some(vec![1]));
+        assert_eq!(hit_test(&root, Vec2::new(25.0, 5.0)), None);
+
+        root.hidden.clear();
+        assert_eq!(hit_test(&root, Vec2::new(3.0, 3.0)), Some(vec![2]));
+    }
+
+    #[test]
+    fn clicks_bubble_until_handled() {
+        let (mut root, log) = tree();
+        let mut events = EventDispatcher::new();
+        events.mouse_down(&mut root, MouseButton::Left, Vec2::new(3.0, 3.0));
+        take(&log);
+        events.mouse_up(&mut root, MouseButton::Left, Vec2::new(3.0, 3.0));
+        assert_eq!(take(&log), ["inner Inactive", "inner click", "a click"]);
+
+        // `b` ignores it, so it reaches the root.
+        events.mouse_down(&mut root, MouseButton::Left, Vec2::new(15.0, 3.0));
+        take(&log);
+        events.mouse_up(&mut root, MouseButton::Left, Vec2::new(15.0, 3.0));
+        assert_eq!(take(&log), ["b Active", "b click", "root click"]);
+    }
+
+    #[test]
+    fn a_click_needs_the_same_element_and_button() {
+        let (mut root, log) = tree();
+        let mut events = EventDispatcher::new();
+
+        events.mouse_down(&mut root, MouseButton::Left, Vec2::new(15.0, 3.0));
+        assert_eq!(events.pressed(), Some(&[1][..]));
+        assert_eq!(events.focused(), Some(&[1][..]));
+        events.mouse_up(&mut root, MouseButton::Left, Vec2::new(8.0, 3.0));
+        assert_eq!(events.pressed(), None);
+
+        events.mouse_down(&mut root, MouseButton::Left, Vec2::new(15.0, 3.0));
+        events.mouse_up(&mut root, MouseButton::Right, Vec2::new(15.0, 3.0));
+        assert!(!take(&log).iter().any(|entry| entry.ends_with("click")));
+    }
+
+    #[test]
+    fn pressing_focuses_the_innermost_focusable_element() {
+        let (mut root, log) = tree();
+        let mut events = EventDispatcher::new();
+        events.mouse_down(&mut root, MouseButton::Left, Vec2::new(3.0, 3.0));
+        assert_eq!(events.focused(), Some(&[0][..]));
+        assert_eq!(events.pressed(), Some(&[0, 0][..]));
+        assert_eq!(take(&log), ["a focus", "a Active", "inner Pressed"]);
+    }
+
+    #[test]
+    fn hover_enter_and_leave() {
+        let (mut root, log) = tree();
+        let mut events = EventDispatcher::new();
+        events.hover(&mut root, Some(vec![0, 0]));
+        events.hover(&mut root, Some(vec![0, 0]));
+        assert_eq!(take(&log), ["inner enter", "inner Hovered"]);
+
+        events.hover(&mut root, Some(vec![1]));
+        assert_eq!(take(&log), ["inner leave", "inner Inactive", "b enter", "b Hovered"]);
+        assert_eq!(events.hovered(), Some(&[1][..]));
+
+        events.hover(&mut root, None);
+        assert_eq!(take(&log), ["b leave", "b Inactive"]);
+    }
+
+    #[test]
+    fn focus_next_skips_hidden_children_and_wraps() {
+        let (mut root, _) = tree();
+        let mut events = EventDispatcher::new();
+        assert!(events.focus_next(&mut root, false));
+        assert_eq!(events.focused(), Some(&[0][..]));
+        assert!(events.focus_next(&mut root, false));
+        assert_eq!(events.focused(), Some(&[1][..]));
+        assert!(events.focus_next(&mut root, false));
+        assert_eq!(events.focused(), Some(&[0][..]));
+        assert!(events.focus_next(&mut root, true));
+        assert_eq!(events.focused(), Some(&[1][..]));
+
+        let log = Log::default();
+        let mut empty = Probe::new("empty", (0.0, 0.0), (1.0, 1.0), &log);
+        assert!(!events.focus_next(&mut empty, false));
+    }
+
+    #[test]
+    fn keys_go_to_the_focused_element_and_bubble() {
+        let (mut root, log) = tree();
+        let mut events = EventDispatcher::new();
+        assert_eq!(events.key(&mut root, KeyCode::Char('x')), EventResult::Ignored);
+        assert_eq!(take(&log), ["root x"]);
+
+        events.focus(&mut root, Some(vec![1]));
+        take(&log);
+        events.key(&mut root, KeyCode::Char('x'));
+        assert_eq!(take(&log), ["b x", "root x"]);
+
+        // Nobody handles Tab, so it moves focus.
+        assert_eq!(events.key(&mut root, KeyCode::Tab), EventResult::Handled);
+        assert_eq!(events.focused(), Some(&[0][..]));
+    }
+}
@@ -0,0 +1,123 @@
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    key::KeyCode,
+    types::{color::Color, rect::Rect, vec2::Vec2},
+    ui::{
+        UIElement,
+        style::border::{Border, TitleAlign},
+        text::{Text, style::{TextStyle, Wrap}},
+        theme,
+    },
+};
+
+/// A key and what it does, as listed by the `HelpOverlay`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyBinding {
+    pub key: KeyCode,
+    pub description: String,
+}
+
+impl KeyBinding {
+    pub fn new(key: KeyCode, description: impl Into<String>) -> Self {
+        Self { key, description: description.into() }
+    }
+}
+
+/// A panel listing key bindings, toggled with `key` (`?` by default) when
+/// nothing else handles it. It lists the bindings registered with `bind`
+/// followed by those the elements report through `UIElement::key_bindings`.
+#[derive(Debug, Clone)]
+pub struct HelpOverlay {
+    pub title: String,
+    pub key: KeyCode,
+    pub bindings: Vec<KeyBinding>,
+    pub visible: bool,
+}
+
+impl Default for HelpOverlay {
+    fn default() -> Self {
+        Self {
+            title: String::from("Help"),
+            key: KeyCode::Char('?'),
+            bindings: Vec::new(),
+            visible: false,
+        }
+    }
+}
+
+impl HelpOverlay {
+    pub fn bind(&mut self, key: KeyCode, description: impl Into<String>) {
+        self.bindings.push(KeyBinding::new(key, description));
+    }
+
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+    }
+
+    /// Everything to list: the registered bindings, then the ones reported by
+    /// `root` and its children in tree order.
+    pub fn all_bindings(&self, root: &dyn UIElement) -> Vec<KeyBinding> {
+        let mut bindings = self.bindings.clone();
+        bindings.push(KeyBinding::new(self.key, "Show or hide this help"));
+        collect_bindings(root, &mut bindings);
+        bindings
+    }
+
+    /// Draw the list centered inside `screen`, keys in the theme's `accent`
+    /// colour. Bindings that don't fit are left out.
+    pub fn draw(&self, root: &dyn UIElement, screen: Rect) {
+        if !self.visible {
+            return;
+        }
+        let theme = theme::current();
+        let bindings = self.all_bindings(root);
+        let keys: Vec<String> = bindings.iter().map(|binding| binding.key.to_string()).collect();
+        let key_width = keys.iter().map(|key| key.width()).max().unwrap_or(0) as f32;
+        let description_width = bindings.iter().map(|binding| binding.description.width()).max().unwrap_or(0) as f32;
+
+        // A border and a space on each side, and two spaces between the columns.
+        let width = (key_width + description_width + 6.0).max(self.title.width() as f32 + 6.0).min(screen.size.x);
+        let height = (bindings.len() as f32 + 2.0).min(screen.size.y);
+        let size = Vec2::new(width, height);
+        let centered = screen.pos + (screen.size - size) / 2.0;
+        let pos = Vec2::new(centered.x.floor(), centered.y.floor());
+
+        let mut fill = Text::new(pos.into(), size, String::new());
+        fill.text_style = Some(TextStyle::new(1, theme.text, theme.surface));
+        fill.draw();
+
+        let rows = (height - 2.0).max(0.0) as usize;
+        for (row, (key, binding)) in keys.into_iter().zip(&bindings).take(rows).enumerate() {
+            let y = pos.y + 1.0 + row as f32;
+            let key_pos = Vec2::new(pos.x + 2.0, y);
+            cell(key_pos, key_width, key, theme.accent, theme.surface);
+            let description_pos = Vec2::new(key_pos.x + key_width + 2.0, y);
+            let remaining = pos.x + width - 2.0 - description_pos.x;
+            cell(description_pos, remaining, binding.description.clone(), theme.text, theme.surface);
+        }
+
+        let mut border = Border::new(pos.into(), size);
+        border.title = Some(self.title.clone());
+        border.title_align = TitleAlign::Center;
+        border.draw();
+    }
+}
+
+fn cell(pos: Vec2<f32>, width: f32, content: String, fg: Color, bg: Color) {
+    let mut text = Text::new(pos.into(), Vec2::new(width.max(0.0), 1.0), content);
+    let mut style = TextStyle::new(1, fg, bg);
+    style.wrap = Wrap::None;
+    text.text_style = Some(style);
+    text.draw();
+}
+
+fn collect_bindings(element: &dyn UIElement, out: &mut Vec<KeyBinding>) {
+    if !element.is_enabled() {
+        return;
+    }
+    out.extend(element.key_bindings());
+    for child in element.children() {
+        collect_bindings(child.as_ref(), out);
+    }
+}
@@ -0,0 +1,160 @@
+use std::io::{Write, stdout};
+
+use crossterm::{
+    cursor::MoveTo,
+    queue,
+    style::{Attribute, Print, ResetColor, SetAttribute, SetBackgroundColor, SetForegroundColor},
+    terminal,
+};
+use ratatui::{
+    backend::IntoCrossterm,
+    buffer::{Buffer, Cell},
+    layout::Rect as TuiRect,
+    style::Modifier,
+    widgets::Widget,
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    clip,
+    shapes::pixel,
+    types::{color::{BlendMode, Color}, pos2::Pos2, vec2::Vec2},
+    ui::{UIElement, layout::LayoutItem},
+};
+
+/// Puts a ratatui widget (`Table`, `Chart`, `Paragraph`, `Sparkline`...) in
+/// rastor's UI. The widget is rendered into a ratatui `Buffer` covering this
+/// element, then the buffer's cells are printed where they belong.
+///
+/// `W` has to be renderable by reference, which all of ratatui's own widgets are.
+pub struct RatatuiWidget<W> {
+    pub pos: Pos2,
+    pub size: Vec2<f32>,
+    pub widget: W,
+    /// Leave cells the widget didn't draw anything on alone, so that whatever
+    /// is behind shows through.
+    pub transparent: bool,
+    pub layout_item: LayoutItem,
+}
+
+impl<W> RatatuiWidget<W>
+where
+    for<'a> &'a W: Widget,
+{
+    pub fn new(pos: Pos2, size: Vec2<f32>, widget: W) -> Self {
+        Self {
+            pos,
+            size,
+            widget,
+            transparent: false,
+            layout_item: LayoutItem::default(),
+        }
+    }
+
+    /// Where the widget ends up on the terminal, cut off at its edges.
+    pub fn area(&self, term_size: (u16, u16)) -> TuiRect {
+        let pos: Vec2<f32> = self.pos.into();
+        let (x, y) = (pos.x.round().max(0.0) as u16, pos.y.round().max(0.0) as u16);
+        let width = (self.size.x.round().max(0.0) as u16).min(term_size.0.saturating_sub(x));
+        let height = (self.size.y.round().max(0.0) as u16).min(term_size.1.saturating_sub(y));
+        TuiRect::new(x, y, width, height)
+    }
+
+    /// Render the widget into a fresh buffer. The buffer uses terminal
+    /// coordinates, so `buffer.area` is the same as `area`.
+    pub fn render(&self, term_size: (u16, u16)) -> Buffer {
+        let area = self.area(term_size);
+        let mut buffer = Buffer::empty(area);
+        (&self.widget).render(area, &mut buffer);
+        buffer
+    }
+}
+
+impl<W> UIElement for RatatuiWidget<W>
+where
+    for<'a> &'a W: Widget,
+{
+    fn draw(&self) {
+        let buffer = self.render(terminal::size().unwrap());
+        let mut stdout = stdout().lock();
+
+        let mut last_style = None;
+        // Cells hidden behind the previous wide symbol.
+        let mut covered = 0;
+        for (i, cell) in buffer.content().iter().enumerate() {
+            let (x, y) = buffer.pos_of(i);
+            if x == buffer.area.x {
+                covered = 0;
+            }
+            if covered > 0 {
+                covered -= 1;
+                continue;
+            }
+            covered = cell.symbol().width().saturating_sub(1);
+
+            if cell.skip || (self.transparent && *cell == Cell::EMPTY) || !clip::is_visible(x, y) {
+                continue;
+            }
+            // Cells without a background take the one of whatever is below.
+            let fg = Color::from(cell.fg.into_crossterm());
+            let bg = Color::from(cell.bg.into_crossterm());
+            let (fg, bg) = pixel::composite(x, y, cell.symbol() == "█", fg, bg, BlendMode::Normal);
+            let style = (fg, bg, cell.modifier);
+            if last_style != Some(style) {
+                queue!(
+                    stdout,
+                    SetAttribute(Attribute::Reset),
+                    SetForegroundColor(fg.into()),
+                    SetBackgroundColor(bg.into()),
+                ).unwrap();
+                queue_modifiers(&mut stdout, cell.modifier);
+                last_style = Some(style);
+            }
+            queue!(stdout, MoveTo(x, y), Print(cell.symbol())).unwrap();
+        }
+
+        queue!(stdout, SetAttribute(Attribute::Reset), ResetColor).unwrap();
+        stdout.flush().unwrap();
+    }
+
+    fn update(&mut self) {}
+
+    fn pos(&self) -> Pos2 {
+        self.pos
+    }
+
+    fn size(&self) -> Vec2<f32> {
+        self.size
+    }
+
+    fn set_pos(&mut self, pos: Pos2) {
+        self.pos = pos;
+    }
+
+    fn set_size(&mut self, size: Vec2<f32>) {
+        self.size = size;
+    }
+
+    fn layout_item(&self) -> LayoutItem {
+        self.layout_item
+    }
+}
+
+fn queue_modifiers(stdout: &mut impl Write, modifier: Modifier) {
+    const ATTRIBUTES: [(Modifier, Attribute); 9] = [
+        (Modifier::BOLD, Attribute::Bold),
+        (Modifier::DIM, Attribute::Dim),
+        (Modifier::ITALIC, Attribute::Italic),
+        (Modifier::UNDERLINED, Attribute::Underlined),
+        (Modifier::SLOW_BLINK, Attribute::SlowBlink),
+        (Modifier::RAPID_BLINK, Attribute::RapidBlink),
+        (Modifier::REVERSED, Attribute::Reverse),
+        (Modifier::HIDDEN, Attribute::Hidden),
+        (Modifier::CROSSED_OUT, Attribute::CrossedOut),
+    ];
+    for (flag, attribute) in ATTRIBUTES {
+        if modifier.contains(flag) {
+            queue!(stdout, SetAttribute(attribute)).unwrap();
+        }
+    }
+}
@@ -0,0 +1,455 @@
+use crate::types::{rect::Rect, vec2::Vec2};
+
+/// Main axis along which a container places its children.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Direction {
+    /// Children are placed left to right.
+    #[default]
+    Row,
+    /// Children are placed top to bottom.
+    Column,
+}
+
+/// Placement of a child on the cross axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Align {
+    #[default]
+    Start,
+    Center,
+    End,
+    /// Stretch children with `Sizing::Auto` on the cross axis to fill the container.
+    Stretch,
+}
+
+/// Distribution of the leftover space on the main axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Justify {
+    #[default]
+    Start,
+    Center,
+    End,
+    SpaceBetween,
+    SpaceAround,
+    SpaceEvenly,
+}
+
+/// How big a child wants to be along one axis.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Sizing {
+    /// Whatever `UIElement::intrinsic_size` reports. Containers with a layout
+    /// measure their children for it, but most elements report their current
+    /// size, so they keep the size they were created with or were last given,
+    /// e.g. by `Align::Stretch` on an earlier frame.
+    #[default]
+    Auto,
+    /// A fixed amount of cells.
+    Fixed(f32),
+    /// A percentage (0-100) of the container's inner size.
+    Percent(f32),
+    /// A share of the space left over on the main axis, weighted against the
+    /// other `Fill` children. On the cross axis it takes up the whole inner size.
+    Fill(f32),
+}
+
+/// Space around the four sides of a box, used for both padding and margin.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Spacing {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+impl Spacing {
+    pub fn new(top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        Self { top, right, bottom, left }
+    }
+
+    pub fn all(value: f32) -> Self {
+        Self::new(value, value, value, value)
+    }
+
+    pub fn symmetric(vertical: f32, horizontal: f32) -> Self {
+        Self::new(vertical, horizontal, vertical, horizontal)
+    }
+
+    pub fn horizontal(&self) -> f32 {
+        self.left + self.right
+    }
+
+    pub fn vertical(&self) -> f32 {
+        self.top + self.bottom
+    }
+
+    /// Shrink `rect` by this spacing on every side.
+    pub fn shrink(&self, rect: Rect) -> Rect {
+        Rect::new(
+            Vec2::new(rect.pos.x + self.left, rect.pos.y + self.top),
+            Vec2::new(
+                (rect.size.x - self.horizontal()).max(0.0),
+                (rect.size.y - self.vertical()).max(0.0),
+            ),
+        )
+    }
+}
+
+/// Layout settings of a container: how its children are arranged inside it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Layout {
+    pub direction: Direction,
+    /// Space between two neighbouring children on the main axis.
+    pub gap: f32,
+    pub padding: Spacing,
+    pub align: Align,
+    pub justify: Justify,
+}
+
+impl Layout {
+    pub fn new(direction: Direction) -> Self {
+        Self {
+            direction,
+            ..Default::default()
+        }
+    }
+
+    /// Compute the box of every child inside `area`, margins excluded. `items`
+    /// pairs each child's `LayoutItem` with its intrinsic size, and the
+    /// returned rects are in the same order.
+    pub fn compute(&self, area: Rect, items: &[(LayoutItem, Vec2<f32>)]) -> Vec<Rect> {
+        let inner = self.padding.shrink(area);
+        let dir = self.direction;
+        let inner_main = main(inner.size, dir);
+        let inner_cross = cross(inner.size, dir);
+
+        // Resolve everything that doesn't depend on the leftover space first.
+        let mut mains: Vec<f32> = Vec::with_capacity(items.len());
+        let mut used = self.gap * items.len().saturating_sub(1) as f32;
+        for (item, current) in items {
+            // `Fill` children are sized below, once the leftover space is known.
+            let size = match item.main_sizing(dir) {
+                Sizing::Fill(_) => 0.0,
+                sizing => item.clamp_main(resolve(sizing, main(*current, dir), inner_main), dir),
+            };
+            used += size + item.margin_main(dir);
+            mains.push(size);
+        }
+
+        // Hand out the leftover space to `Fill` children. Children that hit their
+        // min/max get frozen and the rest is shared again among the others.
+        let mut frozen: Vec<bool> = items
+            .iter()
+            .map(|(item, _)| !matches!(item.main_sizing(dir), Sizing::Fill(_)))
+            .collect();
+        loop {
+            let free = inner_main - used;
+            let total_weight: f32 = items
+                .iter()
+                .zip(&frozen)
+                .filter(|(_, frozen)| !**frozen)
+                .map(|((item, _), _)| fill_weight(item.main_sizing(dir)))
+                .sum();
+            if total_weight <= 0.0 {
+                for (i, (item, _)) in items.iter().enumerate() {
+                    if !frozen[i] {
+                        mains[i] = item.clamp_main(0.0, dir);
+                        used += mains[i];
+                    }
+                }
+                break;
+            }
+
+            let mut clamped = false;
+            for (i, (item, _)) in items.iter().enumerate() {
+                if frozen[i] {
+                    continue;
+                }
+                let share = free.max(0.0) * fill_weight(item.main_sizing(dir)) / total_weight;
+                let size = item.clamp_main(share, dir);
+                if size != share {
+                    mains[i] = size;
+                    frozen[i] = true;
+                    used += size;
+                    clamped = true;
+                }
+            }
+            if clamped {
+                continue;
+            }
+
+            for (i, (item, _)) in items.iter().enumerate() {
+                if !frozen[i] {
+                    mains[i] = free.max(0.0) * fill_weight(item.main_sizing(dir)) / total_weight;
+                    used += mains[i];
+                }
+            }
+            break;
+        }
+
+        let free = (inner_main - used).max(0.0);
+        let count = items.len() as f32;
+        let (mut cursor, extra_gap) = match self.justify {
+            Justify::Start => (0.0, 0.0),
+            Justify::Center => (free / 2.0, 0.0),
+            Justify::End => (free, 0.0),
+            Justify::SpaceBetween if items.len() > 1 => (0.0, free / (count - 1.0)),
+            Justify::SpaceBetween => (0.0, 0.0),
+            Justify::SpaceAround => (free / count / 2.0, free / count),
+            Justify::SpaceEvenly => (free / (count + 1.0), free / (count + 1.0)),
+        };
+
+        let mut rects = Vec::with_capacity(items.len());
+        for ((item, current), main_size) in items.iter().zip(mains) {
+            let (margin_start, margin_end) = item.margin_main_sides(dir);
+            let (margin_cross_start, margin_cross_end) = item.margin_cross_sides(dir);
+            let cross_space = (inner_cross - margin_cross_start - margin_cross_end).max(0.0);
+            let align = item.align_self.unwrap_or(self.align);
+
+            let cross_size = match item.cross_sizing(dir) {
+                Sizing::Auto if align == Align::Stretch => cross_space,
+                Sizing::Fill(_) => cross_space,
+                sizing => resolve(sizing, cross(*current, dir), inner_cross),
+            };
+            let cross_size = item.clamp_cross(cross_size, dir);
+            let cross_offset = match align {
+                Align::Start | Align::Stretch => 0.0,
+                Align::Center => (cross_space - cross_size) / 2.0,
+                Align::End => cross_space - cross_size,
+            };
+
+            cursor += margin_start;
+            let offset = from_axes(cursor, margin_cross_start + cross_offset, dir);
+            rects.push(Rect::new(
+                inner.pos + offset,
+                from_axes(main_size, cross_size, dir),
+            ));
+            cursor += main_size + margin_end + self.gap + extra_gap;
+        }
+        rects
+    }
+
+    /// The smallest size that fits every child at its `Fixed` or `Auto` size,
+    /// with margins, gaps and padding. `Percent` and `Fill` children depend on
+    /// the container's size, so they count for nothing beyond their minimum.
+    pub fn measure(&self, items: &[(LayoutItem, Vec2<f32>)]) -> Vec2<f32> {
+        let dir = self.direction;
+        let mut main_size = self.gap * items.len().saturating_sub(1) as f32;
+        let mut cross_size: f32 = 0.0;
+        for (item, intrinsic) in items {
+            let (margin_cross_start, margin_cross_end) = item.margin_cross_sides(dir);
+            let main_item = match item.main_sizing(dir) {
+                Sizing::Auto => main(*intrinsic, dir),
+                Sizing::Fixed(v) => v,
+                Sizing::Percent(_) | Sizing::Fill(_) => 0.0,
+            };
+            let cross_item = match item.cross_sizing(dir) {
+                Sizing::Auto => cross(*intrinsic, dir),
+                Sizing::Fixed(v) => v,
+                Sizing::Percent(_) | Sizing::Fill(_) => 0.0,
+            };
+            main_size += item.clamp_main(main_item, dir) + item.margin_main(dir);
+            cross_size = cross_size.max(item.clamp_cross(cross_item, dir) + margin_cross_start + margin_cross_end);
+        }
+        from_axes(main_size, cross_size, dir) + Vec2::new(self.padding.horizontal(), self.padding.vertical())
+    }
+}
+
+/// Per-child layout settings, read by the parent container's `Layout`.
+#[derive(Debug, Clone, Copy)]
+pub struct LayoutItem {
+    pub width: Sizing,
+    pub height: Sizing,
+    pub min_size: Vec2<f32>,
+    pub max_size: Vec2<f32>,
+    pub margin: Spacing,
+    /// Overrides the parent's `Layout::align` for this child only.
+    pub align_self: Option<Align>,
+}
+
+impl Default for LayoutItem {
+    fn default() -> Self {
+        Self {
+            width: Sizing::Auto,
+            height: Sizing::Auto,
+            min_size: Vec2::zero(),
+            max_size: Vec2::splat(f32::INFINITY),
+            margin: Spacing::default(),
+            align_self: None,
+        }
+    }
+}
+
+impl LayoutItem {
+    pub fn new(width: Sizing, height: Sizing) -> Self {
+        Self {
+            width,
+            height,
+            ..Default::default()
+        }
+    }
+
+    fn main_sizing(&self, dir: Direction) -> Sizing {
+        match dir {
+            Direction::Row => self.width,
+            Direction::Column => self.height,
+        }
+    }
+
+    fn cross_sizing(&self, dir: Direction) -> Sizing {
+        match dir {
+            Direction::Row => self.height,
+            Direction::Column => self.width,
+        }
+    }
+
+    fn clamp_main(&self, size: f32, dir: Direction) -> f32 {
+        size.min(main(self.max_size, dir)).max(main(self.min_size, dir))
+    }
+
+    fn clamp_cross(&self, size: f32, dir: Direction) -> f32 {
+        size.min(cross(self.max_size, dir)).max(cross(self.min_size, dir))
+    }
+
+    fn margin_main(&self, dir: Direction) -> f32 {
+        let (start, end) = self.margin_main_sides(dir);
+        start + end
+    }
+
+    fn margin_main_sides(&self, dir: Direction) -> (f32, f32) {
+        match dir {
+            Direction::Row => (self.margin.left, self.margin.right),
+            Direction::Column => (self.margin.top, self.margin.bottom),
+        }
+    }
+
+    fn margin_cross_sides(&self, dir: Direction) -> (f32, f32) {
+        match dir {
+            Direction::Row => (self.margin.top, self.margin.bottom),
+            Direction::Column => (self.margin.left, self.margin.right),
+        }
+    }
+}
+
+fn resolve(sizing: Sizing, current: f32, available: f32) -> f32 {
+    match sizing {
+        Sizing::Auto => current,
+        Sizing::Fixed(v) => v,
+        Sizing::Percent(p) => available * p / 100.0,
+        Sizing::Fill(_) => available,
+    }
+}
+
+fn fill_weight(sizing: Sizing) -> f32 {
+    match sizing {
+        Sizing::Fill(weight) => weight.max(0.0),
+        _ => 0.0,
+    }
+}
+
+fn main(v: Vec2<f32>, dir: Direction) -> f32 {
+    match dir {
+        Direction::Row => v.x,
+        Direction::Column => v.y,
+    }
+}
+
+fn cross(v: Vec2<f32>, dir: Direction) -> f32 {
+    match dir {
+        Direction::Row => v.y,
+        Direction::Column => v.x,
+    }
+}
+
+fn from_axes(main: f32, cross: f32, dir: Direction) -> Vec2<f32> {
+    match dir {
+        Direction::Row => Vec2::new(main, cross),
+        Direction::Column => Vec2::new(cross, main),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn area(width: f32, height: f32) -> Rect {
+        Rect::new(Vec2::zero(), Vec2::new(width, height))
+    }
+
+    fn item(width: Sizing) -> (LayoutItem, Vec2<f32>) {
+        (LayoutItem::new(width, Sizing::Auto), Vec2::new(3.0, 1.0))
+    }
+
+    fn widths(rects: &[Rect]) -> Vec<f32> {
+        rects.iter().map(|rect| rect.size.x).collect()
+    }
+
+    #[test]
+    fn fixed_auto_and_percent() {
+        let layout = Layout { gap: 1.0, ..Layout::new(Direction::Row) };
+        let items = [item(Sizing::Fixed(5.0)), item(Sizing::Auto), item(Sizing::Percent(50.0))];
+        let rects = layout.compute(area(20.0, 4.0), &items);
+        assert_eq!(widths(&rects), [5.0, 3.0, 10.0]);
+        assert_eq!(rects.iter().map(|rect| rect.pos.x).collect::<Vec<_>>(), [0.0, 6.0, 10.0]);
+    }
+
+    #[test]
+    fn fill_takes_exactly_the_leftover_space() {
+        let mut fill = item(Sizing::Fill(1.0));
+        fill.0.min_size = Vec2::new(4.0, 0.0);
+        let layout = Layout { gap: 1.0, justify: Justify::End, ..Layout::new(Direction::Row) };
+        let rects = layout.compute(area(20.0, 4.0), &[item(Sizing::Fixed(5.0)), fill]);
+        assert_eq!(widths(&rects), [5.0, 14.0]);
+        // Nothing is left over for `Justify::End` to shift the children by.
+        assert_eq!(rects[0].pos.x, 0.0);
+    }
+
+    #[test]
+    fn fill_shares_by_weight() {
+        let layout = Layout::new(Direction::Row);
+        let rects = layout.compute(area(30.0, 4.0), &[item(Sizing::Fill(1.0)), item(Sizing::Fill(2.0))]);
+        assert_eq!(widths(&rects), [10.0, 20.0]);
+    }
+
+    #[test]
+    fn clamped_fill_gives_the_rest_to_the_others() {
+        let mut small = item(Sizing::Fill(1.0));
+        small.0.max_size = Vec2::new(5.0, f32::INFINITY);
+        let mut big = item(Sizing::Fill(1.0));
+        big.0.min_size = Vec2::new(2.0, 0.0);
+        let layout = Layout { justify: Justify::Center, ..Layout::new(Direction::Row) };
+        let rects = layout.compute(area(30.0, 4.0), &[small, big]);
+        assert_eq!(widths(&rects), [5.0, 25.0]);
+        assert_eq!(rects[0].pos.x, 0.0);
+    }
+
+    #[test]
+    fn column_with_padding_and_stretch() {
+        let layout = Layout {
+            padding: Spacing::all(1.0),
+            align: Align::Stretch,
+            ..Layout::new(Direction::Column)
+        };
+        let items = [
+            (LayoutItem::new(Sizing::Auto, Sizing::Fixed(2.0)), Vec2::new(3.0, 1.0)),
+            (LayoutItem::new(Sizing::Auto, Sizing::Fill(1.0)), Vec2::new(3.0, 1.0)),
+        ];
+        let rects = layout.compute(area(10.0, 10.0), &items);
+        assert_eq!(rects[1].pos.y, 3.0);
+        assert_eq!(rects[1].size.y, 6.0);
+        assert_eq!(rects[1].size.x, 8.0);
+    }
+
+    #[test]
+    fn measure_fits_fixed_and_auto_children() {
+        let layout = Layout {
+            gap: 1.0,
+            padding: Spacing::symmetric(1.0, 2.0),
+            ..Layout::new(Direction::Row)
+        };
+        let mut margined = item(Sizing::Auto);
+        margined.0.margin = Spacing::new(1.0, 0.0, 1.0, 2.0);
+        let items = [item(Sizing::Fixed(5.0)), margined, item(Sizing::Fill(1.0))];
+        // 5 + (2 + 3) + 0, two gaps and the padding; 1 + 2 for the margined one.
+        let size = layout.measure(&items);
+        assert_eq!((size.x, size.y), (16.0, 5.0));
+    }
+}
@@ -0,0 +1,200 @@
+use std::io::stdout;
+
+use crossterm::terminal;
+
+use crate::{
+    clip,
+    key::{KeyCode, KeyInput, MouseButton},
+    shapes::pixel::{Pixel, flush_pixels},
+    types::{color::Color, pos2::Pos2, rect::Rect, vec2::Vec2},
+    ui::{
+        UIElement,
+        container::style::ContainerStyle,
+        layout::LayoutItem,
+        scroll::{inner_rect, scrollbar::Scrollbar, viewport_rect},
+        text::{Text, style::{TextStyle, Wrap}},
+        theme,
+    },
+};
+
+/// A scrolling list of single line items with a selection, for inventories,
+/// leaderboards, log consoles and the like. It scrolls the same way as a
+/// `ScrollView`, and only the visible rows are turned into `Text`s and drawn.
+pub struct ListView {
+    pub pos: Pos2,
+    pub size: Vec2<f32>,
+    pub items: Vec<String>,
+    pub selected: Option<usize>,
+    pub style: Option<ContainerStyle>,
+    /// Defaults to unwrapped text in the theme's `text` colour.
+    pub item_style: Option<TextStyle>,
+    /// Defaults to unwrapped text in the theme's `surface` colour on `primary`.
+    pub selected_style: Option<TextStyle>,
+    pub layout_item: LayoutItem,
+    pub scrollbar: Scrollbar,
+    /// Whether the arrow keys, page up/down and home/end move the selection.
+    pub focused: bool,
+    /// Keep the view scrolled to the bottom while it is already there, so that
+    /// newly pushed items show up (e.g. for log consoles).
+    pub follow_tail: bool,
+}
+
+impl ListView {
+    pub fn new(pos: Pos2, size: Vec2<f32>, items: Vec<String>) -> Self {
+        Self {
+            pos,
+            size,
+            items,
+            selected: None,
+            style: None,
+            item_style: None,
+            selected_style: None,
+            layout_item: LayoutItem::default(),
+            scrollbar: Scrollbar::default(),
+            focused: false,
+            follow_tail: false,
+        }
+    }
+
+    /// Append an item, scrolling along with it when `follow_tail` is set and
+    /// the view was at the bottom.
+    pub fn push(&mut self, item: String) {
+        let at_bottom = self.scrollbar.offset >= self.scrollbar.max_offset();
+        self.items.push(item);
+        if self.follow_tail && at_bottom {
+            self.scrollbar.set_heights(self.items.len() as f32, self.viewport().size.y);
+            self.scrollbar.scroll_to(f32::INFINITY);
+        }
+    }
+
+    pub fn selected_item(&self) -> Option<&String> {
+        self.selected.and_then(|i| self.items.get(i))
+    }
+
+    /// Select the item at `index` (clamped to the list) and scroll it into view.
+    pub fn select(&mut self, index: usize) {
+        if self.items.is_empty() {
+            self.selected = None;
+            return;
+        }
+        let index = index.min(self.items.len() - 1);
+        self.selected = Some(index);
+        self.scrollbar.set_heights(self.items.len() as f32, self.viewport().size.y);
+        self.scrollbar.scroll_into_view(index as f32, 1.0);
+    }
+
+    /// The area rows are drawn in: inside the border and left of the scrollbar.
+    pub fn viewport(&self) -> Rect {
+        viewport_rect(inner_rect(self.pos, self.size, &self.style), &self.scrollbar)
+    }
+
+    /// Index of the item drawn on the terminal row `y`, if any.
+    pub fn item_at(&self, y: f32) -> Option<usize> {
+        let viewport = self.viewport();
+        if y < viewport.top() || y >= viewport.bottom() {
+            return None;
+        }
+        let index = (y - viewport.top() + self.scrollbar.offset) as usize;
+        (index < self.items.len()).then_some(index)
+    }
+
+    /// Move the selection with the keyboard (when `focused`), select items by
+    /// clicking them, and scroll with the mouse wheel and the scrollbar.
+    pub fn handle_input(&mut self, input: &mut KeyInput) {
+        let area = Rect::new(self.pos.into(), self.size);
+        let inner = inner_rect(self.pos, self.size, &self.style);
+        self.scrollbar.handle_mouse(input, area, Scrollbar::track(inner));
+
+        if !self.scrollbar.is_dragging() && input.is_mouse_down(&MouseButton::Left) {
+            let mouse = input.mouse_pos().to_f32();
+            if self.viewport().contains(mouse) && let Some(index) = self.item_at(mouse.y) {
+                self.selected = Some(index);
+            }
+        }
+
+        if !self.focused || self.items.is_empty() {
+            return;
+        }
+        let page = self.viewport().size.y.max(1.0) as usize;
+        let current = self.selected.unwrap_or(0);
+        if input.is_pressed(&KeyCode::Up) { self.select(current.saturating_sub(1)) }
+        if input.is_pressed(&KeyCode::Down) { self.select(if self.selected.is_some() { current + 1 } else { 0 }) }
+        if input.is_pressed(&KeyCode::PageUp) { self.select(current.saturating_sub(page)) }
+        if input.is_pressed(&KeyCode::PageDown) { self.select(current + page) }
+        if input.is_pressed(&KeyCode::Home) { self.select(0) }
+        if input.is_pressed(&KeyCode::End) { self.select(self.items.len() - 1) }
+    }
+}
+
+impl UIElement for ListView {
+    fn draw(&self) {
+        let viewport = self.viewport();
+        let first = self.scrollbar.offset as usize;
+        let rows = viewport.size.y.max(0.0) as usize;
+
+        let theme = theme::current();
+        let item_style = self.item_style.clone().unwrap_or_else(|| {
+            let mut style = TextStyle::new(1, theme.text, Color::RESET);
+            style.wrap = Wrap::None;
+            style
+        });
+        let selected_style = self.selected_style.clone().unwrap_or_else(|| {
+            let mut style = TextStyle::new(1, theme.surface, theme.primary);
+            style.wrap = Wrap::None;
+            style
+        });
+
+        clip::push(viewport);
+        for (row, index) in (first..self.items.len()).take(rows).enumerate() {
+            let mut text = Text::new(
+                (viewport.pos + Vec2::new(0.0, row as f32)).into(),
+                Vec2::new(viewport.size.x, 1.0),
+                self.items[index].clone(),
+            );
+            let style = if self.selected == Some(index) { &selected_style } else { &item_style };
+            text.text_style = Some(style.clone());
+            text.draw();
+        }
+        clip::pop();
+
+        if let Some(ContainerStyle { border: Some(border), .. }) = &self.style {
+            border.draw();
+        }
+
+        let mut pixels: Vec<Pixel> = Vec::with_capacity(64);
+        let inner = inner_rect(self.pos, self.size, &self.style);
+        self.scrollbar.rasterize(Scrollbar::track(inner), &mut pixels, terminal::size().unwrap());
+        flush_pixels(&mut stdout().lock(), &mut pixels);
+    }
+
+    fn update(&mut self) {
+        if let Some(ContainerStyle { border: Some(border), .. }) = &mut self.style {
+            border.pos = self.pos;
+            border.size = self.size;
+        }
+        if self.selected.is_some_and(|i| i >= self.items.len()) {
+            self.selected = self.items.len().checked_sub(1);
+        }
+        self.scrollbar.set_heights(self.items.len() as f32, self.viewport().size.y);
+    }
+
+    fn pos(&self) -> Pos2 {
+        self.pos
+    }
+
+    fn size(&self) -> Vec2<f32> {
+        self.size
+    }
+
+    fn set_pos(&mut self, pos: Pos2) {
+        self.pos = pos;
+    }
+
+    fn set_size(&mut self, size: Vec2<f32>) {
+        self.size = size;
+    }
+
+    fn layout_item(&self) -> LayoutItem {
+        self.layout_item
+    }
+}
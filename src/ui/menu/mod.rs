@@ -0,0 +1,373 @@
+use std::fmt::Write;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    key::{KeyCode, MouseButton},
+    types::{pos2::Pos2, rect::Rect, vec2::Vec2},
+    ui::{
+        UIElement,
+        event::EventResult,
+        help::KeyBinding,
+        layout::{LayoutItem, Sizing},
+        overlay,
+        popup::{PopupItem, PopupList},
+        text::{Text, style::{TextStyle, Wrap}},
+        theme,
+    },
+    utils::get_terminal_size,
+};
+
+/// An entry in a `Menu`. It either runs `action` or opens `submenu`.
+pub struct MenuItem {
+    pub label: String,
+    /// Runs the item from anywhere, as long as the key isn't handled by the
+    /// focused element. Also shown next to the label.
+    pub accelerator: Option<KeyCode>,
+    pub enabled: bool,
+    pub action: Option<Box<dyn FnMut()>>,
+    pub submenu: Vec<MenuItem>,
+}
+
+impl MenuItem {
+    pub fn new(label: impl Into<String>, action: impl FnMut() + 'static) -> Self {
+        Self {
+            label: label.into(),
+            accelerator: None,
+            enabled: true,
+            action: Some(Box::new(action)),
+            submenu: Vec::new(),
+        }
+    }
+
+    pub fn submenu(label: impl Into<String>, items: Vec<MenuItem>) -> Self {
+        Self {
+            label: label.into(),
+            accelerator: None,
+            enabled: true,
+            action: None,
+            submenu: items,
+        }
+    }
+
+    fn popup_item(&self) -> PopupItem {
+        let mut item = PopupItem::new(self.label.clone());
+        item.enabled = self.enabled;
+        if !self.submenu.is_empty() {
+            item.hint.push('▸');
+        } else if let Some(key) = self.accelerator {
+            write!(item.hint, "{key}").unwrap();
+        }
+        item
+    }
+
+    /// Accelerators of this item and the items below it, described by the
+    /// labels leading to them.
+    fn bindings(&self, path: &str, out: &mut Vec<KeyBinding>) {
+        if !self.enabled {
+            return;
+        }
+        let path = format!("{path} › {}", self.label);
+        if let Some(key) = self.accelerator {
+            out.push(KeyBinding::new(key, path.clone()));
+        }
+        for item in &self.submenu {
+            item.bindings(&path, out);
+        }
+    }
+
+    /// Run the accelerator of this item or any item below it.
+    fn shortcut(&mut self, key: KeyCode) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        if self.accelerator == Some(key)
+            && let Some(action) = &mut self.action
+        {
+            action();
+            return true;
+        }
+        self.submenu.iter_mut().any(|item| item.shortcut(key))
+    }
+}
+
+/// A title in the `MenuBar` with the dropdown it opens.
+pub struct Menu {
+    pub title: String,
+    pub items: Vec<MenuItem>,
+}
+
+impl Menu {
+    pub fn new(title: impl Into<String>, items: Vec<MenuItem>) -> Self {
+        Self { title: title.into(), items }
+    }
+}
+
+/// A one row bar of menu titles, each opening a dropdown that can have nested
+/// submenus. Menus open by clicking their title or with `open_key`, and are
+/// then driven with the arrow keys, Enter and Escape. While a menu is open it
+/// captures all input, and its dropdowns are drawn on the `overlay`.
+pub struct MenuBar {
+    pub pos: Pos2,
+    pub size: Vec2<f32>,
+    pub menus: Vec<Menu>,
+    /// Opens the first menu. Defaults to F10.
+    pub open_key: KeyCode,
+    pub layout_item: LayoutItem,
+    /// Index of the open menu.
+    open: Option<usize>,
+    /// Highlighted item of each open dropdown, outermost first. A highlighted
+    /// item with a submenu opens the next dropdown.
+    selection: Vec<usize>,
+}
+
+impl MenuBar {
+    pub fn new(pos: Pos2, width: f32, menus: Vec<Menu>) -> Self {
+        Self {
+            pos,
+            size: Vec2::new(width, 1.0),
+            menus,
+            open_key: KeyCode::F(10),
+            layout_item: LayoutItem::new(Sizing::Auto, Sizing::Fixed(1.0)),
+            open: None,
+            selection: Vec::new(),
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open.is_some()
+    }
+
+    pub fn open(&mut self, menu: usize) {
+        if menu < self.menus.len() {
+            self.open = Some(menu);
+            self.selection = vec![0];
+        }
+    }
+
+    pub fn close(&mut self) {
+        self.open = None;
+        self.selection.clear();
+    }
+
+    pub fn title_rects(&self) -> Vec<Rect> {
+        let pos: Vec2<f32> = self.pos.into();
+        let mut x = pos.x + 1.0;
+        self.menus
+            .iter()
+            .map(|menu| {
+                let width = menu.title.width() as f32 + 2.0;
+                let rect = Rect::new(Vec2::new(x, pos.y), Vec2::new(width, 1.0));
+                x += width;
+                rect
+            })
+            .collect()
+    }
+
+    /// The items of the open dropdown at `level`.
+    fn items(&self, level: usize) -> Option<&[MenuItem]> {
+        let mut items = self.menus.get(self.open?)?.items.as_slice();
+        for &i in &self.selection[..level.min(self.selection.len())] {
+            items = items.get(i)?.submenu.as_slice();
+        }
+        Some(items)
+    }
+
+    fn items_mut(&mut self, level: usize) -> Option<&mut Vec<MenuItem>> {
+        let mut items = &mut self.menus.get_mut(self.open?)?.items;
+        for &i in &self.selection[..level.min(self.selection.len())] {
+            items = &mut items.get_mut(i)?.submenu;
+        }
+        Some(items)
+    }
+
+    /// The open dropdowns, outermost first, placed to stay on screen.
+    pub fn popups(&self) -> Vec<PopupList> {
+        let Some(open) = self.open else { return vec![] };
+        let screen = match get_terminal_size() {
+            Ok(size) => Rect::new(Vec2::zero(), size.to_f32()),
+            Err(_) => return vec![],
+        };
+
+        let title = self.title_rects()[open];
+        let mut pos = Vec2::new(title.left(), title.bottom());
+        let mut popups: Vec<PopupList> = Vec::new();
+        for level in 0..=self.selection.len() {
+            let Some(items) = self.items(level) else { break };
+            if items.is_empty() {
+                break;
+            }
+            let mut popup = PopupList::new(pos, items.iter().map(MenuItem::popup_item).collect());
+            popup.highlighted = self.selection.get(level).copied();
+            popup.fit(screen);
+
+            // The next dropdown opens next to the highlighted item.
+            if let Some(highlighted) = popup.highlighted {
+                let row = popup.item_rect(highlighted);
+                pos = Vec2::new(popup.rect().right(), row.top() - 1.0);
+            }
+            popups.push(popup);
+        }
+        popups
+    }
+
+    /// Highlight `index` in the innermost dropdown.
+    fn highlight(&mut self, index: usize) {
+        if let Some(last) = self.selection.last_mut() {
+            *last = index;
+        }
+    }
+
+    /// Run the highlighted item, or step into its submenu.
+    fn activate(&mut self) {
+        let level = self.selection.len() - 1;
+        let Some(&index) = self.selection.last() else { return };
+        let Some(item) = self.items_mut(level).and_then(|items| items.get_mut(index)) else { return };
+        if !item.enabled {
+            return;
+        }
+        if !item.submenu.is_empty() {
+            self.selection.push(0);
+            return;
+        }
+        if let Some(action) = &mut item.action {
+            action();
+        }
+        self.close();
+    }
+}
+
+impl UIElement for MenuBar {
+    fn draw(&self) {
+        let theme = theme::current();
+
+        let mut bar = Text::new(self.pos, Vec2::new(self.size.x, 1.0), String::new());
+        bar.text_style = Some(TextStyle::new(1, theme.text, theme.surface));
+        bar.draw();
+
+        for (i, (menu, rect)) in self.menus.iter().zip(self.title_rects()).enumerate() {
+            let (fg, bg) = if self.open == Some(i) {
+                (theme.surface, theme.primary)
+            } else {
+                (theme.text, theme.surface)
+            };
+            let mut style = TextStyle::new(1, fg, bg);
+            style.wrap = Wrap::None;
+            let mut title = Text::new(rect.pos.into(), rect.size, format!(" {} ", menu.title));
+            title.text_style = Some(style);
+            title.draw();
+        }
+
+        let popups = self.popups();
+        if !popups.is_empty() {
+            overlay::defer(move || popups.iter().for_each(PopupList::draw));
+        }
+    }
+
+    fn update(&mut self) {}
+
+    fn pos(&self) -> Pos2 {
+        self.pos
+    }
+
+    fn size(&self) -> Vec2<f32> {
+        self.size
+    }
+
+    fn set_pos(&mut self, pos: Pos2) {
+        self.pos = pos;
+    }
+
+    fn set_size(&mut self, size: Vec2<f32>) {
+        self.size = size;
+    }
+
+    fn layout_item(&self) -> LayoutItem {
+        self.layout_item
+    }
+
+    fn captures_input(&self) -> bool {
+        self.is_open()
+    }
+
+    fn on_click(&mut self, pos: Vec2<f32>, button: MouseButton) -> EventResult {
+        if button != MouseButton::Left {
+            return EventResult::Ignored;
+        }
+
+        if let Some(menu) = self.title_rects().iter().position(|rect| rect.contains(pos)) {
+            if self.open == Some(menu) { self.close() } else { self.open(menu) }
+            return EventResult::Handled;
+        }
+
+        // Innermost dropdowns are drawn on top, so they are checked first.
+        let popups = self.popups();
+        for (level, popup) in popups.iter().enumerate().rev() {
+            if let Some(index) = popup.item_at(pos) {
+                self.selection.truncate(level);
+                self.selection.push(index);
+                self.activate();
+                return EventResult::Handled;
+            }
+            if popup.rect().contains(pos) {
+                return EventResult::Handled;
+            }
+        }
+
+        // Clicking anywhere else closes the menu.
+        if self.is_open() {
+            self.close();
+            return EventResult::Handled;
+        }
+        EventResult::Ignored
+    }
+
+    fn on_key(&mut self, key: KeyCode) -> EventResult {
+        let Some(open) = self.open else { return EventResult::Ignored };
+        let level = self.selection.len() - 1;
+        let count = self.items(level).map_or(0, <[MenuItem]>::len);
+        let current = self.selection[level];
+
+        match key {
+            KeyCode::Esc if level > 0 => { self.selection.pop(); }
+            KeyCode::Esc => self.close(),
+            KeyCode::Up if count > 0 => self.highlight((current + count - 1) % count),
+            KeyCode::Down if count > 0 => self.highlight((current + 1) % count),
+            KeyCode::Left if level > 0 => { self.selection.pop(); }
+            KeyCode::Left => self.open((open + self.menus.len() - 1) % self.menus.len()),
+            KeyCode::Right => {
+                let has_submenu = self
+                    .items(level)
+                    .and_then(|items| items.get(current))
+                    .is_some_and(|item| !item.submenu.is_empty());
+                if has_submenu {
+                    self.activate();
+                } else {
+                    self.open((open + 1) % self.menus.len());
+                }
+            }
+            KeyCode::Enter | KeyCode::Char(' ') => self.activate(),
+            _ => return EventResult::Ignored,
+        }
+        EventResult::Handled
+    }
+
+    fn on_shortcut(&mut self, key: KeyCode) -> EventResult {
+        if key == self.open_key {
+            self.open(0);
+            return EventResult::Handled;
+        }
+        let ran = self.menus.iter_mut().any(|menu| menu.items.iter_mut().any(|item| item.shortcut(key)));
+        if ran { EventResult::Handled } else { EventResult::Ignored }
+    }
+
+    fn key_bindings(&self) -> Vec<KeyBinding> {
+        let mut bindings = vec![KeyBinding::new(self.open_key, "Open the menu")];
+        for menu in &self.menus {
+            for item in &menu.items {
+                item.bindings(&menu.title, &mut bindings);
+            }
+        }
+        bindings
+    }
+}
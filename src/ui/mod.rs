@@ -1,12 +1,41 @@
 pub mod text;
 pub mod container;
 pub mod style;
+pub mod layout;
+pub mod scroll;
+pub mod list;
+pub mod button;
+pub mod dialog;
+pub mod toast;
+pub mod root;
+pub mod progress;
+pub mod theme;
+pub mod interop;
+pub mod event;
+pub mod overlay;
+pub mod popup;
+pub mod tabs;
+pub mod menu;
+pub mod select;
+pub mod tooltip;
+pub mod help;
 
-use crate::{shapes::Shape, types::{pos2::Pos2, vec2::Vec2}};
+use crate::{
+    key::{KeyCode, MouseButton},
+    shapes::Shape,
+    types::{pos2::Pos2, vec2::Vec2},
+    ui::{event::EventResult, help::KeyBinding, layout::LayoutItem},
+};
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ElementState {
+    /// Focused, or otherwise highlighted.
     Active,
     Inactive,
+    /// The pointer is over the element.
+    Hovered,
+    /// A mouse button went down on the element and hasn't been released yet.
+    Pressed,
     Disabled,
 }
 
@@ -15,6 +44,96 @@ pub trait UIElement {
     fn size(&self) -> Vec2<f32>;
     fn draw(&self);
     fn update(&mut self);
+
+    /// Move the element. Called by a parent container's layout pass.
+    /// Default implementation is a no-op.
+    fn set_pos(&mut self, _pos: Pos2) {}
+
+    /// Resize the element. Called by a parent container's layout pass.
+    /// Default implementation is a no-op.
+    fn set_size(&mut self, _size: Vec2<f32>) {}
+
+    /// The size the element's content needs, which `Sizing::Auto` gives it.
+    /// Defaults to the current size.
+    fn intrinsic_size(&self) -> Vec2<f32> {
+        self.size()
+    }
+
+    /// How this element wants to be sized and placed by its parent container.
+    fn layout_item(&self) -> LayoutItem {
+        LayoutItem::default()
+    }
+
+    /// Elements inside this one, for hit-testing and event bubbling.
+    fn children(&self) -> &[Box<dyn UIElement>] {
+        &[]
+    }
+
+    fn children_mut(&mut self) -> &mut [Box<dyn UIElement>] {
+        &mut []
+    }
+
+    /// Whether the child at `index` is currently shown. Hidden children can't
+    /// be clicked or focused with Tab.
+    fn is_child_visible(&self, _index: usize) -> bool {
+        true
+    }
+
+    /// Whether the element can be focused by clicking it or with Tab.
+    fn focusable(&self) -> bool {
+        false
+    }
+
+    /// Disabled elements get no events at all, and neither do their children.
+    fn is_enabled(&self) -> bool {
+        true
+    }
+
+    /// Called by the event dispatcher when the element is hovered, pressed,
+    /// focused... Default implementation is a no-op.
+    fn set_state(&mut self, _state: ElementState) {}
+
+    /// A mouse button was pressed and released over the element. Events that
+    /// are `Ignored` bubble up to the parent.
+    fn on_click(&mut self, _pos: Vec2<f32>, _button: MouseButton) -> EventResult {
+        EventResult::Ignored
+    }
+
+    fn on_hover_enter(&mut self) {}
+
+    fn on_hover_leave(&mut self) {}
+
+    /// A key was pressed while this element, or one of its children, had focus.
+    /// Handled keys are consumed and won't show up in `KeyInput::is_pressed`.
+    fn on_key(&mut self, _key: KeyCode) -> EventResult {
+        EventResult::Ignored
+    }
+
+    /// The element gained (`true`) or lost (`false`) focus.
+    fn on_focus(&mut self, _focused: bool) {}
+
+    /// While this is `true` (e.g. while a dropdown is open), every click goes
+    /// to this element wherever it is, and keys go to it before the focused
+    /// element.
+    fn captures_input(&self) -> bool {
+        false
+    }
+
+    /// A key nobody handled. Offered to every element in turn, so it works
+    /// for shortcuts that don't depend on focus.
+    fn on_shortcut(&mut self, _key: KeyCode) -> EventResult {
+        EventResult::Ignored
+    }
+
+    /// Shown next to the element after it has been hovered for a while.
+    fn tooltip(&self) -> Option<&str> {
+        None
+    }
+
+    /// Keys the element responds to, listed by the `HelpOverlay`.
+    fn key_bindings(&self) -> Vec<KeyBinding> {
+        Vec::new()
+    }
 }
 
 pub trait InteractiveUIElement: UIElement {
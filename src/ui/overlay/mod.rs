@@ -0,0 +1,28 @@
+//! Drawing that has to happen on top of everything else, like dropdowns.
+//! Elements `defer` it while they are drawn, and whoever draws the whole UI
+//! (`UIRoot` does) calls `flush` once everything else is on screen.
+
+use std::cell::RefCell;
+
+thread_local! {
+    static DEFERRED: RefCell<Vec<Box<dyn FnOnce()>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Run `draw` at the next `flush` instead of right away.
+pub fn defer(draw: impl FnOnce() + 'static) {
+    DEFERRED.with_borrow_mut(|deferred| deferred.push(Box::new(draw)));
+}
+
+/// Run everything deferred so far, in the order it was deferred.
+pub fn flush() {
+    // Taken out first, so deferred drawing may defer more drawing.
+    loop {
+        let deferred = DEFERRED.with_borrow_mut(std::mem::take);
+        if deferred.is_empty() {
+            break;
+        }
+        for draw in deferred {
+            draw();
+        }
+    }
+}
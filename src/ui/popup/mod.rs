@@ -0,0 +1,126 @@
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    types::{rect::Rect, vec2::Vec2},
+    ui::{
+        UIElement,
+        style::border::Border,
+        text::{Text, style::{TextStyle, Wrap}},
+        theme,
+    },
+};
+
+/// One row of a `PopupList`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PopupItem {
+    pub label: String,
+    /// Drawn right aligned, e.g. a keyboard shortcut or `▸` for submenus.
+    pub hint: String,
+    pub enabled: bool,
+}
+
+impl PopupItem {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            hint: String::new(),
+            enabled: true,
+        }
+    }
+}
+
+/// A bordered list of items drawn over the rest of the UI, as used by the
+/// dropdowns of `MenuBar` and `Select`. It only draws and measures; the
+/// owner decides what the items do.
+#[derive(Debug, Clone)]
+pub struct PopupList {
+    /// Top left corner, border included.
+    pub pos: Vec2<f32>,
+    pub items: Vec<PopupItem>,
+    pub highlighted: Option<usize>,
+    /// Width to grow to even if the items are narrower, border included.
+    pub min_width: f32,
+}
+
+impl PopupList {
+    pub fn new(pos: Vec2<f32>, items: Vec<PopupItem>) -> Self {
+        Self {
+            pos,
+            items,
+            highlighted: None,
+            min_width: 0.0,
+        }
+    }
+
+    pub fn size(&self) -> Vec2<f32> {
+        let content = self
+            .items
+            .iter()
+            .map(|item| {
+                let gap = if item.hint.is_empty() { 0 } else { 2 };
+                item.label.width() + gap + item.hint.width()
+            })
+            .max()
+            .unwrap_or(0);
+        // A border and a space on each side.
+        let width = (content as f32 + 4.0).max(self.min_width);
+        Vec2::new(width, self.items.len() as f32 + 2.0)
+    }
+
+    pub fn rect(&self) -> Rect {
+        Rect::new(self.pos, self.size())
+    }
+
+    /// Row `index` inside the border.
+    pub fn item_rect(&self, index: usize) -> Rect {
+        let size = self.size();
+        Rect::new(self.pos + Vec2::new(1.0, 1.0 + index as f32), Vec2::new(size.x - 2.0, 1.0))
+    }
+
+    pub fn item_at(&self, point: Vec2<f32>) -> Option<usize> {
+        (0..self.items.len()).find(|&i| self.item_rect(i).contains(point))
+    }
+
+    /// Move the popup so it stays inside `screen`, as long as it fits at all.
+    pub fn fit(&mut self, screen: Rect) {
+        let size = self.size();
+        self.pos.x = self.pos.x.min(screen.right() - size.x).max(screen.left());
+        self.pos.y = self.pos.y.min(screen.bottom() - size.y).max(screen.top());
+    }
+
+    pub fn draw(&self) {
+        let theme = theme::current();
+        let size = self.size();
+
+        let mut fill = Text::new(self.pos.into(), size, String::new());
+        fill.text_style = Some(TextStyle::new(1, theme.text, theme.surface));
+        fill.draw();
+
+        for (i, item) in self.items.iter().enumerate() {
+            let rect = self.item_rect(i);
+            let (fg, bg) = if !item.enabled {
+                (theme.disabled, theme.surface)
+            } else if self.highlighted == Some(i) {
+                (theme.surface, theme.primary)
+            } else {
+                (theme.text, theme.surface)
+            };
+
+            let mut row = TextStyle::new(1, fg, bg);
+            row.wrap = Wrap::None;
+            let mut label = Text::new(rect.pos.into(), rect.size, format!(" {}", item.label));
+            label.text_style = Some(row.clone());
+            label.draw();
+
+            if !item.hint.is_empty() {
+                let hint_width = (item.hint.width() as f32 + 1.0).min(rect.size.x);
+                let pos = Vec2::new(rect.right() - hint_width, rect.top());
+                let mut hint = Text::new(pos.into(), Vec2::new(hint_width, 1.0), format!("{} ", item.hint));
+                hint.text_style = Some(row);
+                hint.draw();
+            }
+        }
+
+        Border::new(self.pos.into(), size).draw();
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{
+    types::{pos2::Pos2, vec2::Vec2},
+    ui::{
+        UIElement,
+        container::style::ContainerStyle,
+        layout::LayoutItem,
+        progress::ProgressBar,
+        scroll::inner_rect,
+    },
+};
+
+/// What a gauge writes on top of its bar.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum GaugeLabel {
+    None,
+    /// e.g. `75%`
+    #[default]
+    Percent,
+    /// e.g. `30/40`
+    Value,
+    Custom(String),
+}
+
+/// A `ProgressBar` driven by a value out of a maximum (hit points, mana,
+/// ammo...), with an optional border around it.
+pub struct Gauge {
+    pub pos: Pos2,
+    pub size: Vec2<f32>,
+    pub value: f32,
+    pub max: f32,
+    pub label: GaugeLabel,
+    /// Everything about the bar but its position, size, ratio and label, which
+    /// the gauge sets in `update`.
+    pub bar: ProgressBar,
+    pub style: Option<ContainerStyle>,
+    pub layout_item: LayoutItem,
+}
+
+impl Gauge {
+    pub fn new(pos: Pos2, size: Vec2<f32>, value: f32, max: f32) -> Self {
+        Self {
+            pos,
+            size,
+            value,
+            max,
+            label: GaugeLabel::default(),
+            bar: ProgressBar::new(pos, size, 0.0),
+            style: None,
+            layout_item: LayoutItem::default(),
+        }
+    }
+
+    /// `value / max`, clamped to 0..=1. An empty gauge (`max <= 0`) has a ratio of 0.
+    pub fn ratio(&self) -> f32 {
+        if self.max <= 0.0 {
+            return 0.0;
+        }
+        (self.value / self.max).clamp(0.0, 1.0)
+    }
+
+    fn label_text(&self) -> Option<String> {
+        match &self.label {
+            GaugeLabel::None => None,
+            GaugeLabel::Percent => Some(format!("{}%", (self.ratio() * 100.0).round())),
+            GaugeLabel::Value => Some(format!("{}/{}", self.value.round(), self.max.round())),
+            GaugeLabel::Custom(text) => Some(text.clone()),
+        }
+    }
+}
+
+impl UIElement for Gauge {
+    fn draw(&self) {
+        self.bar.draw();
+
+        if let Some(ContainerStyle { border: Some(border), .. }) = &self.style {
+            border.draw();
+        }
+    }
+
+    fn update(&mut self) {
+        if let Some(ContainerStyle { border: Some(border), .. }) = &mut self.style {
+            border.pos = self.pos;
+            border.size = self.size;
+        }
+
+        let inner = inner_rect(self.pos, self.size, &self.style);
+        self.bar.pos = inner.pos.into();
+        self.bar.size = inner.size;
+        self.bar.ratio = self.ratio();
+        self.bar.label = self.label_text();
+    }
+
+    fn pos(&self) -> Pos2 {
+        self.pos
+    }
+
+    fn size(&self) -> Vec2<f32> {
+        self.size
+    }
+
+    fn set_pos(&mut self, pos: Pos2) {
+        self.pos = pos;
+    }
+
+    fn set_size(&mut self, size: Vec2<f32>) {
+        self.size = size;
+    }
+
+    fn layout_item(&self) -> LayoutItem {
+        self.layout_item
+    }
+}
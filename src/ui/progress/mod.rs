@@ -0,0 +1,212 @@
+pub mod gauge;
+
+use std::io::stdout;
+
+use crossterm::terminal;
+
+use crate::{
+    shapes::pixel::{Pixel, flush_pixels},
+    types::{color::Color, pos2::Pos2, vec2::Vec2},
+    ui::{
+        UIElement,
+        layout::LayoutItem,
+        text::{Text, style::{TextAlign, TextStyle, VerticalAlign, Wrap}},
+        theme,
+    },
+};
+
+/// Partially filled cells, from 1/8 to 7/8, growing left to right.
+const HORIZONTAL_EIGHTHS: [char; 7] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+/// Partially filled cells, from 1/8 to 7/8, growing bottom to top.
+const VERTICAL_EIGHTHS: [char; 7] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇'];
+const FULL: char = '█';
+
+/// Which way a bar fills up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Orientation {
+    /// Left to right.
+    #[default]
+    Horizontal,
+    /// Bottom to top.
+    Vertical,
+}
+
+/// Colour of the filled part of a bar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BarFill {
+    Solid(Color),
+    /// Blends from `from` at the empty end of the bar to `to` at the full end,
+    /// so a draining bar shows less and less of `to`.
+    Gradient { from: Color, to: Color },
+}
+
+impl BarFill {
+    /// Colour at `t`, from 0 (empty end) to 1 (full end).
+    pub fn color_at(&self, t: f32) -> Color {
+        match *self {
+            BarFill::Solid(color) => color,
+            BarFill::Gradient { from, to } => from.lerp(to, t),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BarStyle {
+    /// One continuous bar, filled with 1/8 cell precision.
+    #[default]
+    Smooth,
+    /// Blocks of `length` cells separated by `gap` empty cells. Blocks are
+    /// either full or empty, and any value above zero fills at least one.
+    Segmented { length: u16, gap: u16 },
+}
+
+/// A bar showing how far along something is, e.g. loading, health or mana.
+pub struct ProgressBar {
+    pub pos: Pos2,
+    pub size: Vec2<f32>,
+    /// How full the bar is, from 0 to 1. Values outside of that are clamped.
+    pub ratio: f32,
+    pub orientation: Orientation,
+    /// Defaults to the theme's `primary` colour.
+    pub fill: Option<BarFill>,
+    pub style: BarStyle,
+    /// Drawn on the empty part of the bar. `' '` leaves it blank.
+    pub empty_char: char,
+    /// Defaults to the theme's `muted` colour.
+    pub empty_color: Option<Color>,
+    /// Text centered on top of the bar.
+    pub label: Option<String>,
+    /// Defaults to the theme's `text` colour.
+    pub label_color: Option<Color>,
+    pub layout_item: LayoutItem,
+}
+
+impl ProgressBar {
+    pub fn new(pos: Pos2, size: Vec2<f32>, ratio: f32) -> Self {
+        Self {
+            pos,
+            size,
+            ratio,
+            orientation: Orientation::default(),
+            fill: None,
+            style: BarStyle::default(),
+            empty_char: '░',
+            empty_color: None,
+            label: None,
+            label_color: None,
+            layout_item: LayoutItem::default(),
+        }
+    }
+
+    /// Rasterize the bar, without its label, into the output pixel buffer.
+    /// `term_size` is (width, height) in terminal cells.
+    pub fn rasterize(&self, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
+        let pos: Vec2<f32> = self.pos.into();
+        let (x0, y0) = (pos.x.round() as i32, pos.y.round() as i32);
+        let (width, height) = (self.size.x.round() as i32, self.size.y.round() as i32);
+        let (length, thickness) = match self.orientation {
+            Orientation::Horizontal => (width, height),
+            Orientation::Vertical => (height, width),
+        };
+        if length <= 0 || thickness <= 0 {
+            return;
+        }
+
+        let theme = theme::current();
+        let fill = self.fill.unwrap_or(BarFill::Solid(theme.primary));
+        let empty_color = self.empty_color.unwrap_or(theme.muted);
+        let ratio = self.ratio.clamp(0.0, 1.0);
+        for i in 0..length {
+            let t = if length > 1 { i as f32 / (length - 1) as f32 } else { 1.0 };
+            let cell = match self.cell(i, length, ratio) {
+                Some(0) => Some((self.empty_char, empty_color)),
+                Some(8) => Some((FULL, fill.color_at(t))),
+                Some(eighths) => {
+                    let partials = match self.orientation {
+                        Orientation::Horizontal => &HORIZONTAL_EIGHTHS,
+                        Orientation::Vertical => &VERTICAL_EIGHTHS,
+                    };
+                    Some((partials[eighths as usize - 1], fill.color_at(t)))
+                }
+                None => None,
+            };
+            let Some((ch, color)) = cell else { continue };
+            if ch == ' ' {
+                continue;
+            }
+
+            for j in 0..thickness {
+                let (x, y) = match self.orientation {
+                    Orientation::Horizontal => (x0 + i, y0 + j),
+                    Orientation::Vertical => (x0 + j, y0 + height - 1 - i),
+                };
+                if x >= 0 && y >= 0 && x < term_size.0 as i32 && y < term_size.1 as i32 {
+                    out.push(Pixel::new(x as u16, y as u16, ch, color, 0));
+                }
+            }
+        }
+    }
+
+    /// How many eighths of the `i`th cell along the bar are filled, or `None`
+    /// if the cell is a gap between segments.
+    fn cell(&self, i: i32, length: i32, ratio: f32) -> Option<u8> {
+        match self.style {
+            BarStyle::Smooth => {
+                let filled = ratio * length as f32;
+                Some(((filled - i as f32).clamp(0.0, 1.0) * 8.0).round() as u8)
+            }
+            BarStyle::Segmented { length: segment, gap } => {
+                let (segment, gap) = (segment.max(1) as i32, gap as i32);
+                let period = segment + gap;
+                if i % period >= segment {
+                    return None;
+                }
+                // Count the last segment even if it gets cut off by the end of the bar.
+                let segments = (length + period - 1) / period;
+                let filled = (ratio * segments as f32).ceil() as i32;
+                Some(if i / period < filled { 8 } else { 0 })
+            }
+        }
+    }
+}
+
+impl UIElement for ProgressBar {
+    fn draw(&self) {
+        let mut pixels: Vec<Pixel> = Vec::with_capacity(64);
+        self.rasterize(&mut pixels, terminal::size().unwrap());
+        flush_pixels(&mut stdout().lock(), &mut pixels);
+
+        if let Some(label) = &self.label {
+            let mut text = Text::new(self.pos, self.size, label.clone());
+            let label_color = self.label_color.unwrap_or(theme::current().text);
+            let mut style = TextStyle::new(1, label_color, Color::RESET);
+            style.align = TextAlign::Center;
+            style.vertical_align = VerticalAlign::Middle;
+            style.wrap = Wrap::None;
+            text.text_style = Some(style);
+            text.draw();
+        }
+    }
+
+    fn update(&mut self) {}
+
+    fn pos(&self) -> Pos2 {
+        self.pos
+    }
+
+    fn size(&self) -> Vec2<f32> {
+        self.size
+    }
+
+    fn set_pos(&mut self, pos: Pos2) {
+        self.pos = pos;
+    }
+
+    fn set_size(&mut self, size: Vec2<f32>) {
+        self.size = size;
+    }
+
+    fn layout_item(&self) -> LayoutItem {
+        self.layout_item
+    }
+}
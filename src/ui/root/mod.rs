@@ -0,0 +1,171 @@
+use std::time::Duration;
+
+use color_eyre::Result;
+
+use crate::{
+    key::{KeyCode, KeyInput},
+    types::{color::Color, pos2::Pos2, rect::Rect, vec2::Vec2},
+    ui::{
+        UIElement,
+        container::UIContainer,
+        dialog::{Dialog, DialogResult},
+        event::{EventDispatcher, find_capturing},
+        help::HelpOverlay,
+        overlay,
+        layout::{LayoutItem, Sizing},
+        text::{Text, style::TextStyle},
+        theme,
+        toast::{Toast, Toasts},
+        tooltip::Tooltips,
+    },
+    utils::get_terminal_size,
+};
+
+/// Sent back by `UIRoot::handle_input` when a dialog closes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DialogResponse {
+    pub id: String,
+    pub result: DialogResult,
+}
+
+/// The top of the UI tree. It draws the theme's background, then these
+/// layers: the regular UI in `container` (along with the `overlay` drawing its
+/// elements deferred), the tooltip, any open dialogs (the last one pushed on
+/// top), the help overlay and finally the toasts. While a dialog or the help
+/// is open, only that gets input, otherwise input goes to `container` through
+/// `events`.
+pub struct UIRoot {
+    /// Fills the whole terminal unless its `layout_item` says otherwise.
+    pub container: UIContainer,
+    pub toasts: Toasts,
+    pub events: EventDispatcher,
+    pub tooltips: Tooltips,
+    /// Toggled with its key when nothing in `container` handles that key.
+    /// Off (`None`) by default.
+    pub help: Option<HelpOverlay>,
+    dialogs: Vec<Dialog>,
+    screen: Rect,
+}
+
+impl Default for UIRoot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UIRoot {
+    pub fn new() -> Self {
+        let mut container = UIContainer::new(Vec2::zero().into(), Vec2::zero());
+        container.layout_item = LayoutItem::new(Sizing::Fill(1.0), Sizing::Fill(1.0));
+        Self {
+            container,
+            toasts: Toasts::default(),
+            events: EventDispatcher::new(),
+            tooltips: Tooltips::default(),
+            help: None,
+            dialogs: Vec::new(),
+            screen: Rect::new(Vec2::zero(), Vec2::zero()),
+        }
+    }
+
+    /// Open `dialog` on top of the others. Events are taken by every
+    /// `handle_input`, so the dialog only sees what happens after it opened.
+    pub fn push_dialog(&mut self, mut dialog: Dialog) {
+        dialog.layout(self.screen);
+        self.dialogs.push(dialog);
+    }
+
+    /// Whether a dialog is open, i.e. whether the scene below should ignore input.
+    pub fn has_dialog(&self) -> bool {
+        !self.dialogs.is_empty()
+    }
+
+    pub fn toast(&mut self, message: impl Into<String>, duration: Duration) {
+        self.toasts.push(Toast::new(message.into(), duration));
+    }
+
+    /// Pass input to the topmost dialog, closing it once it has a result. With
+    /// no dialog open, dispatch events to the elements in `container` instead.
+    pub fn handle_input(&mut self, input: &mut KeyInput) -> Option<DialogResponse> {
+        if let Some(help) = &mut self.help
+            && help.visible
+        {
+            input.take_events();
+            if input.is_pressed(&help.key) || input.is_pressed(&KeyCode::Esc) {
+                help.visible = false;
+            }
+            return None;
+        }
+
+        let Some(dialog) = self.dialogs.last_mut() else {
+            self.events.dispatch(&mut self.container, input);
+            if let Some(help) = &mut self.help
+                && input.is_pressed(&help.key)
+            {
+                help.visible = true;
+            }
+            return None;
+        };
+        // Whatever happens while the dialog is open isn't meant for the scene,
+        // which is why the dialog takes every event.
+        let result = dialog.handle_input(input)?;
+        let dialog = self.dialogs.pop()?;
+        Some(DialogResponse { id: dialog.id, result })
+    }
+
+    /// Follow the terminal size. Called by `update`.
+    pub fn fit_to_terminal(&mut self) -> Result<()> {
+        self.screen = Rect::new(Vec2::zero(), get_terminal_size()?.to_f32());
+        self.container.fit_to_terminal()
+    }
+}
+
+impl UIElement for UIRoot {
+    fn draw(&self) {
+        let background = theme::current().background;
+        if background != Color::RESET {
+            let mut fill = Text::new(self.screen.pos.into(), self.screen.size, String::new());
+            fill.text_style = Some(TextStyle::new(1, background, background));
+            fill.draw();
+        }
+
+        self.container.draw();
+        overlay::flush();
+        self.tooltips.draw(&self.container, self.screen);
+        for dialog in &self.dialogs {
+            dialog.draw(self.screen);
+        }
+        if let Some(help) = &self.help {
+            help.draw(&self.container, self.screen);
+        }
+        self.toasts.draw(self.screen);
+    }
+
+    fn update(&mut self) {
+        self.fit_to_terminal().unwrap();
+        self.container.update();
+        for dialog in &mut self.dialogs {
+            dialog.layout(self.screen);
+        }
+        self.toasts.update();
+
+        // No tooltips while something else is going on.
+        let busy = self.has_dialog()
+            || self.help.as_ref().is_some_and(|help| help.visible)
+            || self.events.pressed().is_some()
+            || find_capturing(&self.container).is_some();
+        self.tooltips.update(if busy { None } else { self.events.hovered() });
+    }
+
+    fn pos(&self) -> Pos2 {
+        self.screen.pos.into()
+    }
+
+    fn size(&self) -> Vec2<f32> {
+        self.screen.size
+    }
+
+    fn layout_item(&self) -> LayoutItem {
+        self.container.layout_item
+    }
+}
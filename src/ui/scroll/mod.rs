@@ -0,0 +1,188 @@
+pub mod scrollbar;
+
+use std::{io::stdout, ops::Range};
+
+use crossterm::terminal;
+
+use crate::{
+    clip,
+    key::{KeyCode, KeyInput},
+    shapes::pixel::{Pixel, flush_pixels},
+    types::{pos2::Pos2, rect::Rect, vec2::Vec2},
+    ui::{
+        UIElement,
+        container::style::ContainerStyle,
+        layout::{LayoutItem, Sizing},
+        scroll::scrollbar::Scrollbar,
+    },
+};
+
+/// A container that stacks its children vertically, clips them to its `size`
+/// and scrolls through them. Only the children that are (partly) visible get
+/// laid out and drawn.
+pub struct ScrollView {
+    pub pos: Pos2,
+    pub size: Vec2<f32>,
+    pub children: Vec<Box<dyn UIElement>>,
+    pub style: Option<ContainerStyle>,
+    pub layout_item: LayoutItem,
+    pub scrollbar: Scrollbar,
+    /// Whether arrow keys, page up/down and home/end scroll this view.
+    pub focused: bool,
+    visible: Range<usize>,
+}
+
+impl ScrollView {
+    pub fn new(pos: Pos2, size: Vec2<f32>) -> Self {
+        Self {
+            pos,
+            size,
+            children: Vec::new(),
+            style: None,
+            layout_item: LayoutItem::default(),
+            scrollbar: Scrollbar::default(),
+            focused: false,
+            visible: 0..0,
+        }
+    }
+
+    pub fn add_child(&mut self, child: Box<dyn UIElement>) {
+        self.children.push(child);
+    }
+
+    /// The area inside the border, if any.
+    pub fn inner(&self) -> Rect {
+        inner_rect(self.pos, self.size, &self.style)
+    }
+
+    /// The area children are drawn in: the inner area minus the scrollbar.
+    pub fn viewport(&self) -> Rect {
+        viewport_rect(self.inner(), &self.scrollbar)
+    }
+
+    /// Scroll with the keyboard (when `focused`), the mouse wheel (when hovered)
+    /// and by dragging the scrollbar. Mouse input needs mouse capture enabled.
+    pub fn handle_input(&mut self, input: &mut KeyInput) {
+        let area = Rect::new(self.pos.into(), self.size);
+        self.scrollbar.handle_mouse(input, area, Scrollbar::track(self.inner()));
+
+        if !self.focused {
+            return;
+        }
+        let page = self.viewport().size.y;
+        let step = self.scrollbar.step;
+        if input.is_pressed(&KeyCode::Up) { self.scrollbar.scroll_by(-step) }
+        if input.is_pressed(&KeyCode::Down) { self.scrollbar.scroll_by(step) }
+        if input.is_pressed(&KeyCode::PageUp) { self.scrollbar.scroll_by(-page) }
+        if input.is_pressed(&KeyCode::PageDown) { self.scrollbar.scroll_by(page) }
+        if input.is_pressed(&KeyCode::Home) { self.scrollbar.scroll_to(0.0) }
+        if input.is_pressed(&KeyCode::End) { self.scrollbar.scroll_to(f32::INFINITY) }
+    }
+}
+
+impl UIElement for ScrollView {
+    fn draw(&self) {
+        clip::push(self.viewport());
+        for child in &self.children[self.visible.clone()] {
+            child.draw();
+        }
+        clip::pop();
+
+        if let Some(ContainerStyle { border: Some(border), .. }) = &self.style {
+            border.draw();
+        }
+
+        let mut pixels: Vec<Pixel> = Vec::with_capacity(64);
+        self.scrollbar.rasterize(Scrollbar::track(self.inner()), &mut pixels, terminal::size().unwrap());
+        flush_pixels(&mut stdout().lock(), &mut pixels);
+    }
+
+    fn update(&mut self) {
+        if let Some(ContainerStyle { border: Some(border), .. }) = &mut self.style {
+            border.pos = self.pos;
+            border.size = self.size;
+        }
+
+        let viewport = self.viewport();
+        let heights: Vec<f32> = self.children.iter().map(|child| row_height(child.as_ref())).collect();
+        self.scrollbar.set_heights(heights.iter().sum(), viewport.size.y);
+
+        // Children are stacked, so the visible ones are a contiguous range.
+        let mut top = -self.scrollbar.offset;
+        let mut visible = self.children.len()..self.children.len();
+        for (i, (child, height)) in self.children.iter_mut().zip(heights).enumerate() {
+            if top + height > 0.0 && top < viewport.size.y {
+                visible.start = visible.start.min(i);
+                visible.end = i + 1;
+                child.set_pos((viewport.pos + Vec2::new(0.0, top)).into());
+                child.set_size(Vec2::new(viewport.size.x, height));
+            }
+            top += height;
+        }
+        self.visible = visible;
+
+        for child in &mut self.children {
+            child.update();
+        }
+    }
+
+    fn pos(&self) -> Pos2 {
+        self.pos
+    }
+
+    fn size(&self) -> Vec2<f32> {
+        self.size
+    }
+
+    fn set_pos(&mut self, pos: Pos2) {
+        self.pos = pos;
+    }
+
+    fn set_size(&mut self, size: Vec2<f32>) {
+        self.size = size;
+    }
+
+    fn layout_item(&self) -> LayoutItem {
+        self.layout_item
+    }
+
+    fn children(&self) -> &[Box<dyn UIElement>] {
+        &self.children
+    }
+
+    fn children_mut(&mut self) -> &mut [Box<dyn UIElement>] {
+        &mut self.children
+    }
+
+    /// Children scrolled out of view keep their last position, so they are
+    /// left out of hit testing and focus instead.
+    fn is_child_visible(&self, index: usize) -> bool {
+        self.visible.contains(&index)
+    }
+}
+
+/// Height of a child inside a scroll view: its fixed layout height, or else
+/// whatever height it currently has.
+fn row_height(child: &dyn UIElement) -> f32 {
+    match child.layout_item().height {
+        Sizing::Fixed(height) => height,
+        _ => child.size().y,
+    }
+    .max(0.0)
+}
+
+pub(crate) fn inner_rect(pos: Pos2, size: Vec2<f32>, style: &Option<ContainerStyle>) -> Rect {
+    let rect = Rect::new(pos.into(), size);
+    match style {
+        Some(style) => style.padding().shrink(rect),
+        None => rect,
+    }
+}
+
+pub(crate) fn viewport_rect(inner: Rect, scrollbar: &Scrollbar) -> Rect {
+    if scrollbar.visible {
+        Rect::new(inner.pos, Vec2::new((inner.size.x - 1.0).max(0.0), inner.size.y))
+    } else {
+        inner
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{
+    key::{KeyInput, MouseButton},
+    shapes::pixel::Pixel,
+    types::{color::Color, rect::Rect, vec2::Vec2},
+    ui::theme,
+};
+
+/// Scroll position of some content taller than its viewport, along with the
+/// vertical bar used to show and drag it.
+#[derive(Debug, Clone)]
+pub struct Scrollbar {
+    /// How many rows of content are scrolled past the top of the viewport.
+    pub offset: f32,
+    /// Rows moved per wheel notch or arrow key.
+    pub step: f32,
+    pub visible: bool,
+    pub track_char: char,
+    pub thumb_char: char,
+    /// Defaults to the theme's `muted` colour.
+    pub track_color: Option<Color>,
+    /// Defaults to the theme's `primary` colour.
+    pub thumb_color: Option<Color>,
+    content_height: f32,
+    viewport_height: f32,
+    /// Where the pointer grabbed the thumb, relative to the thumb's top, while dragging.
+    drag_anchor: Option<f32>,
+}
+
+impl Default for Scrollbar {
+    fn default() -> Self {
+        Self {
+            offset: 0.0,
+            step: 1.0,
+            visible: true,
+            track_char: '│',
+            thumb_char: '█',
+            track_color: None,
+            thumb_color: None,
+            content_height: 0.0,
+            viewport_height: 0.0,
+            drag_anchor: None,
+        }
+    }
+}
+
+impl Scrollbar {
+    pub fn content_height(&self) -> f32 {
+        self.content_height
+    }
+
+    pub fn viewport_height(&self) -> f32 {
+        self.viewport_height
+    }
+
+    /// Update the heights the offset is clamped against.
+    pub fn set_heights(&mut self, content_height: f32, viewport_height: f32) {
+        self.content_height = content_height.max(0.0);
+        self.viewport_height = viewport_height.max(0.0);
+        self.scroll_to(self.offset);
+    }
+
+    pub fn max_offset(&self) -> f32 {
+        (self.content_height - self.viewport_height).max(0.0)
+    }
+
+    pub fn scroll_to(&mut self, offset: f32) {
+        self.offset = offset.clamp(0.0, self.max_offset());
+    }
+
+    pub fn scroll_by(&mut self, rows: f32) {
+        self.scroll_to(self.offset + rows);
+    }
+
+    /// Scroll as little as possible so that the rows `top..top + height` are visible.
+    pub fn scroll_into_view(&mut self, top: f32, height: f32) {
+        if top < self.offset {
+            self.scroll_to(top);
+        } else if top + height > self.offset + self.viewport_height {
+            self.scroll_to(top + height - self.viewport_height);
+        }
+    }
+
+    pub fn is_dragging(&self) -> bool {
+        self.drag_anchor.is_some()
+    }
+
+    /// The thumb's top row and height inside a track of `track_height` rows.
+    fn thumb(&self, track_height: f32) -> (f32, f32) {
+        if self.content_height <= self.viewport_height || self.content_height <= 0.0 {
+            return (0.0, track_height);
+        }
+        let height = (track_height * self.viewport_height / self.content_height).max(1.0).round();
+        let top = ((track_height - height) * self.offset / self.max_offset()).round();
+        (top, height)
+    }
+
+    /// Scroll with the mouse wheel while hovering `area`, and drag the thumb
+    /// around inside `track`. Clicking the track away from the thumb jumps there.
+    pub fn handle_mouse(&mut self, input: &mut KeyInput, area: Rect, track: Rect) {
+        let mouse = input.mouse_pos().to_f32();
+        if area.contains(mouse) {
+            let scroll = input.take_scroll();
+            if scroll != 0 {
+                self.scroll_by(scroll as f32 * self.step);
+            }
+        }
+
+        if !self.visible || !input.is_mouse_down(&MouseButton::Left) {
+            self.drag_anchor = None;
+            return;
+        }
+
+        let (thumb_top, thumb_height) = self.thumb(track.size.y);
+        let anchor = match self.drag_anchor {
+            Some(anchor) => anchor,
+            None if track.contains(mouse) => {
+                let grabbed = mouse.y - track.top() - thumb_top;
+                // Grab the thumb by its middle when clicking outside of it.
+                let anchor = if (0.0..thumb_height).contains(&grabbed) { grabbed } else { thumb_height / 2.0 };
+                self.drag_anchor = Some(anchor);
+                anchor
+            }
+            None => return,
+        };
+
+        let free = track.size.y - thumb_height;
+        if free > 0.0 {
+            let top = (mouse.y - track.top() - anchor).clamp(0.0, free);
+            self.scroll_to(top / free * self.max_offset());
+        }
+    }
+
+    /// Rasterize the bar into `track`, which should be one column wide.
+    /// Nothing is drawn when everything fits in the viewport.
+    pub fn rasterize(&self, track: Rect, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
+        if !self.visible || self.max_offset() <= 0.0 {
+            return;
+        }
+
+        let theme = theme::current();
+        let (thumb_top, thumb_height) = self.thumb(track.size.y);
+        let x = track.left() as i32;
+        for row in 0..track.size.y as i32 {
+            let y = track.top() as i32 + row;
+            if x < 0 || y < 0 || x >= term_size.0 as i32 || y >= term_size.1 as i32 {
+                continue;
+            }
+            let on_thumb = (row as f32) >= thumb_top && (row as f32) < thumb_top + thumb_height;
+            let (ch, color) = if on_thumb {
+                (self.thumb_char, self.thumb_color.unwrap_or(theme.primary))
+            } else {
+                (self.track_char, self.track_color.unwrap_or(theme.muted))
+            };
+            out.push(Pixel::new(x as u16, y as u16, ch, color, 0));
+        }
+    }
+
+    /// The one column wide track along the right edge of `area`.
+    pub fn track(area: Rect) -> Rect {
+        Rect::new(
+            Vec2::new(area.right() - 1.0, area.top()),
+            Vec2::new(1.0_f32.min(area.size.x), area.size.y),
+        )
+    }
+}
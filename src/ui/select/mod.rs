@@ -0,0 +1,289 @@
+use crate::{
+    key::{KeyCode, MouseButton},
+    types::{color::Color, pos2::Pos2, rect::Rect, vec2::Vec2},
+    ui::{
+        ElementState, UIElement,
+        event::EventResult,
+        layout::{LayoutItem, Sizing, Spacing},
+        overlay,
+        popup::{PopupItem, PopupList},
+        style::border::Border,
+        text::{Text, style::{TextStyle, Wrap}},
+        theme::{self, Theme},
+    },
+    utils::get_terminal_size,
+};
+
+/// A bordered box showing the chosen option, which opens a popup list of all
+/// the options when clicked or activated with Enter, Space or Down. While the
+/// list is open it captures all input; Up and Down move through it, Enter
+/// chooses and Escape or clicking elsewhere closes it.
+pub struct Select {
+    pub pos: Pos2,
+    pub size: Vec2<f32>,
+    pub options: Vec<String>,
+    pub selected: Option<usize>,
+    /// Shown while nothing is selected.
+    pub placeholder: String,
+    pub state: ElementState,
+    /// Most options shown at once; the list scrolls to keep the highlighted
+    /// one visible.
+    pub max_rows: usize,
+    pub layout_item: LayoutItem,
+    /// Called with the index of the option that was chosen.
+    pub on_change: Option<Box<dyn FnMut(usize)>>,
+    pub tooltip: Option<String>,
+    open: bool,
+    highlighted: usize,
+    /// First option shown in the list.
+    offset: usize,
+}
+
+impl Select {
+    pub fn new(pos: Pos2, width: f32, options: Vec<String>) -> Self {
+        Self {
+            pos,
+            size: Vec2::new(width, 3.0),
+            options,
+            selected: None,
+            placeholder: String::new(),
+            state: ElementState::Inactive,
+            max_rows: 8,
+            layout_item: LayoutItem::new(Sizing::Fixed(width), Sizing::Fixed(3.0)),
+            on_change: None,
+            tooltip: None,
+            open: false,
+            highlighted: 0,
+            offset: 0,
+        }
+    }
+
+    pub fn selected_option(&self) -> Option<&String> {
+        self.selected.and_then(|i| self.options.get(i))
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn open(&mut self) {
+        if self.options.is_empty() {
+            return;
+        }
+        self.open = true;
+        self.offset = 0;
+        self.highlight(self.selected.unwrap_or(0));
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    /// Choose the option at `index`, calling `on_change` if it changed.
+    pub fn choose(&mut self, index: usize) {
+        if index >= self.options.len() {
+            return;
+        }
+        let changed = self.selected != Some(index);
+        self.selected = Some(index);
+        if changed && let Some(on_change) = &mut self.on_change {
+            on_change(index);
+        }
+    }
+
+    fn rows(&self) -> usize {
+        self.options.len().min(self.max_rows.max(1))
+    }
+
+    /// Highlight `index` and scroll the list so it is visible.
+    fn highlight(&mut self, index: usize) {
+        self.highlighted = index.min(self.options.len().saturating_sub(1));
+        let rows = self.rows();
+        self.offset = self.offset.min(self.options.len() - rows);
+        if self.highlighted < self.offset {
+            self.offset = self.highlighted;
+        } else if self.highlighted >= self.offset + rows {
+            self.offset = self.highlighted + 1 - rows;
+        }
+    }
+
+    /// The open list: below the box, or above it when there is no room below.
+    pub fn popup(&self) -> Option<PopupList> {
+        if !self.open {
+            return None;
+        }
+        let items = self.options
+            .get(self.offset..self.offset + self.rows())?
+            .iter()
+            .map(PopupItem::new)
+            .collect();
+        let rect = Rect::new(self.pos.into(), self.size);
+        let mut popup = PopupList::new(Vec2::new(rect.left(), rect.bottom()), items);
+        popup.min_width = self.size.x;
+        popup.highlighted = self.highlighted.checked_sub(self.offset);
+
+        if let Ok(size) = get_terminal_size() {
+            let screen = Rect::new(Vec2::zero(), size.to_f32());
+            if popup.rect().bottom() > screen.bottom() {
+                popup.pos.y = rect.top() - popup.size().y;
+            }
+            popup.fit(screen);
+        }
+        Some(popup)
+    }
+
+    fn color(&self, theme: &Theme) -> Color {
+        match self.state {
+            ElementState::Active | ElementState::Hovered | ElementState::Pressed => theme.focus_border,
+            ElementState::Inactive => theme.text,
+            ElementState::Disabled => theme.disabled,
+        }
+    }
+}
+
+impl UIElement for Select {
+    fn draw(&self) {
+        let theme = theme::current();
+        let color = self.color(&theme);
+
+        let mut border = Border::new(self.pos, self.size);
+        border.color = Some(color);
+        border.draw();
+
+        let inner = Spacing::all(1.0).shrink(Rect::new(self.pos.into(), self.size));
+        let (content, fg) = match self.selected_option() {
+            Some(option) => (option.clone(), color),
+            None => (self.placeholder.clone(), theme.muted),
+        };
+
+        // Leave room for the arrow on the right.
+        let mut style = TextStyle::new(1, fg, Color::RESET);
+        style.wrap = Wrap::None;
+        let mut value = Text::new(inner.pos.into(), Vec2::new((inner.size.x - 2.0).max(0.0), 1.0), format!(" {content}"));
+        value.text_style = Some(style);
+        value.draw();
+
+        let mut arrow_style = TextStyle::new(1, color, Color::RESET);
+        arrow_style.wrap = Wrap::None;
+        let mut arrow = Text::new(
+            Vec2::new(inner.right() - 2.0, inner.top()).into(),
+            Vec2::new(2.0, 1.0),
+            String::from(if self.open { "▴" } else { "▾" }),
+        );
+        arrow.text_style = Some(arrow_style);
+        arrow.draw();
+
+        if let Some(popup) = self.popup() {
+            overlay::defer(move || popup.draw());
+        }
+    }
+
+    fn update(&mut self) {
+        if self.selected.is_some_and(|i| i >= self.options.len()) {
+            self.selected = None;
+        }
+        if self.options.is_empty() {
+            self.open = false;
+        }
+        // `options` may have changed since the list was last scrolled.
+        self.highlight(self.highlighted);
+    }
+
+    fn pos(&self) -> Pos2 {
+        self.pos
+    }
+
+    fn size(&self) -> Vec2<f32> {
+        self.size
+    }
+
+    fn set_pos(&mut self, pos: Pos2) {
+        self.pos = pos;
+    }
+
+    fn set_size(&mut self, size: Vec2<f32>) {
+        self.size = size;
+    }
+
+    fn layout_item(&self) -> LayoutItem {
+        self.layout_item
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn tooltip(&self) -> Option<&str> {
+        self.tooltip.as_deref()
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.state != ElementState::Disabled
+    }
+
+    fn set_state(&mut self, state: ElementState) {
+        if self.state != ElementState::Disabled {
+            self.state = state;
+        }
+    }
+
+    fn captures_input(&self) -> bool {
+        self.open
+    }
+
+    fn on_click(&mut self, pos: Vec2<f32>, button: MouseButton) -> EventResult {
+        if button != MouseButton::Left {
+            return EventResult::Ignored;
+        }
+        if let Some(popup) = self.popup() {
+            if let Some(row) = popup.item_at(pos) {
+                self.choose(self.offset + row);
+                self.close();
+            } else if !popup.rect().contains(pos) {
+                self.close();
+            }
+            return EventResult::Handled;
+        }
+        if Rect::new(self.pos.into(), self.size).contains(pos) {
+            self.open();
+            return EventResult::Handled;
+        }
+        EventResult::Ignored
+    }
+
+    fn on_key(&mut self, key: KeyCode) -> EventResult {
+        if !self.open {
+            return match key {
+                KeyCode::Enter | KeyCode::Char(' ') | KeyCode::Down => {
+                    self.open();
+                    EventResult::Handled
+                }
+                _ => EventResult::Ignored,
+            };
+        }
+
+        let count = self.options.len();
+        match key {
+            KeyCode::Up => self.highlight(self.highlighted.saturating_sub(1)),
+            KeyCode::Down => self.highlight(self.highlighted + 1),
+            KeyCode::PageUp => self.highlight(self.highlighted.saturating_sub(self.rows())),
+            KeyCode::PageDown => self.highlight(self.highlighted + self.rows()),
+            KeyCode::Home => self.highlight(0),
+            KeyCode::End => self.highlight(count.saturating_sub(1)),
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                self.choose(self.highlighted);
+                self.close();
+            }
+            KeyCode::Esc => self.close(),
+            // Swallow everything else so it doesn't reach other elements.
+            _ => {}
+        }
+        EventResult::Handled
+    }
+
+    fn on_focus(&mut self, focused: bool) {
+        if !focused {
+            self.close();
+        }
+    }
+}
@@ -1,62 +1,216 @@
-use crate::{X_SCALE, shapes::{Shape, line::Line}, types::{color::Color, pos2::Pos2, vec2::Vec2}};
+use std::io::stdout;
 
+use crossterm::terminal;
+use unicode_width::UnicodeWidthChar;
+
+use crate::{shapes::pixel::{Pixel, flush_pixels}, types::{color::Color, pos2::Pos2, vec2::Vec2}, ui::theme};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum BorderStyle {
     Solid,
+    /// Edges are drawn as `dash_length` cells of line followed by `gap_length`
+    /// empty cells. Corners are always drawn.
     Dashed { dash_length: f32, gap_length: f32 },
+    /// Edges are drawn as a dot every other cell. Corners are always drawn.
     Dotted,
     None
 }
 
+/// The characters a border is drawn with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BorderSet {
+    pub top_left: char,
+    pub top_right: char,
+    pub bottom_left: char,
+    pub bottom_right: char,
+    pub horizontal: char,
+    pub vertical: char,
+    /// Used on the edges by `BorderStyle::Dotted`.
+    pub dot: char,
+}
+
+impl BorderSet {
+    pub const SINGLE: Self = Self::new(['┌', '┐', '└', '┘'], '─', '│', '·');
+    pub const DOUBLE: Self = Self::new(['╔', '╗', '╚', '╝'], '═', '║', '·');
+    pub const ROUNDED: Self = Self::new(['╭', '╮', '╰', '╯'], '─', '│', '·');
+    pub const HEAVY: Self = Self::new(['┏', '┓', '┗', '┛'], '━', '┃', '·');
+    /// Fallback for terminals/fonts without box-drawing characters.
+    pub const ASCII: Self = Self::new(['+', '+', '+', '+'], '-', '|', '.');
+
+    /// `corners` in order: top left, top right, bottom left, bottom right
+    pub const fn new(corners: [char; 4], horizontal: char, vertical: char, dot: char) -> Self {
+        Self {
+            top_left: corners[0],
+            top_right: corners[1],
+            bottom_left: corners[2],
+            bottom_right: corners[3],
+            horizontal,
+            vertical,
+            dot,
+        }
+    }
+}
+
+impl Default for BorderSet {
+    fn default() -> Self {
+        Self::SINGLE
+    }
+}
+
+/// Where the title sits on the top edge of a border.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TitleAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
 pub struct Border {
+    /// Top left cell of the border.
     pub pos: Pos2,
-    pub width: f32,
-    pub color: Color,
-    pub style: BorderStyle
+    /// Width and height in cells, border included.
+    pub size: Vec2<f32>,
+    /// `None` uses the current theme's `muted` colour.
+    pub color: Option<Color>,
+    /// `None` uses the current theme's `border_style`.
+    pub style: Option<BorderStyle>,
+    /// `None` uses the current theme's `border_set`.
+    pub set: Option<BorderSet>,
+    /// Optional text drawn into the top edge.
+    pub title: Option<String>,
+    pub title_align: TitleAlign,
 }
 
 impl Border {
-    pub fn new(pos: Pos2, width: f32, color: Color, style: BorderStyle) -> Self {
+    pub fn new(pos: Pos2, size: Vec2<f32>) -> Self {
         Self {
-            pos: pos - Vec2::new(0.0, width / 2.0), // adjust position to account for border width
-            // pos,
-            width,
-            color,
-            style
+            pos,
+            size,
+            color: None,
+            style: None,
+            set: None,
+            title: None,
+            title_align: TitleAlign::default(),
         }
     }
 
     pub fn draw(&self) {
-        // Implement drawing logic based on the border style
-        match self.style {
-            BorderStyle::Solid => {
-                // we're reimplementing the drawing logic again since the triangle drawing here wont work well
-                // cuz.. ehmm... you wouldnt want a diagonal line in the middle out of nowhere, would you?
-                // clockwise, starting from top-left corner
-                let rect_corners = [
-                    self.pos + Vec2::new(0.0, 0.0) * self.width, // top left
-                    self.pos + Vec2::new(1.0 * X_SCALE, 0.0) * self.width, // top right
-                    self.pos + Vec2::new(1.0 * X_SCALE, 1.0) * self.width, // bottom right
-                    self.pos + Vec2::new(0.0, 1.0) * self.width, // bottom left
-                ];
-                for i in 0..rect_corners.len() {
-                    let rect_corner = rect_corners[i];
-                    let mut next_rect_corner =  if i >= rect_corners.len() - 1 {
-                        rect_corners[0]
-                    } else {
-                        rect_corners[i + 1]
-                    };
-                    Line::new(rect_corner, next_rect_corner, self.color).draw();
+        let (term_width, term_height) = terminal::size().unwrap();
+        let mut pixels: Vec<Pixel> = Vec::with_capacity(256);
+        self.rasterize(&mut pixels, (term_width, term_height));
+        let mut out = stdout().lock();
+        flush_pixels(&mut out, &mut pixels);
+    }
+
+    /// Rasterize the border (and its title) into the output pixel buffer.
+    /// `term_size` is (width, height) in terminal cells.
+    pub fn rasterize(&self, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
+        let theme = theme::current();
+        let style = self.style.unwrap_or(theme.border_style);
+        let set = self.set.unwrap_or(theme.border_set);
+        let color = self.color.unwrap_or(theme.muted);
+        if let BorderStyle::None = style {
+            return;
+        }
+
+        let pos: Vec2<f32> = self.pos.into();
+        let (x0, y0) = (pos.x.round() as i32, pos.y.round() as i32);
+        let width = self.size.x.round() as i32;
+        let height = self.size.y.round() as i32;
+        if width <= 0 || height <= 0 {
+            return;
+        }
+        let (x1, y1) = (x0 + width - 1, y0 + height - 1);
+
+        let mut push = |x: i32, y: i32, ch: char| {
+            if x >= 0 && y >= 0 && x < term_size.0 as i32 && y < term_size.1 as i32 {
+                out.push(Pixel::new(x as u16, y as u16, ch, color, 0));
+            }
+        };
+
+        let title = self.title_cells(width);
+        for x in x0..=x1 {
+            let i = x - x0;
+            let (top, bottom) = if x == x0 {
+                (set.top_left, set.bottom_left)
+            } else if x == x1 {
+                (set.top_right, set.bottom_right)
+            } else {
+                // Corners are not part of the edge pattern, so it starts at 0
+                // right after the left corner.
+                match edge_char(style, set, i - 1, set.horizontal) {
+                    Some(ch) => (ch, ch),
+                    None => (' ', ' '),
+                }
+            };
+
+            match title.iter().find(|(tx, _)| *tx == i) {
+                Some((_, Some(ch))) => push(x, y0, *ch),
+                Some((_, None)) => {}
+                None if top != ' ' => push(x, y0, top),
+                None => {}
+            }
+            if y1 != y0 && bottom != ' ' {
+                push(x, y1, bottom);
+            }
+        }
+
+        for y in (y0 + 1)..y1 {
+            if let Some(ch) = edge_char(style, set, y - y0 - 1, set.vertical) {
+                push(x0, y, ch);
+                if x1 != x0 {
+                    push(x1, y, ch);
                 }
-            },
-            BorderStyle::Dashed { dash_length, gap_length } => {
-                // Draw dashed border using dash_length and gap_length
-            },
-            BorderStyle::Dotted => {
-                // Draw dotted border
-            },
-            BorderStyle::None => {
-                // No border to draw
             }
         }
     }
+
+
+    /// Title characters with their column offset from the border's left edge,
+    /// and `None` for the columns covered by the right half of a wide one.
+    /// The title is padded with a space on each side and cut off so that it
+    /// never covers the corners.
+    fn title_cells(&self, width: i32) -> Vec<(i32, Option<char>)> {
+        let Some(title) = &self.title else { return vec![] };
+        let available = (width - 2).max(0) as usize;
+
+        let mut cells = Vec::new();
+        let mut used = 0;
+        for ch in format!(" {title} ").chars() {
+            // Zero-width characters, like combining marks, get no cell.
+            let ch_width = ch.width().unwrap_or(0);
+            if ch_width == 0 {
+                continue;
+            }
+            if used + ch_width > available {
+                break;
+            }
+            cells.push((used as i32, Some(ch)));
+            cells.extend((used + 1..used + ch_width).map(|x| (x as i32, None)));
+            used += ch_width;
+        }
+
+        let free = (available - used) as i32;
+        let start = 1 + match self.title_align {
+            TitleAlign::Left => 0,
+            TitleAlign::Center => free / 2,
+            TitleAlign::Right => free,
+        };
+        cells.into_iter().map(|(x, ch)| (start + x, ch)).collect()
+    }
+}
+
+/// Character to draw at the `i`th cell of an edge, or `None` for a gap.
+fn edge_char(style: BorderStyle, set: BorderSet, i: i32, line: char) -> Option<char> {
+    match style {
+        BorderStyle::Solid => Some(line),
+        BorderStyle::Dashed { dash_length, gap_length } => {
+            let dash = dash_length.round().max(1.0) as i32;
+            let gap = gap_length.round().max(0.0) as i32;
+            (i % (dash + gap) < dash).then_some(line)
+        }
+        BorderStyle::Dotted => (i % 2 == 0).then_some(set.dot),
+        BorderStyle::None => None,
+    }
 }
@@ -0,0 +1,191 @@
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    key::{KeyCode, MouseButton},
+    types::{pos2::Pos2, rect::Rect, vec2::Vec2},
+    ui::{
+        ElementState, UIElement,
+        container::UIContainer,
+        event::EventResult,
+        layout::{LayoutItem, Spacing},
+        style::border::Border,
+        text::{Text, style::{TextStyle, Wrap}},
+        theme,
+    },
+};
+
+/// A row of tab titles above a bordered page. Only the selected tab's page is
+/// laid out, drawn and sent events. Left and Right switch tabs while the view,
+/// or something on its page that ignores them, is focused.
+pub struct TabView {
+    pub pos: Pos2,
+    pub size: Vec2<f32>,
+    pub titles: Vec<String>,
+    /// One per title, in the same order.
+    pub pages: Vec<Box<dyn UIElement>>,
+    pub selected: usize,
+    pub state: ElementState,
+    pub layout_item: LayoutItem,
+}
+
+impl TabView {
+    pub fn new(pos: Pos2, size: Vec2<f32>) -> Self {
+        Self {
+            pos,
+            size,
+            titles: Vec::new(),
+            pages: Vec::new(),
+            selected: 0,
+            state: ElementState::Inactive,
+            layout_item: LayoutItem::default(),
+        }
+    }
+
+    pub fn add_tab(&mut self, title: impl Into<String>, page: UIContainer) {
+        self.titles.push(title.into());
+        self.pages.push(Box::new(page));
+    }
+
+    /// Select the tab at `index`, clamped to the tabs there are.
+    pub fn select(&mut self, index: usize) {
+        self.selected = index.min(self.pages.len().saturating_sub(1));
+    }
+
+    /// Where each title sits in the tab row.
+    pub fn title_rects(&self) -> Vec<Rect> {
+        let pos: Vec2<f32> = self.pos.into();
+        let mut x = pos.x;
+        self.titles
+            .iter()
+            .map(|title| {
+                // A space on each side of the title.
+                let width = title.width() as f32 + 2.0;
+                let rect = Rect::new(Vec2::new(x, pos.y), Vec2::new(width, 1.0));
+                x += width + 1.0;
+                rect
+            })
+            .collect()
+    }
+
+    /// The page's area: below the tab row and inside the border.
+    pub fn page_rect(&self) -> Rect {
+        let rect = Rect::new(self.pos.into(), self.size);
+        Spacing::new(2.0, 1.0, 1.0, 1.0).shrink(rect)
+    }
+}
+
+impl UIElement for TabView {
+    fn draw(&self) {
+        let theme = theme::current();
+        let focused = matches!(self.state, ElementState::Active | ElementState::Hovered);
+
+        for (i, (title, rect)) in self.titles.iter().zip(self.title_rects()).enumerate() {
+            let (fg, bg) = if i == self.selected {
+                (theme.surface, theme.primary)
+            } else {
+                (theme.muted, theme.surface)
+            };
+            let mut style = TextStyle::new(1, fg, bg);
+            style.wrap = Wrap::None;
+            style.attributes.bold = i == self.selected && focused;
+            let mut text = Text::new(rect.pos.into(), rect.size, format!(" {title} "));
+            text.text_style = Some(style);
+            text.draw();
+        }
+
+        if let Some(page) = self.pages.get(self.selected) {
+            page.draw();
+        }
+
+        let border_pos: Vec2<f32> = self.pos.into();
+        let mut border = Border::new((border_pos + Vec2::new(0.0, 1.0)).into(), self.size - Vec2::new(0.0, 1.0));
+        if focused {
+            border.color = Some(theme.focus_border);
+        }
+        border.draw();
+    }
+
+    fn update(&mut self) {
+        self.select(self.selected);
+        let page_rect = self.page_rect();
+        if let Some(page) = self.pages.get_mut(self.selected) {
+            page.set_pos(page_rect.pos.into());
+            page.set_size(page_rect.size);
+            page.update();
+        }
+    }
+
+    fn pos(&self) -> Pos2 {
+        self.pos
+    }
+
+    fn size(&self) -> Vec2<f32> {
+        self.size
+    }
+
+    fn set_pos(&mut self, pos: Pos2) {
+        self.pos = pos;
+    }
+
+    fn set_size(&mut self, size: Vec2<f32>) {
+        self.size = size;
+    }
+
+    fn layout_item(&self) -> LayoutItem {
+        self.layout_item
+    }
+
+    fn children(&self) -> &[Box<dyn UIElement>] {
+        match self.pages.get(self.selected) {
+            Some(page) => std::slice::from_ref(page),
+            None => &[],
+        }
+    }
+
+    fn children_mut(&mut self) -> &mut [Box<dyn UIElement>] {
+        match self.pages.get_mut(self.selected) {
+            Some(page) => std::slice::from_mut(page),
+            None => &mut [],
+        }
+    }
+
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.state != ElementState::Disabled
+    }
+
+    fn set_state(&mut self, state: ElementState) {
+        if self.state != ElementState::Disabled {
+            self.state = state;
+        }
+    }
+
+    fn on_click(&mut self, pos: Vec2<f32>, button: MouseButton) -> EventResult {
+        if button != MouseButton::Left {
+            return EventResult::Ignored;
+        }
+        match self.title_rects().iter().position(|rect| rect.contains(pos)) {
+            Some(index) => {
+                self.select(index);
+                EventResult::Handled
+            }
+            None => EventResult::Ignored,
+        }
+    }
+
+    fn on_key(&mut self, key: KeyCode) -> EventResult {
+        let count = self.pages.len();
+        if count == 0 {
+            return EventResult::Ignored;
+        }
+        match key {
+            KeyCode::Left => self.select((self.selected + count - 1) % count),
+            KeyCode::Right => self.select((self.selected + 1) % count),
+            _ => return EventResult::Ignored,
+        }
+        EventResult::Handled
+    }
+}
@@ -0,0 +1,227 @@
+use std::{collections::HashMap, path::Path};
+
+use color_eyre::{Result, eyre::eyre};
+
+const BLOCK_FONT: &str = include_str!("fonts/block.flf");
+
+/// The characters every FIGlet font has to define, in order: printable ASCII
+/// followed by the 7 "Deutsch" characters (which are allowed to be missing).
+const REQUIRED_CHARS: std::ops::RangeInclusive<u32> = 32..=126;
+const DEUTSCH_CHARS: [u32; 7] = [196, 214, 220, 228, 246, 252, 223];
+
+/// A FIGlet font, used to draw text several cells tall.
+///
+/// Glyphs are laid out at full width, i.e. without FIGlet's kerning and
+/// smushing rules.
+#[derive(Debug, Clone)]
+pub struct Font {
+    /// Height of every glyph in rows.
+    pub height: usize,
+    glyphs: HashMap<char, Vec<Vec<char>>>,
+}
+
+impl Font {
+    /// The built-in 5 rows tall block font. It covers printable ASCII, with
+    /// lowercase letters drawn as uppercase.
+    pub fn block() -> Self {
+        Self::parse(BLOCK_FONT).expect("built-in block font is valid")
+    }
+
+    /// Load a FIGlet `.flf` font file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Parse the contents of a FIGlet `.flf` font file.
+    pub fn parse(source: &str) -> Result<Self> {
+        let mut lines = source.lines();
+
+        let header = lines.next().ok_or_else(|| eyre!("empty font file"))?;
+        let mut params = header
+            .strip_prefix("flf2a")
+            .ok_or_else(|| eyre!("not a FIGlet font, header is `{header}`"))?
+            .chars();
+        let hardblank = params.next().ok_or_else(|| eyre!("missing hardblank in font header"))?;
+        let params: Vec<i64> = params
+            .as_str()
+            .split_whitespace()
+            .map(|p| p.parse().map_err(|_| eyre!("invalid font header parameter `{p}`")))
+            .collect::<Result<_>>()?;
+        let (height, comment_lines) = match params[..] {
+            [height, _baseline, _max_length, _old_layout, comment_lines, ..] => (height, comment_lines),
+            _ => return Err(eyre!("font header `{header}` has too few parameters")),
+        };
+        if height <= 0 {
+            return Err(eyre!("font height must be positive, got {height}"));
+        }
+        let height = height as usize;
+
+        for _ in 0..comment_lines {
+            lines.next();
+        }
+
+        let read_glyph = |lines: &mut std::str::Lines| -> Option<Vec<Vec<char>>> {
+            let rows: Vec<Vec<char>> = lines
+                .by_ref()
+                .take(height)
+                .map(|line| {
+                    let line = line.trim_end_matches(['\r', '\n']);
+                    let endmark = line.chars().last();
+                    line.trim_end_matches(|c| Some(c) == endmark)
+                        .chars()
+                        .map(|c| if c == hardblank { ' ' } else { c })
+                        .collect()
+                })
+                .collect();
+            (rows.len() == height).then_some(rows)
+        };
+
+        let mut glyphs = HashMap::new();
+        for code in REQUIRED_CHARS {
+            let glyph = read_glyph(&mut lines)
+                .ok_or_else(|| eyre!("font ends before the glyph for {:?}", char::from_u32(code).unwrap()))?;
+            glyphs.insert(char::from_u32(code).unwrap(), glyph);
+        }
+        for code in DEUTSCH_CHARS {
+            match read_glyph(&mut lines) {
+                Some(glyph) => glyphs.insert(char::from_u32(code).unwrap(), glyph),
+                None => break,
+            };
+        }
+
+        // Code tagged characters: a line starting with the character code,
+        // followed by the glyph itself.
+        while let Some(tag) = lines.next() {
+            let Some(code) = tag.split_whitespace().next() else { continue };
+            let Some(glyph) = read_glyph(&mut lines) else { break };
+            if let Some(ch) = parse_code(code).and_then(char::from_u32) {
+                glyphs.insert(ch, glyph);
+            }
+        }
+
+        Ok(Self { height, glyphs })
+    }
+
+    fn glyph(&self, ch: char) -> Option<&Vec<Vec<char>>> {
+        self.glyphs
+            .get(&ch)
+            .or_else(|| self.glyphs.get(&ch.to_ascii_uppercase()))
+            .or_else(|| self.glyphs.get(&ch.to_ascii_lowercase()))
+    }
+
+    /// Render a single line of text into `height` rows of characters, where
+    /// spaces are transparent. Characters the font doesn't have are skipped.
+    pub fn render(&self, text: &str) -> Vec<Vec<char>> {
+        let mut rows = vec![Vec::new(); self.height];
+        for glyph in text.chars().filter_map(|ch| self.glyph(ch)) {
+            let width = glyph.iter().map(|row| row.len()).max().unwrap_or(0);
+            for (row, glyph_row) in rows.iter_mut().zip(glyph) {
+                row.extend(glyph_row);
+                row.extend(std::iter::repeat_n(' ', width - glyph_row.len()));
+            }
+        }
+        rows
+    }
+
+    /// Width in font cells of `text` once rendered.
+    pub fn width(&self, text: &str) -> usize {
+        text.chars()
+            .filter_map(|ch| self.glyph(ch))
+            .map(|glyph| glyph.iter().map(|row| row.len()).max().unwrap_or(0))
+            .sum()
+    }
+}
+
+/// Parse a FIGlet character code: decimal, `0x` hexadecimal or `0` octal.
+/// Negative codes are not characters and return `None`.
+fn parse_code(code: &str) -> Option<u32> {
+    if let Some(hex) = code.strip_prefix("0x").or_else(|| code.strip_prefix("0X")) {
+        u32::from_str_radix(hex, 16).ok()
+    } else if code.len() > 1 && code.starts_with('0') {
+        u32::from_str_radix(&code[1..], 8).ok()
+    } else {
+        code.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 2 rows tall font where every glyph is its character on the first
+    /// row and twice on the second, followed by `extra`.
+    fn font_source(extra: &str) -> String {
+        let mut source = String::from("flf2a$ 2 1 4 0 1\nthis comment line is skipped\n");
+        for code in REQUIRED_CHARS {
+            let ch = char::from_u32(code).unwrap();
+            if ch == ' ' {
+                source.push_str("$@\n$$@@\n");
+            } else {
+                source.push_str(&format!("{ch}@\n{ch}{ch}@@\n"));
+            }
+        }
+        source + extra
+    }
+
+    fn rows(font: &Font, text: &str) -> Vec<String> {
+        font.render(text).into_iter().map(|row| row.into_iter().collect()).collect()
+    }
+
+    #[test]
+    fn block_font_parses() {
+        let font = Font::parse(BLOCK_FONT).unwrap();
+        assert_eq!(font.height, 5);
+        assert!(font.width("A") > 0);
+    }
+
+    #[test]
+    fn comments_endmarks_and_hardblanks() {
+        let font = Font::parse(&font_source("")).unwrap();
+        assert_eq!(font.height, 2);
+        // Glyphs are padded to their widest row, and the space is all hardblanks.
+        assert_eq!(rows(&font, "A b"), ["A   b ", "AA  bb"]);
+        assert_eq!(font.width(" "), 2);
+    }
+
+    #[test]
+    fn missing_glyphs_are_skipped() {
+        let font = Font::parse(&font_source("")).unwrap();
+        assert_eq!(font.width("Aé"), 2);
+    }
+
+    #[test]
+    fn code_tagged_glyphs() {
+        let mut extra = String::new();
+        for _ in DEUTSCH_CHARS {
+            extra.push_str("#@\n##@@\n");
+        }
+        extra.push_str("0xe9 LATIN SMALL LETTER E WITH ACUTE\né@\néé@@\n");
+        extra.push_str("0350 e with grave, in octal\nè@\nèè@@\n");
+        extra.push_str("8364\n€@\n€€@@\n");
+        extra.push_str("-1 not a character\nx@\nxx@@\n");
+        let font = Font::parse(&font_source(&extra)).unwrap();
+        assert_eq!(rows(&font, "Ä"), ["# ", "##"]);
+        assert_eq!(rows(&font, "éè€"), ["é è € ", "ééèè€€"]);
+    }
+
+    #[test]
+    fn invalid_headers() {
+        assert!(Font::parse("").is_err());
+        assert!(Font::parse("tlf2a$ 2 1 4 0 0").is_err());
+        assert!(Font::parse("flf2a$ 2 1").is_err());
+        assert!(Font::parse("flf2a$ 0 1 4 0 0").is_err());
+        assert!(Font::parse("flf2a$ two 1 4 0 0").is_err());
+        assert!(Font::parse("flf2a$ 2 1 4 0 0\na@\n").is_err());
+    }
+
+    #[test]
+    fn character_codes() {
+        assert_eq!(parse_code("65"), Some(65));
+        assert_eq!(parse_code("0x41"), Some(65));
+        assert_eq!(parse_code("0X41"), Some(65));
+        assert_eq!(parse_code("0101"), Some(65));
+        assert_eq!(parse_code("0"), Some(0));
+        assert_eq!(parse_code("-2"), None);
+        assert_eq!(parse_code("09"), None);
+    }
+}
@@ -1,15 +1,46 @@
+pub mod font;
 pub mod style;
+pub mod span;
+pub mod wrap;
 
 use std::io::Write;
-use crossterm::{cursor::MoveTo, queue, style::{SetBackgroundColor, SetForegroundColor}};
-use crate::{shapes::Shape, types::{color::Color, pos2::Pos2, vec2::Vec2}, ui::{ElementState, UIElement, text::style::TextStyle}};
+use color_eyre::Result;
+use crossterm::{cursor::MoveTo, queue, style::{Attribute, Print, ResetColor, SetAttribute, SetBackgroundColor, SetForegroundColor}, terminal};
+use lazy_static::lazy_static;
+use unicode_width::UnicodeWidthStr;
+use crate::{
+    clip,
+    shapes::pixel::{self, Pixel, flush_pixels},
+    types::{color::{BlendMode, Color}, pos2::Pos2, vec2::Vec2},
+    ui::{
+        UIElement,
+        layout::LayoutItem,
+        text::{
+            font::Font,
+            span::{Span, parse_markup},
+            style::{Overflow, TextAlign, TextStyle, VerticalAlign},
+            wrap::{CellStyle, StyledGrapheme, TextLine, graphemes, wrap_lines},
+        },
+        theme,
+    },
+};
+
+lazy_static! {
+    static ref BLOCK_FONT: Font = Font::block();
+}
 
 #[derive(Debug, Clone)]
 pub struct Text {
     pub pos: Pos2,
     pub size: Vec2<f32>,
     pub content: String,
+    /// Styled pieces of text. When set, these are drawn instead of `content`,
+    /// each one on top of `text_style`.
+    pub spans: Option<Vec<Span>>,
     pub text_style: Option<TextStyle>,
+    pub layout_item: LayoutItem,
+    /// Only used for big text (`font_size` > 1), which is drawn as pixels.
+    pub z_index: i32,
 }
 
 impl Text {
@@ -18,28 +49,235 @@ impl Text {
             pos,
             size,
             content,
-            text_style: None
+            spans: None,
+            text_style: None,
+            layout_item: LayoutItem::default(),
+            z_index: 0,
         }
     }
 
+    /// Create a text made of styled spans. `content` is set to the plain text.
+    pub fn rich(pos: Pos2, size: Vec2<f32>, spans: Vec<Span>) -> Self {
+        let content = spans.iter().map(|span| span.content.as_str()).collect();
+        let mut text = Self::new(pos, size, content);
+        text.spans = Some(spans);
+        text
+    }
+
+    /// Create a rich text from markup, see `span::parse_markup` for the syntax.
+    pub fn from_markup(pos: Pos2, size: Vec2<f32>, markup: &str) -> Result<Self> {
+        Ok(Self::rich(pos, size, parse_markup(markup)?))
+    }
+
     pub fn len(&self) -> usize {
         self.content.len()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.content.is_empty()
+    }
+
+    /// Width of the content in terminal columns, as opposed to `len` which
+    /// counts bytes.
+    pub fn width(&self) -> usize {
+        self.content.width()
+    }
+
+    /// Wrap, truncate and vertically align the content inside the text box.
+    /// Returns the visible lines along with the row (relative to `pos`) each
+    /// one goes on.
+    pub fn lines(&self) -> Vec<(usize, TextLine)> {
+        let default_style = TextStyle::default();
+        let style = self.text_style.as_ref().unwrap_or(&default_style);
+        let (width, height) = (self.size.x.max(0.0) as usize, self.size.y.max(0.0) as usize);
+        if width == 0 || height == 0 {
+            return vec![];
+        }
+
+        let mut lines = wrap_lines(&self.styled_graphemes(style), width, style.wrap);
+
+        let ellipsis = style.overflow == Overflow::Ellipsis;
+        let overflowing = lines.len() > height;
+        lines.truncate(height);
+        for line in &mut lines {
+            line.truncate(width, ellipsis, false);
+        }
+        if overflowing {
+            lines.last_mut().unwrap().truncate(width, ellipsis, true);
+        }
+
+        let top = match style.vertical_align {
+            VerticalAlign::Top => 0,
+            VerticalAlign::Middle => (height - lines.len()) / 2,
+            VerticalAlign::Bottom => height - lines.len(),
+        };
+        lines.into_iter().enumerate().map(|(i, line)| (top + i, line)).collect()
+    }
+
+    fn styled_graphemes(&self, style: &TextStyle) -> Vec<StyledGrapheme> {
+        let cell_style = CellStyle {
+            fg_color: style.fg_color.unwrap_or_else(|| theme::current().text),
+            bg_color: style.bg_color,
+            attributes: style.attributes,
+        };
+        match &self.spans {
+            Some(spans) => spans
+                .iter()
+                .flat_map(|span| graphemes(&span.content, span.style.resolve(cell_style)))
+                .collect(),
+            None => graphemes(&self.content, cell_style),
+        }
+    }
+
+    /// Rasterize the content with a FIGlet font into the output pixel buffer,
+    /// each font cell taking up `font_size - 1` by `font_size - 1` terminal
+    /// cells. Lines only break on `\n` and anything outside of the text box is
+    /// clipped. `term_size` is (width, height) in terminal cells.
+    pub fn rasterize_big(&self, out: &mut Vec<Pixel>, term_size: (u16, u16)) {
+        let default_style = TextStyle::default();
+        let style = self.text_style.as_ref().unwrap_or(&default_style);
+        let font: &Font = style.font.as_deref().unwrap_or(&BLOCK_FONT);
+        let scale = style.font_size.saturating_sub(1).max(1) as i32;
+
+        let pos: Vec2<f32> = self.pos.into();
+        let (left, top) = (pos.x.round() as i32, pos.y.round() as i32);
+        let (width, height) = (self.size.x.max(0.0) as i32, self.size.y.max(0.0) as i32);
+        let right = (left + width).min(term_size.0 as i32);
+        let bottom = (top + height).min(term_size.1 as i32);
+
+        let graphemes = self.styled_graphemes(style);
+        let paragraphs: Vec<&[StyledGrapheme]> = graphemes.split(|g| g.symbol == "\n").collect();
+        let line_height = font.height as i32 * scale;
+        let total_height = line_height * paragraphs.len() as i32;
+        let mut y = top + match style.vertical_align {
+            VerticalAlign::Top => 0,
+            VerticalAlign::Middle => (height - total_height) / 2,
+            VerticalAlign::Bottom => height - total_height,
+        };
+
+        for paragraph in paragraphs {
+            let line_width: i32 = paragraph.iter().map(|g| font.width(&g.symbol) as i32 * scale).sum();
+            let mut x = left + match style.align {
+                TextAlign::Left | TextAlign::Justify => 0,
+                TextAlign::Center => (width - line_width) / 2,
+                TextAlign::Right => width - line_width,
+            };
+
+            for grapheme in paragraph {
+                let rows = font.render(&grapheme.symbol);
+                for (row, cells) in rows.iter().enumerate() {
+                    for (col, &ch) in cells.iter().enumerate() {
+                        if ch == ' ' {
+                            continue;
+                        }
+                        let cell_x = x + col as i32 * scale;
+                        let cell_y = y + row as i32 * scale;
+                        for py in cell_y.max(top)..(cell_y + scale).min(bottom) {
+                            for px in cell_x.max(left).max(0)..(cell_x + scale).min(right) {
+                                if py >= 0 {
+                                    out.push(Pixel::new(px as u16, py as u16, ch, grapheme.style.fg_color, self.z_index));
+                                }
+                            }
+                        }
+                    }
+                }
+                x += font.width(&grapheme.symbol) as i32 * scale;
+            }
+            y += line_height;
+        }
+    }
 }
 
 impl UIElement for Text {
     fn pos(&self) -> Pos2 { self.pos }
     fn size(&self) -> Vec2<f32> { self.size }
+    fn set_pos(&mut self, pos: Pos2) { self.pos = pos }
+    fn set_size(&mut self, size: Vec2<f32>) { self.size = size }
+    fn layout_item(&self) -> LayoutItem { self.layout_item }
 
     fn draw(&self) {
         let mut stdout = std::io::stdout().lock();
         let pos: Vec2<f32> = self.pos.into();
+        let (x, y) = (pos.x.round().max(0.0) as u16, pos.y.round().max(0.0) as u16);
+        let width = self.size.x.max(0.0) as usize;
+
+        let default_style = TextStyle::default();
+        let style = self.text_style.as_ref().unwrap_or(&default_style);
 
-        let text_color = self.text_style.as_ref().map_or(Color::White, |style| style.fg_color);
-        let bg_color = self.text_style.as_ref().map_or(Color::Black, |style| style.bg_color);
-        // +1 to account for border, so that the border doesnt cover up the contents-
-        queue!(stdout, SetForegroundColor(text_color), MoveTo(pos.x as u16+1, pos.y as u16)).unwrap();
-        stdout.write_all(self.content.as_bytes()).unwrap();
+        if style.bg_color != Color::RESET {
+            let end = x + width as u16;
+            for row in y..y + self.size.y.max(0.0) as u16 {
+                // Blend the background of each visible cell with what is
+                // below it, then print runs of blanks of the same colour.
+                let cells: Vec<(u16, Color)> = (x..end)
+                    .filter(|&col| clip::is_visible(col, row))
+                    .map(|col| (col, pixel::composite(col, row, false, Color::RESET, style.bg_color, BlendMode::Normal).1))
+                    .collect();
+                for run in cells.chunk_by(|a, b| a.0 + 1 == b.0 && a.1 == b.1) {
+                    let (start, bg) = run[0];
+                    queue!(stdout, SetBackgroundColor(bg.into()), MoveTo(start, row), Print(" ".repeat(run.len()))).unwrap();
+                }
+            }
+        }
+
+        if style.font_size > 1 {
+            let mut pixels: Vec<Pixel> = Vec::with_capacity(1024);
+            self.rasterize_big(&mut pixels, terminal::size().unwrap());
+            flush_pixels(&mut stdout, &mut pixels);
+            queue!(stdout, ResetColor).unwrap();
+            stdout.flush().unwrap();
+            return;
+        }
+
+        for (row, line) in self.lines() {
+            let row = y + row as u16;
+            let free = width - line.width;
+            let (mut col, spaces) = match style.align {
+                TextAlign::Left => (0, vec![]),
+                TextAlign::Center => (free / 2, vec![]),
+                TextAlign::Right => (free, vec![]),
+                TextAlign::Justify if line.ends_paragraph => (0, vec![]),
+                TextAlign::Justify => (0, justify_spaces(&line, free)),
+            };
+
+            let mut spaces = spaces.into_iter();
+            let mut last_style: Option<CellStyle> = None;
+            // Where the terminal cursor is after the last print, so we only
+            // move it when something got clipped.
+            let mut cursor: Option<u16> = None;
+            for grapheme in &line.graphemes {
+                let extra = if grapheme.is_whitespace() { spaces.next().unwrap_or(0) } else { 0 };
+                let cell = x + col as u16;
+                col += grapheme.width + extra;
+
+                let last = cell + (grapheme.width + extra).max(1) as u16 - 1;
+                if !clip::is_visible(cell, row) || !clip::is_visible(last, row) {
+                    continue;
+                }
+                if cursor != Some(cell) {
+                    queue!(stdout, MoveTo(cell, row)).unwrap();
+                }
+                // Glyphs without a background of their own take the one of
+                // whatever was drawn below them, the text box's included.
+                let CellStyle { fg_color, mut bg_color, attributes } = grapheme.style;
+                if bg_color == style.bg_color {
+                    bg_color = Color::RESET;
+                }
+                let (fg_color, bg_color) = pixel::composite(cell, row, false, fg_color, bg_color, BlendMode::Normal);
+                for covered in cell + 1..=last {
+                    pixel::composite(covered, row, false, Color::RESET, bg_color, BlendMode::Normal);
+                }
+                let cell_style = CellStyle { fg_color, bg_color, attributes };
+                if last_style != Some(cell_style) {
+                    queue_style(&mut stdout, cell_style);
+                    last_style = Some(cell_style);
+                }
+                queue!(stdout, Print(&grapheme.symbol), Print(" ".repeat(extra))).unwrap();
+                cursor = Some(x + col as u16);
+            }
+        }
+
+        queue!(stdout, SetAttribute(Attribute::Reset), ResetColor).unwrap();
         stdout.flush().unwrap();
     }
 
@@ -47,3 +285,22 @@ impl UIElement for Text {
         // No dynamic behavior for now, but this could be used for blinking text, etc.
     }
 }
+
+/// Extra columns to add after each space of `line` so that it grows by `free` columns.
+fn justify_spaces(line: &TextLine, free: usize) -> Vec<usize> {
+    let gaps = line.graphemes.iter().filter(|g| g.is_whitespace()).count();
+    if gaps == 0 {
+        return vec![];
+    }
+    (0..gaps).map(|i| free / gaps + usize::from(i < free % gaps)).collect()
+}
+
+fn queue_style(stdout: &mut impl Write, style: CellStyle) {
+    queue!(
+        stdout,
+        SetAttribute(Attribute::Reset),
+        SetForegroundColor(style.fg_color.into()),
+        SetBackgroundColor(style.bg_color.into()),
+    ).unwrap();
+    pixel::queue_attributes(stdout, style.attributes);
+}
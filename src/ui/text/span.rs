@@ -0,0 +1,211 @@
+use color_eyre::{Result, eyre::eyre};
+
+use crate::{types::color::Color, ui::text::{style::TextAttributes, wrap::CellStyle}};
+
+/// Style overrides for a piece of text. Anything left as `None` falls back to
+/// the owning `Text`'s `TextStyle`, and attributes are added on top of it.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SpanStyle {
+    pub fg_color: Option<Color>,
+    pub bg_color: Option<Color>,
+    pub attributes: TextAttributes,
+}
+
+impl SpanStyle {
+    pub fn fg(color: Color) -> Self {
+        Self { fg_color: Some(color), ..Default::default() }
+    }
+
+    /// Apply this style on top of `base`.
+    pub fn resolve(&self, base: CellStyle) -> CellStyle {
+        CellStyle {
+            fg_color: self.fg_color.unwrap_or(base.fg_color),
+            bg_color: self.bg_color.unwrap_or(base.bg_color),
+            attributes: TextAttributes {
+                bold: base.attributes.bold || self.attributes.bold,
+                italic: base.attributes.italic || self.attributes.italic,
+                underline: base.attributes.underline || self.attributes.underline,
+            },
+        }
+    }
+
+    /// Apply `other` on top of this style (used for nested markup tags).
+    fn merge(&self, other: &SpanStyle) -> SpanStyle {
+        SpanStyle {
+            fg_color: other.fg_color.or(self.fg_color),
+            bg_color: other.bg_color.or(self.bg_color),
+            attributes: TextAttributes {
+                bold: self.attributes.bold || other.attributes.bold,
+                italic: self.attributes.italic || other.attributes.italic,
+                underline: self.attributes.underline || other.attributes.underline,
+            },
+        }
+    }
+}
+
+/// A run of text sharing a single style.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span {
+    pub content: String,
+    pub style: SpanStyle,
+}
+
+impl Span {
+    pub fn new(content: impl Into<String>, style: SpanStyle) -> Self {
+        Self { content: content.into(), style }
+    }
+
+    pub fn raw(content: impl Into<String>) -> Self {
+        Self::new(content, SpanStyle::default())
+    }
+}
+
+/// Parse a small markup language into spans.
+///
+/// A tag is a space separated list of words in square brackets, each one being
+/// - a colour, e.g. `red`, `dark_grey` or `#ff8800`, for the foreground,
+/// - `on` followed by a colour for the background,
+/// - `b`/`bold`, `i`/`italic` or `u`/`underline`.
+///
+/// `[/]` closes the last opened tag, and so does `[/name]` as long as `name`
+/// was the first word of that tag. Use `[[` for a literal `[`.
+///
+/// ```text
+/// [red]Danger[/] [b white on blue]bold[/b]
+/// ```
+pub fn parse_markup(markup: &str) -> Result<Vec<Span>> {
+    let mut spans: Vec<Span> = Vec::new();
+    // Open tags with their first word, and the style with every open tag applied.
+    let mut stack: Vec<(String, SpanStyle)> = Vec::new();
+    let mut current = String::new();
+
+    let mut chars = markup.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch != '[' {
+            current.push(ch);
+            continue;
+        }
+        if chars.peek() == Some(&'[') {
+            chars.next();
+            current.push('[');
+            continue;
+        }
+
+        let mut tag = String::new();
+        loop {
+            match chars.next() {
+                Some(']') => break,
+                Some(c) => tag.push(c),
+                None => return Err(eyre!("unterminated markup tag `[{tag}`")),
+            }
+        }
+
+        let style = stack.last().map_or(SpanStyle::default(), |(_, style)| *style);
+        if !current.is_empty() {
+            spans.push(Span::new(std::mem::take(&mut current), style));
+        }
+
+        if let Some(name) = tag.strip_prefix('/') {
+            let name = name.trim();
+            match stack.pop() {
+                Some((open, _)) if name.is_empty() || name == open => {}
+                Some((open, _)) => return Err(eyre!("`[/{name}]` closes `[{open}]`")),
+                None => return Err(eyre!("`[/{name}]` has no matching opening tag")),
+            }
+        } else {
+            let name = tag.split_whitespace().next().unwrap_or_default().to_string();
+            stack.push((name, style.merge(&parse_tag(&tag)?)));
+        }
+    }
+
+    if !current.is_empty() {
+        let style = stack.last().map_or(SpanStyle::default(), |(_, style)| *style);
+        spans.push(Span::new(current, style));
+    }
+    Ok(spans)
+}
+
+fn parse_tag(tag: &str) -> Result<SpanStyle> {
+    let mut style = SpanStyle::default();
+    let mut words = tag.split_whitespace();
+
+    while let Some(word) = words.next() {
+        match word {
+            "b" | "bold" => style.attributes.bold = true,
+            "i" | "italic" => style.attributes.italic = true,
+            "u" | "underline" => style.attributes.underline = true,
+            "on" => {
+                let color = words.next().ok_or_else(|| eyre!("missing colour after `on` in `[{tag}]`"))?;
+                style.bg_color = Some(color.parse()?);
+            }
+            color => style.fg_color = Some(color.parse()?),
+        }
+    }
+    Ok(style)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bold() -> SpanStyle {
+        SpanStyle { attributes: TextAttributes { bold: true, ..Default::default() }, ..Default::default() }
+    }
+
+    #[test]
+    fn plain_text_is_one_raw_span() {
+        assert_eq!(parse_markup("hello").unwrap(), [Span::raw("hello")]);
+        assert!(parse_markup("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn nested_tags_merge_their_styles() {
+        let spans = parse_markup("a[red]b[b]c[/]d[/]e").unwrap();
+        let red_bold = SpanStyle { fg_color: Some(Color::RED), ..bold() };
+        assert_eq!(spans, [
+            Span::raw("a"),
+            Span::new("b", SpanStyle::fg(Color::RED)),
+            Span::new("c", red_bold),
+            Span::new("d", SpanStyle::fg(Color::RED)),
+            Span::raw("e"),
+        ]);
+    }
+
+    #[test]
+    fn inner_colour_wins() {
+        let spans = parse_markup("[red][blue]x[/][/]").unwrap();
+        assert_eq!(spans, [Span::new("x", SpanStyle::fg(Color::BLUE))]);
+    }
+
+    #[test]
+    fn named_close_matches_the_first_word() {
+        let spans = parse_markup("[b white on blue]x[/b]y").unwrap();
+        let style = SpanStyle { fg_color: Some(Color::WHITE), bg_color: Some(Color::BLUE), ..bold() };
+        assert_eq!(spans, [Span::new("x", style), Span::raw("y")]);
+    }
+
+    #[test]
+    fn background_and_hex_colours() {
+        let spans = parse_markup("[on #ff8800]x").unwrap();
+        let style = SpanStyle { bg_color: Some(Color::rgb(255, 136, 0)), ..Default::default() };
+        assert_eq!(spans, [Span::new("x", style)]);
+    }
+
+    #[test]
+    fn double_bracket_is_a_literal() {
+        assert_eq!(parse_markup("[[b]x").unwrap(), [Span::raw("[b]x")]);
+        assert_eq!(parse_markup("[i][[[/]").unwrap(), [Span::new("[", SpanStyle {
+            attributes: TextAttributes { italic: true, ..Default::default() },
+            ..Default::default()
+        })]);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(parse_markup("[red").is_err());
+        assert!(parse_markup("[red]x[/blue]").is_err());
+        assert!(parse_markup("x[/]").is_err());
+        assert!(parse_markup("[on]x").is_err());
+        assert!(parse_markup("[not_a_colour]x").is_err());
+    }
+}
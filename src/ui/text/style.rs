@@ -1,18 +1,98 @@
-use crate::types::color::Color;
+use std::sync::Arc;
+
+use crate::{types::color::Color, ui::text::font::Font};
 
 #[derive(Debug, Clone)]
 pub struct TextStyle {
+    /// `1` draws regular text, one character per cell. Anything bigger draws
+    /// the text with `font`, scaled by `font_size - 1`: at `2` each font cell
+    /// is one terminal cell, at `3` a 2 by 2 block, and so on. The 5 rows tall
+    /// block font is thus 5 rows tall at `2` and 10 rows tall at `3`.
     pub font_size: u16,
-    pub fg_color: Color,
+    /// FIGlet font for big text. Defaults to `Font::block()`.
+    pub font: Option<Arc<Font>>,
+    /// `None` uses the current theme's `text` colour.
+    pub fg_color: Option<Color>,
+    /// Fills the whole text box. Use `Color::RESET` to keep whatever is behind.
     pub bg_color: Color,
+    pub attributes: TextAttributes,
+    pub align: TextAlign,
+    pub vertical_align: VerticalAlign,
+    pub wrap: Wrap,
+    pub overflow: Overflow,
 }
 
 impl TextStyle {
     pub fn new(font_size: u16, color: Color, background_color: Color) -> Self {
         TextStyle {
             font_size,
-            fg_color: color,
+            fg_color: Some(color),
             bg_color: background_color,
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for TextStyle {
+    fn default() -> Self {
+        Self {
+            font_size: 1,
+            font: None,
+            fg_color: None,
+            bg_color: Color::RESET,
+            attributes: TextAttributes::default(),
+            align: TextAlign::default(),
+            vertical_align: VerticalAlign::default(),
+            wrap: Wrap::default(),
+            overflow: Overflow::default(),
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TextAttributes {
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+    /// Stretch the spaces of every wrapped line so it fills the whole width.
+    /// The last line of a paragraph is left aligned.
+    Justify,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VerticalAlign {
+    #[default]
+    Top,
+    Middle,
+    Bottom,
+}
+
+/// How lines longer than the text box are broken up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Wrap {
+    /// Only break on `\n`.
+    None,
+    /// Break between words, splitting words that don't fit on a line of their own.
+    #[default]
+    Word,
+    /// Break anywhere.
+    Char,
+}
+
+/// What happens to text that doesn't fit in the text box.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Overflow {
+    /// Cut it off at the edge.
+    Clip,
+    /// Cut it off and end the last visible line with `…`.
+    #[default]
+    Ellipsis,
+}
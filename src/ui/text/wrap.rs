@@ -0,0 +1,165 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{types::color::Color, ui::text::style::{TextAttributes, Wrap}};
+
+/// Resolved look of a single grapheme.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CellStyle {
+    pub fg_color: Color,
+    pub bg_color: Color,
+    pub attributes: TextAttributes,
+}
+
+/// A user-perceived character (e.g. `é`, `👍🏽`) with its width in terminal columns.
+#[derive(Debug, Clone)]
+pub struct StyledGrapheme {
+    pub symbol: String,
+    pub width: usize,
+    pub style: CellStyle,
+}
+
+impl StyledGrapheme {
+    pub fn new(symbol: &str, style: CellStyle) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            width: symbol.width(),
+            style,
+        }
+    }
+
+    pub fn is_whitespace(&self) -> bool {
+        self.symbol.chars().all(char::is_whitespace)
+    }
+
+    fn is_newline(&self) -> bool {
+        self.symbol == "\n" || self.symbol == "\r\n"
+    }
+}
+
+/// Split `content` into graphemes that all share the same style.
+pub fn graphemes(content: &str, style: CellStyle) -> Vec<StyledGrapheme> {
+    content.graphemes(true).map(|g| StyledGrapheme::new(g, style)).collect()
+}
+
+/// A single line of text after wrapping.
+#[derive(Debug, Clone, Default)]
+pub struct TextLine {
+    pub graphemes: Vec<StyledGrapheme>,
+    /// Width in terminal columns.
+    pub width: usize,
+    /// Whether this is the last line of a paragraph (i.e. followed by `\n` or the
+    /// end of the text) rather than a line broken by wrapping.
+    pub ends_paragraph: bool,
+}
+
+impl TextLine {
+    fn push(&mut self, grapheme: StyledGrapheme) {
+        self.width += grapheme.width;
+        self.graphemes.push(grapheme);
+    }
+
+    /// Cut this line down to `width` columns, replacing the end with `…` if
+    /// `ellipsis` is set. Does nothing if the line already fits and `force` is false.
+    pub fn truncate(&mut self, width: usize, ellipsis: bool, force: bool) {
+        if self.width <= width && !force {
+            return;
+        }
+
+        let room = if ellipsis { width.saturating_sub(1) } else { width };
+        let style = self.graphemes.last().map(|g| g.style);
+        let mut kept = TextLine { ends_paragraph: self.ends_paragraph, ..Default::default() };
+        for grapheme in self.graphemes.drain(..) {
+            if kept.width + grapheme.width > room {
+                break;
+            }
+            kept.push(grapheme);
+        }
+        // Don't leave the ellipsis dangling after a space.
+        while ellipsis && kept.graphemes.last().is_some_and(|g| g.is_whitespace()) {
+            let g = kept.graphemes.pop().unwrap();
+            kept.width -= g.width;
+        }
+        if let (true, Some(style), true) = (ellipsis, style, width > 0) {
+            kept.push(StyledGrapheme::new("…", style));
+        }
+        *self = kept;
+    }
+}
+
+/// Break `graphemes` into lines of at most `width` columns.
+pub fn wrap_lines(graphemes: &[StyledGrapheme], width: usize, wrap: Wrap) -> Vec<TextLine> {
+    let mut lines = Vec::new();
+
+    for paragraph in graphemes.split(|g| g.is_newline()) {
+        let start = lines.len();
+        match wrap {
+            Wrap::None => {
+                let mut line = TextLine::default();
+                paragraph.iter().cloned().for_each(|g| line.push(g));
+                lines.push(line);
+            }
+            Wrap::Char => wrap_chars(paragraph, width, &mut lines),
+            Wrap::Word => wrap_words(paragraph, width, &mut lines),
+        }
+        if lines.len() == start {
+            lines.push(TextLine::default());
+        }
+        lines.last_mut().unwrap().ends_paragraph = true;
+    }
+    lines
+}
+
+fn wrap_chars(paragraph: &[StyledGrapheme], width: usize, lines: &mut Vec<TextLine>) {
+    let mut line = TextLine::default();
+    for grapheme in paragraph {
+        if line.width + grapheme.width > width && !line.graphemes.is_empty() {
+            lines.push(std::mem::take(&mut line));
+        }
+        line.push(grapheme.clone());
+    }
+    lines.push(line);
+}
+
+fn wrap_words(paragraph: &[StyledGrapheme], width: usize, lines: &mut Vec<TextLine>) {
+    let mut line = TextLine::default();
+    // Whitespace seen since the last word. It is only written out once we know
+    // the next word goes on the same line, so lines never start or end with it.
+    let mut pending: Vec<&StyledGrapheme> = Vec::new();
+
+    let mut rest = paragraph;
+    while !rest.is_empty() {
+        let is_space = rest[0].is_whitespace();
+        let len = rest.iter().take_while(|g| g.is_whitespace() == is_space).count();
+        let (token, tail) = rest.split_at(len);
+        rest = tail;
+
+        if is_space {
+            if !line.graphemes.is_empty() {
+                pending.extend(token);
+            }
+            continue;
+        }
+
+        let word_width: usize = token.iter().map(|g| g.width).sum();
+        let pending_width: usize = pending.iter().map(|g| g.width).sum();
+        if line.width + pending_width + word_width <= width {
+            pending.drain(..).for_each(|g| line.push(g.clone()));
+            token.iter().cloned().for_each(|g| line.push(g));
+            continue;
+        }
+
+        pending.clear();
+        if !line.graphemes.is_empty() {
+            lines.push(std::mem::take(&mut line));
+        }
+        if word_width <= width {
+            token.iter().cloned().for_each(|g| line.push(g));
+        } else {
+            // The word doesn't even fit on a line of its own, break it up.
+            wrap_chars(token, width, lines);
+            line = lines.pop().unwrap();
+        }
+    }
+    lines.push(line);
+}
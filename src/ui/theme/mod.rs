@@ -0,0 +1,106 @@
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+
+use crate::{
+    types::color::Color,
+    ui::{
+        layout::Spacing,
+        style::border::{BorderSet, BorderStyle},
+    },
+};
+
+lazy_static! {
+    static ref CURRENT: RwLock<Theme> = RwLock::new(Theme::dark());
+}
+
+/// The theme elements fall back to when they have no colour or style of
+/// their own. Starts out as `Theme::dark()`.
+pub fn current() -> Theme {
+    *CURRENT.read()
+}
+
+/// Switch themes. Takes effect on the next `draw`.
+pub fn set(theme: Theme) {
+    *CURRENT.write() = theme;
+}
+
+/// Colours by role, plus the default look of borders and padding.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    /// Behind everything. `Color::RESET` keeps the terminal's own background.
+    pub background: Color,
+    /// Behind panels drawn on top of the scene: dialogs, toasts...
+    pub surface: Color,
+    /// Highlights: selections, progress bars, scrollbar thumbs...
+    pub primary: Color,
+    /// Things that should stand out from `primary`, e.g. notifications.
+    pub accent: Color,
+    pub text: Color,
+    /// Secondary text, borders and empty tracks.
+    pub muted: Color,
+    pub disabled: Color,
+    /// Border and label colour of the focused element.
+    pub focus_border: Color,
+    pub border_set: BorderSet,
+    pub border_style: BorderStyle,
+    /// Space between a bordered panel's edges and its content, the default
+    /// for `ContainerStyle::padding`.
+    pub padding: Spacing,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    pub fn dark() -> Self {
+        Self {
+            background: Color::RESET,
+            surface: Color::rgb(24, 24, 32),
+            primary: Color::rgb(97, 175, 239),
+            accent: Color::rgb(229, 192, 123),
+            text: Color::rgb(220, 223, 228),
+            muted: Color::rgb(110, 118, 129),
+            disabled: Color::rgb(75, 80, 88),
+            focus_border: Color::rgb(229, 192, 123),
+            border_set: BorderSet::ROUNDED,
+            border_style: BorderStyle::Solid,
+            padding: Spacing::symmetric(1.0, 2.0),
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            background: Color::rgb(250, 250, 250),
+            surface: Color::rgb(234, 234, 238),
+            primary: Color::rgb(1, 102, 204),
+            accent: Color::rgb(166, 38, 164),
+            text: Color::rgb(40, 42, 46),
+            muted: Color::rgb(130, 132, 140),
+            disabled: Color::rgb(180, 182, 188),
+            focus_border: Color::rgb(1, 102, 204),
+            border_set: BorderSet::ROUNDED,
+            border_style: BorderStyle::Solid,
+            padding: Spacing::symmetric(1.0, 2.0),
+        }
+    }
+
+    /// Only uses the basic 16 colours, so it also works on limited terminals.
+    pub fn high_contrast() -> Self {
+        Self {
+            background: Color::BLACK,
+            surface: Color::BLACK,
+            primary: Color::YELLOW,
+            accent: Color::CYAN,
+            text: Color::WHITE,
+            muted: Color::WHITE,
+            disabled: Color::DARK_GREY,
+            focus_border: Color::YELLOW,
+            border_set: BorderSet::HEAVY,
+            border_style: BorderStyle::Solid,
+            padding: Spacing::symmetric(1.0, 2.0),
+        }
+    }
+}
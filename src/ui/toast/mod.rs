@@ -0,0 +1,135 @@
+use std::time::{Duration, Instant};
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    types::{color::Color, rect::Rect, vec2::Vec2},
+    ui::{
+        UIElement,
+        container::{UIContainer, style::ContainerStyle},
+        layout::{Align, Direction, Layout, Sizing},
+        style::border::Border,
+        text::{Text, style::{TextStyle, Wrap}},
+        theme::{self, Theme},
+    },
+};
+
+/// Which corner of the screen toasts are stacked in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Corner {
+    TopLeft,
+    #[default]
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// A short notification that disappears on its own after `duration`.
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub message: String,
+    /// Defaults to the theme's `accent` colour.
+    pub color: Option<Color>,
+    pub duration: Duration,
+    created: Instant,
+}
+
+impl Toast {
+    pub fn new(message: String, duration: Duration) -> Self {
+        Self {
+            message,
+            color: None,
+            duration,
+            created: Instant::now(),
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.created.elapsed() >= self.duration
+    }
+}
+
+/// A stack of toasts in one corner of the screen, newest closest to the corner.
+#[derive(Debug, Clone)]
+pub struct Toasts {
+    pub items: Vec<Toast>,
+    pub corner: Corner,
+    /// Widest a toast gets, border included. Narrower messages get narrower boxes.
+    pub max_width: f32,
+    /// Empty cells between the stack and the edges of the screen.
+    pub margin: f32,
+}
+
+impl Default for Toasts {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            corner: Corner::default(),
+            max_width: 40.0,
+            margin: 1.0,
+        }
+    }
+}
+
+impl Toasts {
+    pub fn push(&mut self, toast: Toast) {
+        self.items.push(toast);
+    }
+
+    /// Drop the toasts whose time is up.
+    pub fn update(&mut self) {
+        self.items.retain(|toast| !toast.is_expired());
+    }
+
+    pub fn draw(&self, screen: Rect) {
+        let theme = theme::current();
+        // One line of message inside the padding, which leaves room for the border.
+        let height = 1.0 + theme.padding.vertical();
+
+        let inner = Rect::new(
+            screen.pos + Vec2::new(self.margin, self.margin),
+            Vec2::new(screen.size.x - self.margin * 2.0, screen.size.y - self.margin * 2.0),
+        );
+        let top = matches!(self.corner, Corner::TopLeft | Corner::TopRight);
+        let left = matches!(self.corner, Corner::TopLeft | Corner::BottomLeft);
+
+        for (i, toast) in self.items.iter().rev().enumerate() {
+            let offset = i as f32 * height;
+            if offset + height > inner.size.y {
+                break;
+            }
+
+            let width = (toast.message.width() as f32 + theme.padding.horizontal()).min(self.max_width).min(inner.size.x);
+            let x = if left { inner.left() } else { inner.right() - width };
+            let y = if top { inner.top() + offset } else { inner.bottom() - offset - height };
+            panel(toast, Rect::new(Vec2::new(x, y), Vec2::new(width, height)), &theme).draw();
+        }
+    }
+}
+
+/// A bordered box around the toast's message, laid out inside `rect`.
+fn panel(toast: &Toast, rect: Rect, theme: &Theme) -> UIContainer {
+    let color = toast.color.unwrap_or(theme.accent);
+
+    let mut border = Border::new(rect.pos.into(), rect.size);
+    border.color = Some(color);
+
+    let mut panel = UIContainer::new(rect.pos.into(), rect.size);
+    panel.style = Some(ContainerStyle::new(Some(border)));
+    panel.layout = Some(Layout {
+        direction: Direction::Column,
+        align: Align::Stretch,
+        ..Default::default()
+    });
+
+    // The message's background blanks out whatever is behind the box.
+    let mut message = Text::new(Vec2::zero().into(), Vec2::zero(), toast.message.clone());
+    let mut style = TextStyle::new(1, color, theme.surface);
+    style.wrap = Wrap::None;
+    message.text_style = Some(style);
+    message.layout_item.height = Sizing::Fill(1.0);
+    panel.add_child(Box::new(message));
+
+    panel.update();
+    panel
+}
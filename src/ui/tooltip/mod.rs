@@ -0,0 +1,108 @@
+use std::time::{Duration, Instant};
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    types::{rect::Rect, vec2::Vec2},
+    ui::{
+        UIElement,
+        event::{ElementPath, get},
+        style::border::Border,
+        text::{Text, style::TextStyle},
+        theme,
+    },
+};
+
+/// Shows the `tooltip` of the element under the pointer once it has been
+/// hovered for `delay`. The tooltip goes below the element, or above it when
+/// there is no room below, and is shifted sideways to stay on screen.
+#[derive(Debug, Clone)]
+pub struct Tooltips {
+    pub enabled: bool,
+    pub delay: Duration,
+    /// Widest a tooltip gets, border included. Longer text wraps.
+    pub max_width: f32,
+    hovered: Option<ElementPath>,
+    since: Instant,
+}
+
+impl Default for Tooltips {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            delay: Duration::from_millis(600),
+            max_width: 40.0,
+            hovered: None,
+            since: Instant::now(),
+        }
+    }
+}
+
+impl Tooltips {
+    /// Restart the delay whenever the hovered element changes. `None` hides
+    /// the tooltip, e.g. while a mouse button is down.
+    pub fn update(&mut self, hovered: Option<&[usize]>) {
+        if self.hovered.as_deref() != hovered {
+            self.hovered = hovered.map(<[usize]>::to_vec);
+            self.since = Instant::now();
+        }
+    }
+
+    /// Whether a tooltip is due, if the hovered element has one.
+    pub fn is_due(&self) -> bool {
+        self.enabled && self.hovered.is_some() && self.since.elapsed() >= self.delay
+    }
+
+    /// The text to show and the rect of the element it belongs to: the
+    /// hovered element's tooltip, or else the closest parent's.
+    fn find<'a>(&self, root: &'a dyn UIElement) -> Option<(&'a str, Rect)> {
+        let path = self.hovered.as_ref()?;
+        (0..=path.len()).rev().find_map(|depth| {
+            let element = get(root, &path[..depth])?;
+            let tooltip = element.tooltip()?;
+            Some((tooltip, Rect::new(element.pos().into(), element.size())))
+        })
+    }
+
+    pub fn draw(&self, root: &dyn UIElement, screen: Rect) {
+        if !self.is_due() {
+            return;
+        }
+        let Some((tooltip, anchor)) = self.find(root) else { return };
+
+        // Measure the wrapped text to know how tall the box has to be.
+        let width = (tooltip.width() as f32 + 4.0).min(self.max_width).min(screen.size.x);
+        let mut text = Text::new(Vec2::zero().into(), Vec2::new(width - 4.0, screen.size.y), tooltip.to_string());
+        let theme = theme::current();
+        text.text_style = Some(TextStyle::new(1, theme.text, theme.surface));
+        let size = Vec2::new(width, text.lines().len() as f32 + 2.0);
+
+        let pos = place(size, anchor, screen);
+        let mut fill = Text::new(pos.into(), size, String::new());
+        fill.text_style = text.text_style.clone();
+        fill.draw();
+
+        text.pos = (pos + Vec2::new(2.0, 1.0)).into();
+        text.size = size - Vec2::new(4.0, 2.0);
+        text.draw();
+
+        Border::new(pos.into(), size).draw();
+    }
+}
+
+/// Where a box of `size` goes next to `anchor`: below it and left aligned
+/// with it, flipped above it and right aligned with it when that doesn't
+/// fit inside `screen`, and finally pushed inside `screen` if it still
+/// sticks out.
+pub fn place(size: Vec2<f32>, anchor: Rect, screen: Rect) -> Vec2<f32> {
+    let mut pos = Vec2::new(anchor.left(), anchor.bottom());
+    if pos.y + size.y > screen.bottom() && anchor.top() - size.y >= screen.top() {
+        pos.y = anchor.top() - size.y;
+    }
+    if pos.x + size.x > screen.right() && anchor.right() - size.x >= screen.left() {
+        pos.x = anchor.right() - size.x;
+    }
+    pos.x = pos.x.min(screen.right() - size.x).max(screen.left());
+    pos.y = pos.y.min(screen.bottom() - size.y).max(screen.top());
+    pos
+}
@@ -1,7 +1,20 @@
+use std::io::Write;
+
 use color_eyre::Result;
-use crate::types::vec2::Vec2;
+use crossterm::{cursor::MoveTo, execute, terminal::{Clear, ClearType}};
+
+use crate::{shapes::pixel, types::vec2::Vec2};
 
 pub fn get_terminal_size() -> Result<Vec2<u16>> {
     let size = crossterm::terminal::size()?;
     Ok(Vec2::new(size.0, size.1))
 }
+
+/// Clear the terminal to start a new frame, and forget what the last one
+/// drew so translucent pixels aren't blended with it. Call this once at the
+/// top of every frame instead of clearing the screen yourself.
+pub fn clear_screen(out: &mut impl Write) -> Result<()> {
+    execute!(out, Clear(ClearType::All), MoveTo(0, 0))?;
+    pixel::clear_canvas();
+    Ok(())
+}